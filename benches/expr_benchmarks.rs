@@ -0,0 +1,67 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rust_lodash::prelude::*;
+use serde_json::{json, Value};
+
+fn generate_users(size: usize) -> Vec<Value> {
+    (0..size)
+        .map(|i| {
+            let tags = if i % 3 == 0 { json!(["admin", "dev"]) } else { json!(["dev"]) };
+            json!({"name": format!("user{i}"), "age": i % 60, "tags": tags})
+        })
+        .collect()
+}
+
+fn bench_predicate(c: &mut Criterion) {
+    let users = generate_users(10000);
+    let source = r#"age >= 18 && tags contains "admin""#;
+
+    c.bench_function("expr_predicate_10000", |b| {
+        let adult_admin = compile_predicate(source).unwrap();
+        b.iter(|| {
+            let result = filter(black_box(&users), &adult_admin);
+            black_box(result)
+        })
+    });
+
+    c.bench_function("closure_predicate_10000", |b| {
+        b.iter(|| {
+            let result = filter(black_box(&users), |user: &Value| {
+                user["age"].as_f64().is_some_and(|age| age >= 18.0)
+                    && user["tags"]
+                        .as_array()
+                        .is_some_and(|tags| tags.iter().any(|tag| tag == "admin"))
+            });
+            black_box(result)
+        })
+    });
+
+    c.bench_function("expr_parse", |b| {
+        b.iter(|| black_box(Expr::parse(black_box(source)).unwrap()))
+    });
+}
+
+fn bench_mapper(c: &mut Criterion) {
+    let users = generate_users(10000);
+
+    c.bench_function("expr_mapper_10000", |b| {
+        let upper_name = compile_mapper("upper(name)").unwrap();
+        b.iter(|| {
+            let result = map(black_box(&users), &upper_name);
+            black_box(result)
+        })
+    });
+
+    c.bench_function("closure_mapper_10000", |b| {
+        b.iter(|| {
+            let result = map(black_box(&users), |user: &Value| {
+                user["name"]
+                    .as_str()
+                    .map_or(Value::Null, |name| Value::String(name.to_uppercase()))
+            });
+            black_box(result)
+        })
+    });
+}
+
+criterion_group!(benches, bench_predicate, bench_mapper);
+criterion_main!(benches);
@@ -7,6 +7,8 @@ This example demonstrates the parallel processing functionality of the Lodash-RS
 #[cfg(feature = "parallel")]
 use rust_lodash::prelude::*;
 #[cfg(feature = "parallel")]
+use std::sync::atomic::{AtomicUsize, Ordering};
+#[cfg(feature = "parallel")]
 use std::time::Instant;
 
 #[cfg(feature = "parallel")]
@@ -38,13 +40,15 @@ fn basic_parallel_operations() {
     println!("  filterParallel([1..1000], x => x % 2 === 0) = [{} elements]", evens.len());
     
     // Parallel reduce operation
-    let sum = reduce_parallel(&numbers, |acc, x| acc + x, 0);
-    println!("  reduceParallel([1..1000], (acc, x) => acc + x, 0) = {}", sum);
+    let sum = reduce_parallel(&numbers, |acc, x| acc + x, |a, b| a + b, 0);
+    println!("  reduceParallel([1..1000], (acc, x) => acc + x, (a, b) => a + b, 0) = {}", sum);
     
     // Parallel forEach operation
-    let mut count = 0;
-    for_each_parallel(&numbers, |_| count += 1);
-    println!("  forEachParallel([1..1000], x => count++) = count = {}", count);
+    let count = AtomicUsize::new(0);
+    for_each_parallel(&numbers, |_| {
+        count.fetch_add(1, Ordering::Relaxed);
+    });
+    println!("  forEachParallel([1..1000], x => count++) = count = {}", count.into_inner());
     
     // Parallel find operation
     let first_even = find_parallel(&numbers, |x| x % 2 == 0);
@@ -110,7 +114,7 @@ fn parallel_chain_operations() {
         .filter(|x| x % 2 == 0)
         .map(|x| x * 3)
         .take(10)
-        .collect::<Vec<_>>();
+        .collect();
     println!("  chain([1..1000]).filter(x => x % 2 === 0).map(x => x * 3).take(10) = {:?}", result);
     
     println!();
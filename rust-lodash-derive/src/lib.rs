@@ -0,0 +1,500 @@
+/*!
+Derive macros for Lodash-RS.
+
+This crate implements `#[derive(ToKey, ToComparable, ToHashKey)]` for the
+traits in `rust_lodash::utils::type_conv`, so user types can be grouped,
+sorted and memoized by their fields. Enable the `derive` feature of
+`rust-lodash` instead of depending on it directly; the macros are re-exported
+next to the traits.
+
+Fields take `#[lodash(...)]` attributes:
+
+- `key`: only the fields marked `key` take part, in declaration order. By
+  default every field does.
+- `skip`: leave the field out.
+- `order = "desc"` (or `"asc"`): for `ToComparable`, compare the field in
+  descending order.
+
+The attributes apply to every derive on the type.
+*/
+
+#![warn(missing_docs, clippy::all, clippy::pedantic)]
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2, TokenTree};
+use quote::{format_ident, quote, ToTokens};
+use syn::{
+    parse_macro_input, parse_quote, Attribute, Data, DeriveInput, Fields, Generics, Ident, LitStr,
+    Type,
+};
+
+/// The most fields a derived `ToComparable` can compare, the largest tuple
+/// that implements `PartialOrd`.
+const MAX_COMPARABLE_FIELDS: usize = 12;
+
+/// Derive `ToKey`.
+///
+/// A struct's key is the key of its one field, or the keys of its fields
+/// joined by `compose_key`; a struct without fields uses its name. An enum's
+/// key is the variant name, followed by the keys of the variant's fields.
+///
+/// ```
+/// use rust_lodash::collection::transform::group_by;
+/// use rust_lodash::utils::ToKey;
+///
+/// #[derive(Clone, ToKey)]
+/// struct Sale {
+///     #[lodash(key)]
+///     region: String,
+///     #[lodash(key)]
+///     year: u32,
+///     amount: f64,
+/// }
+///
+/// let sales = vec![
+///     Sale { region: "east".into(), year: 2024, amount: 10.0 },
+///     Sale { region: "east".into(), year: 2024, amount: 5.0 },
+///     Sale { region: "west".into(), year: 2024, amount: 1.0 },
+/// ];
+/// let groups = group_by(&sales, |sale| sale.to_key());
+/// assert_eq!(groups["east:2024"].len(), 2);
+/// ```
+#[proc_macro_derive(ToKey, attributes(lodash))]
+pub fn derive_to_key(input: TokenStream) -> TokenStream {
+    expand(&parse_macro_input!(input as DeriveInput), Trait::Key)
+}
+
+/// Derive `ToComparable`.
+///
+/// A struct compares by its one field, or by a tuple of its fields in
+/// declaration order, with `order = "desc"` fields wrapped in
+/// `core::cmp::Reverse`. The output is `Ord` when every field's output is,
+/// so it can be returned to `sort_by`. A fieldless enum compares by the
+/// declaration order of its variants.
+///
+/// ```
+/// use rust_lodash::collection::transform::sort_by;
+/// use rust_lodash::utils::ToComparable;
+///
+/// #[derive(Clone, ToComparable)]
+/// enum Tier {
+///     Free,
+///     Pro,
+/// }
+///
+/// #[derive(Clone, ToComparable)]
+/// struct Player {
+///     #[lodash(order = "desc")]
+///     tier: Tier,
+///     #[lodash(order = "desc")]
+///     score: u32,
+///     name: String,
+///     #[lodash(skip)]
+///     session: u64,
+/// }
+///
+/// let players = vec![
+///     Player { tier: Tier::Free, score: 90, name: "ann".into(), session: 1 },
+///     Player { tier: Tier::Pro, score: 10, name: "bob".into(), session: 2 },
+///     Player { tier: Tier::Free, score: 90, name: "al".into(), session: 3 },
+/// ];
+/// let sorted = sort_by(&players, |player| player.to_comparable());
+/// let names: Vec<&str> = sorted.iter().map(|player| player.name.as_str()).collect();
+/// assert_eq!(names, ["bob", "al", "ann"]);
+/// ```
+#[proc_macro_derive(ToComparable, attributes(lodash))]
+pub fn derive_to_comparable(input: TokenStream) -> TokenStream {
+    expand(&parse_macro_input!(input as DeriveInput), Trait::Comparable)
+}
+
+/// Derive `ToHashKey`.
+///
+/// A struct's hash key is the hash key of its one field, or the hash keys of
+/// its fields combined by `compose_hash_key`. An enum's hash key combines
+/// the variant's position with its fields' hash keys.
+///
+/// ```
+/// use rust_lodash::utils::ToHashKey;
+///
+/// #[derive(ToHashKey)]
+/// struct Request {
+///     #[lodash(key)]
+///     user: u64,
+///     #[lodash(key)]
+///     path: String,
+///     received_at: u64,
+/// }
+///
+/// let first = Request { user: 7, path: "/".into(), received_at: 1 };
+/// let retry = Request { user: 7, path: "/".into(), received_at: 2 };
+/// assert_eq!(first.to_hash_key(), retry.to_hash_key());
+/// ```
+#[proc_macro_derive(ToHashKey, attributes(lodash))]
+pub fn derive_to_hash_key(input: TokenStream) -> TokenStream {
+    expand(&parse_macro_input!(input as DeriveInput), Trait::HashKey)
+}
+
+/// The trait being derived.
+#[derive(Clone, Copy, PartialEq)]
+enum Trait {
+    Key,
+    Comparable,
+    HashKey,
+}
+
+impl Trait {
+    fn path(self) -> TokenStream2 {
+        match self {
+            Self::Key => quote!(::rust_lodash::utils::type_conv::ToKey),
+            Self::Comparable => quote!(::rust_lodash::utils::type_conv::ToComparable),
+            Self::HashKey => quote!(::rust_lodash::utils::type_conv::ToHashKey),
+        }
+    }
+}
+
+/// A field that takes part in the derived trait.
+struct KeyField {
+    /// The binding the field is matched to
+    binding: Ident,
+    /// The field's type
+    ty: Type,
+    /// Whether the field compares in descending order
+    descending: bool,
+}
+
+/// A struct, or one variant of an enum.
+struct Shape {
+    /// The variant name, for enums
+    variant: Option<Ident>,
+    /// The pattern matching `self`
+    pattern: TokenStream2,
+    /// The fields that take part, in declaration order
+    fields: Vec<KeyField>,
+}
+
+/// The parsed `#[lodash(...)]` attributes of a field.
+#[derive(Default)]
+struct FieldAttrs {
+    key: bool,
+    skip: bool,
+    descending: bool,
+}
+
+fn expand(input: &DeriveInput, derived: Trait) -> TokenStream {
+    expand_derive(input, derived)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand_derive(input: &DeriveInput, derived: Trait) -> syn::Result<TokenStream2> {
+    reject_attrs(&input.attrs)?;
+    let shapes = match &input.data {
+        Data::Struct(data) => vec![shape(quote!(Self), None, &data.fields)?],
+        Data::Enum(data) => data
+            .variants
+            .iter()
+            .map(|variant| {
+                reject_attrs(&variant.attrs)?;
+                let name = &variant.ident;
+                shape(quote!(Self::#name), Some(name.clone()), &variant.fields)
+            })
+            .collect::<syn::Result<_>>()?,
+        Data::Union(data) => {
+            return Err(syn::Error::new_spanned(
+                data.union_token,
+                "lodash traits cannot be derived for unions",
+            ))
+        }
+    };
+
+    let name = &input.ident;
+    let trait_path = derived.path();
+    let generics = bounded_generics(&input.generics, &shapes, &trait_path);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let body = match derived {
+        Trait::Key => to_key_body(name, &shapes),
+        Trait::HashKey => to_hash_key_body(&shapes),
+        Trait::Comparable => {
+            let (output, body) = to_comparable_body(input, &shapes)?;
+            return Ok(quote! {
+                #[automatically_derived]
+                impl #impl_generics #trait_path for #name #ty_generics #where_clause {
+                    type Output = #output;
+
+                    fn to_comparable(&self) -> Self::Output {
+                        #body
+                    }
+                }
+            });
+        }
+    };
+    let method = if derived == Trait::Key {
+        quote!(fn to_key(&self) -> ::std::string::String)
+    } else {
+        quote!(fn to_hash_key(&self) -> u64)
+    };
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics #trait_path for #name #ty_generics #where_clause {
+            #method {
+                #body
+            }
+        }
+    })
+}
+
+/// Reject `#[lodash(...)]` outside fields.
+fn reject_attrs(attrs: &[Attribute]) -> syn::Result<()> {
+    match attrs.iter().find(|attr| attr.path().is_ident("lodash")) {
+        Some(attr) => Err(syn::Error::new_spanned(
+            attr,
+            "`#[lodash(...)]` is only supported on fields",
+        )),
+        None => Ok(()),
+    }
+}
+
+fn field_attrs(attrs: &[Attribute]) -> syn::Result<FieldAttrs> {
+    let mut parsed = FieldAttrs::default();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("lodash")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("key") {
+                parsed.key = true;
+            } else if meta.path.is_ident("skip") {
+                parsed.skip = true;
+            } else if meta.path.is_ident("order") {
+                let order: LitStr = meta.value()?.parse()?;
+                parsed.descending = match order.value().as_str() {
+                    "asc" => false,
+                    "desc" => true,
+                    _ => {
+                        return Err(syn::Error::new_spanned(
+                            order,
+                            "expected `order = \"asc\"` or `order = \"desc\"`",
+                        ))
+                    }
+                };
+            } else {
+                return Err(meta.error("expected `key`, `skip` or `order = \"...\"`"));
+            }
+            Ok(())
+        })?;
+        if parsed.key && parsed.skip {
+            return Err(syn::Error::new_spanned(
+                attr,
+                "a field cannot be both `key` and `skip`",
+            ));
+        }
+    }
+    Ok(parsed)
+}
+
+/// Collect the fields of a struct or variant that take part, with the
+/// pattern binding them.
+fn shape(path: TokenStream2, variant: Option<Ident>, fields: &Fields) -> syn::Result<Shape> {
+    let attrs = fields
+        .iter()
+        .map(|field| field_attrs(&field.attrs))
+        .collect::<syn::Result<Vec<_>>>()?;
+    let any_key = attrs.iter().any(|attrs| attrs.key);
+    let mut key_fields = Vec::new();
+    let mut bindings = Vec::new();
+    for (index, (field, attrs)) in fields.iter().zip(&attrs).enumerate() {
+        if attrs.skip || (any_key && !attrs.key) {
+            bindings.push(None);
+            continue;
+        }
+        let binding = format_ident!("__lodash_field_{}", index);
+        bindings.push(Some(binding.clone()));
+        key_fields.push(KeyField {
+            binding,
+            ty: field.ty.clone(),
+            descending: attrs.descending,
+        });
+    }
+
+    let pattern = match fields {
+        Fields::Named(named) => {
+            let entries = named
+                .named
+                .iter()
+                .zip(&bindings)
+                .filter_map(|(field, binding)| {
+                    let name = field.ident.as_ref()?;
+                    binding.as_ref().map(|binding| quote!(#name: #binding))
+                });
+            quote!(#path { #(#entries,)* .. })
+        }
+        Fields::Unnamed(_) => {
+            let entries = bindings.iter().map(|binding| {
+                binding
+                    .as_ref()
+                    .map_or_else(|| quote!(_), ToTokens::to_token_stream)
+            });
+            quote!(#path(#(#entries),*))
+        }
+        Fields::Unit => path,
+    };
+    Ok(Shape {
+        variant,
+        pattern,
+        fields: key_fields,
+    })
+}
+
+/// Add a `Type: Trait` bound for every field type that mentions a type
+/// parameter.
+fn bounded_generics(generics: &Generics, shapes: &[Shape], trait_path: &TokenStream2) -> Generics {
+    let params: Vec<&Ident> = generics.type_params().map(|param| &param.ident).collect();
+    let mut generics = generics.clone();
+    if params.is_empty() {
+        return generics;
+    }
+    let where_clause = generics.make_where_clause();
+    for field in shapes.iter().flat_map(|shape| &shape.fields) {
+        if mentions(field.ty.to_token_stream(), &params) {
+            let ty = &field.ty;
+            where_clause.predicates.push(parse_quote!(#ty: #trait_path));
+        }
+    }
+    generics
+}
+
+fn mentions(tokens: TokenStream2, params: &[&Ident]) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => params.iter().any(|param| **param == ident),
+        TokenTree::Group(group) => mentions(group.stream(), params),
+        _ => false,
+    })
+}
+
+fn to_key_body(name: &Ident, shapes: &[Shape]) -> TokenStream2 {
+    let trait_path = Trait::Key.path();
+    let arms = shapes.iter().map(|shape| {
+        let pattern = &shape.pattern;
+        let mut parts: Vec<TokenStream2> = Vec::new();
+        if let Some(variant) = &shape.variant {
+            let variant = variant.to_string();
+            parts.push(quote!(::std::string::String::from(#variant)));
+        }
+        parts.extend(shape.fields.iter().map(|field| {
+            let binding = &field.binding;
+            quote!(#trait_path::to_key(#binding))
+        }));
+        let key = match parts.len() {
+            0 => {
+                let name = name.to_string();
+                quote!(::std::string::String::from(#name))
+            }
+            1 => parts.remove(0),
+            _ => quote!(::rust_lodash::utils::type_conv::compose_key([#(#parts),*])),
+        };
+        quote!(#pattern => #key,)
+    });
+    quote! {
+        match self {
+            #(#arms)*
+        }
+    }
+}
+
+fn to_hash_key_body(shapes: &[Shape]) -> TokenStream2 {
+    let trait_path = Trait::HashKey.path();
+    let arms = shapes.iter().enumerate().map(|(index, shape)| {
+        let pattern = &shape.pattern;
+        let mut parts: Vec<TokenStream2> = Vec::new();
+        if shape.variant.is_some() {
+            let index = index as u64;
+            parts.push(quote!(#index));
+        }
+        parts.extend(shape.fields.iter().map(|field| {
+            let binding = &field.binding;
+            quote!(#trait_path::to_hash_key(#binding))
+        }));
+        let key = match parts.len() {
+            0 => quote!(0),
+            1 if shape.variant.is_none() => parts.remove(0),
+            _ => quote!(::rust_lodash::utils::type_conv::compose_hash_key([#(#parts),*])),
+        };
+        quote!(#pattern => #key,)
+    });
+    quote! {
+        match self {
+            #(#arms)*
+        }
+    }
+}
+
+fn to_comparable_body(
+    input: &DeriveInput,
+    shapes: &[Shape],
+) -> syn::Result<(TokenStream2, TokenStream2)> {
+    let trait_path = Trait::Comparable.path();
+    if let Data::Enum(data) = &input.data {
+        if let Some(variant) = data
+            .variants
+            .iter()
+            .find(|variant| !matches!(variant.fields, Fields::Unit))
+        {
+            return Err(syn::Error::new_spanned(
+                &variant.ident,
+                "ToComparable can only be derived for enums whose variants have no fields",
+            ));
+        }
+        let arms = shapes.iter().enumerate().map(|(index, shape)| {
+            let pattern = &shape.pattern;
+            let index = u32::try_from(index).unwrap_or(u32::MAX);
+            quote!(#pattern => #index,)
+        });
+        return Ok((
+            quote!(u32),
+            quote! {
+                match self {
+                    #(#arms)*
+                }
+            },
+        ));
+    }
+
+    let shape = &shapes[0];
+    if shape.fields.len() > MAX_COMPARABLE_FIELDS {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            format!(
+                "ToComparable compares at most {MAX_COMPARABLE_FIELDS} fields; \
+                 mark the ones to compare with `#[lodash(key)]`"
+            ),
+        ));
+    }
+    let (types, values): (Vec<_>, Vec<_>) = shape
+        .fields
+        .iter()
+        .map(|field| {
+            let ty = &field.ty;
+            let binding = &field.binding;
+            let output = quote!(<#ty as #trait_path>::Output);
+            let value = quote!(#trait_path::to_comparable(#binding));
+            if field.descending {
+                (
+                    quote!(::core::cmp::Reverse<#output>),
+                    quote!(::core::cmp::Reverse(#value)),
+                )
+            } else {
+                (output, value)
+            }
+        })
+        .unzip();
+    let (output, value) = match types.len() {
+        0 => return Ok((quote!(()), TokenStream2::new())),
+        1 => (types[0].clone(), values[0].clone()),
+        _ => (quote!((#(#types,)*)), quote!((#(#values,)*))),
+    };
+    let pattern = &shape.pattern;
+    Ok((
+        output,
+        quote! {
+            let #pattern = self;
+            #value
+        },
+    ))
+}
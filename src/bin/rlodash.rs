@@ -0,0 +1,257 @@
+/*!
+`rlodash`: a Lodash-style JSON processor for the command line.
+
+Reads JSON or NDJSON from a file or stdin, runs a [`Pipeline`] written in
+the command-line syntax over it and writes JSON, NDJSON or CSV:
+
+```text
+rlodash 'filter(matches({active: true})) | sort_by(age) | take(10) | map(name)' users.json
+```
+
+Build it with `cargo install rust-lodash --features cli`, adding `parallel`
+to decode and encode large NDJSON inputs on all cores.
+*/
+
+#![warn(clippy::all, clippy::pedantic)]
+
+use rust_lodash::chain::Pipeline;
+use rust_lodash::io::CsvWriter;
+use serde_json::Value;
+use std::collections::HashSet;
+use std::fs;
+use std::io::{self, BufWriter, Read, Write};
+use std::process::ExitCode;
+
+const USAGE: &str = "\
+Usage: rlodash [OPTIONS] [PIPELINE] [FILE]
+
+Runs PIPELINE over the JSON array or NDJSON lines in FILE (or stdin) and
+writes the result. Without a PIPELINE the input is only converted.
+
+PIPELINE is a list of steps separated by `|`, for example
+  'filter(age >= 18 && tags contains \"admin\") | sort_by(age, desc) | take(10) | map(name)'
+Steps: filter(expr), filter(matches(value)), map(expr), sort_by(path[, desc]),
+uniq_by(path), take(n), skip(n), reverse, group_by(path), count_by(path).
+
+Options:
+  -i, --input <json|ndjson>        Input format (default: a JSON array, else NDJSON)
+  -o, --output <json|ndjson|csv>   Output format (default: json)
+  -p, --pretty                     Pretty-print JSON output
+  -h, --help                       Show this help";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum InputFormat {
+    Auto,
+    Json,
+    Ndjson,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OutputFormat {
+    Json,
+    Ndjson,
+    Csv,
+}
+
+#[derive(Debug)]
+struct Options {
+    pipeline: Option<String>,
+    file: Option<String>,
+    input: InputFormat,
+    output: OutputFormat,
+    pretty: bool,
+}
+
+fn main() -> ExitCode {
+    let options = match parse_args(std::env::args().skip(1)) {
+        Ok(Some(options)) => options,
+        Ok(None) => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(message) => {
+            eprintln!("rlodash: {message}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+    match run(&options) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("rlodash: {message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Parse the arguments, or `None` if help was requested.
+fn parse_args(args: impl Iterator<Item = String>) -> Result<Option<Options>, String> {
+    let mut options = Options {
+        pipeline: None,
+        file: None,
+        input: InputFormat::Auto,
+        output: OutputFormat::Json,
+        pretty: false,
+    };
+    let mut positional = Vec::new();
+    let mut args = args.peekable();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "-p" | "--pretty" => options.pretty = true,
+            "-i" | "--input" => {
+                options.input = match args.next().as_deref() {
+                    Some("json") => InputFormat::Json,
+                    Some("ndjson") => InputFormat::Ndjson,
+                    other => return Err(format!("invalid input format {other:?}")),
+                }
+            }
+            "-o" | "--output" => {
+                options.output = match args.next().as_deref() {
+                    Some("json") => OutputFormat::Json,
+                    Some("ndjson") => OutputFormat::Ndjson,
+                    Some("csv") => OutputFormat::Csv,
+                    other => return Err(format!("invalid output format {other:?}")),
+                }
+            }
+            "--" => positional.extend(args.by_ref()),
+            flag if flag.starts_with('-') && flag != "-" => {
+                return Err(format!("unknown option `{flag}`"))
+            }
+            _ => positional.push(arg),
+        }
+    }
+    let mut positional = positional.into_iter();
+    options.pipeline = positional.next();
+    options.file = positional.next().filter(|file| file != "-");
+    if let Some(extra) = positional.next() {
+        return Err(format!("unexpected argument `{extra}`"));
+    }
+    Ok(Some(options))
+}
+
+fn run(options: &Options) -> Result<(), String> {
+    let pipeline = options
+        .pipeline
+        .as_deref()
+        .map(Pipeline::parse)
+        .transpose()
+        .map_err(|error| format!("invalid pipeline: {error:#}"))?;
+
+    let text = if let Some(file) = &options.file {
+        fs::read_to_string(file).map_err(|error| format!("{file}: {error}"))?
+    } else {
+        let mut text = String::new();
+        io::stdin()
+            .read_to_string(&mut text)
+            .map_err(|error| format!("stdin: {error}"))?;
+        text
+    };
+    let data = parse_input(&text, options.input)?;
+    let result = match pipeline {
+        Some(pipeline) => pipeline.run(&data).map_err(|error| format!("{error:#}"))?,
+        None => data,
+    };
+
+    let stdout = io::stdout();
+    let mut out = BufWriter::new(stdout.lock());
+    write_output(&mut out, &result, options)
+        .and_then(|()| out.flush())
+        .map_err(|error| format!("writing output: {error}"))
+}
+
+fn parse_input(text: &str, format: InputFormat) -> Result<Value, String> {
+    match format {
+        InputFormat::Json => {
+            serde_json::from_str(text).map_err(|error| format!("invalid JSON: {error}"))
+        }
+        InputFormat::Ndjson => parse_ndjson(text).map(Value::Array),
+        // A single value that is not an array is a one-line NDJSON stream.
+        InputFormat::Auto => match serde_json::from_str(text) {
+            Ok(Value::Array(values)) => Ok(Value::Array(values)),
+            Ok(value) => Ok(Value::Array(vec![value])),
+            Err(_) if text.trim().lines().nth(1).is_some() => {
+                parse_ndjson(text).map(Value::Array)
+            }
+            Err(error) => Err(format!("invalid JSON: {error}")),
+        },
+    }
+}
+
+/// Parse one JSON value per non-blank line, reporting 1-based line numbers.
+fn parse_ndjson(text: &str) -> Result<Vec<Value>, String> {
+    let lines: Vec<(usize, &str)> = text
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .collect();
+    let parse = |&(index, line): &(usize, &str)| {
+        serde_json::from_str::<Value>(line)
+            .map_err(|error| format!("line {}: invalid JSON: {error}", index + 1))
+    };
+    #[cfg(feature = "parallel")]
+    let values = rust_lodash::extensions::parallel::map_parallel(&lines, parse);
+    #[cfg(not(feature = "parallel"))]
+    let values: Vec<_> = lines.iter().map(parse).collect();
+    values.into_iter().collect()
+}
+
+fn write_output(out: &mut impl Write, result: &Value, options: &Options) -> io::Result<()> {
+    match options.output {
+        OutputFormat::Json if options.pretty => serde_json::to_writer_pretty(&mut *out, result)?,
+        OutputFormat::Json => serde_json::to_writer(&mut *out, result)?,
+        OutputFormat::Ndjson => {
+            let rows = match result {
+                Value::Array(rows) => rows.as_slice(),
+                other => std::slice::from_ref(other),
+            };
+            let encode = |row: &Value| row.to_string();
+            #[cfg(feature = "parallel")]
+            let lines = rust_lodash::extensions::parallel::map_parallel(rows, encode);
+            #[cfg(not(feature = "parallel"))]
+            let lines: Vec<String> = rows.iter().map(encode).collect();
+            for line in lines {
+                writeln!(out, "{line}")?;
+            }
+            return Ok(());
+        }
+        OutputFormat::Csv => return write_csv(out, result),
+    }
+    writeln!(out)
+}
+
+/// Write an array as CSV. Object rows contribute their keys as columns in
+/// first-seen order, and any other row fills a `value` column.
+fn write_csv(out: &mut impl Write, result: &Value) -> io::Result<()> {
+    let Value::Array(rows) = result else {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "CSV output needs an array result",
+        ));
+    };
+    let mut columns: Vec<&str> = Vec::new();
+    let mut seen = HashSet::new();
+    for row in rows {
+        let keys: Vec<&str> = match row {
+            Value::Object(object) => object.keys().map(String::as_str).collect(),
+            _ => vec!["value"],
+        };
+        for key in keys {
+            if seen.insert(key) {
+                columns.push(key);
+            }
+        }
+    }
+
+    let header = columns.iter().map(ToString::to_string).collect();
+    let mut writer = CsvWriter::with_header(out, header);
+    for row in rows {
+        let written = match row {
+            Value::Object(_) => writer.write(row),
+            other => writer.write(&serde_json::json!({ "value": other })),
+        };
+        written.map_err(|error| io::Error::new(io::ErrorKind::Other, format!("{error:#}")))?;
+    }
+    writer
+        .flush()
+        .map_err(|error| io::Error::new(io::ErrorKind::Other, format!("{error:#}")))
+}
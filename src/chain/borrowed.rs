@@ -0,0 +1,238 @@
+//! Borrowing chain module for chaining over references.
+//!
+//! [`chain`](crate::chain::chain) clones its input up front. A [`ChainRef`]
+//! instead filters, skips and takes references to the input, lazily, and
+//! only clones or maps the surviving elements into owned values at the end.
+
+use crate::chain::Chain;
+use crate::collection::source::Source;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+/// Create a borrowing chain over `data`. `T` need not be `Clone`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::chain::chain_ref;
+///
+/// struct User {
+///     name: String,
+///     age: u32,
+/// }
+///
+/// let users = vec![
+///     User { name: "ann".to_string(), age: 31 },
+///     User { name: "bob".to_string(), age: 17 },
+///     User { name: "cy".to_string(), age: 45 },
+/// ];
+/// let adults = chain_ref(&users)
+///     .filter(|u| u.age >= 18)
+///     .map(|u| u.name.clone())
+///     .value();
+/// assert_eq!(adults, vec!["ann", "cy"]);
+/// ```
+pub fn chain_ref<'a, S>(data: S) -> ChainRef<'a, S::Item>
+where
+    S: Source<'a>,
+    S::Iter: 'a,
+{
+    ChainRef::new(data)
+}
+
+/// Chain wrapper over references to the elements of a source.
+///
+/// Steps run lazily as iterator adapters; nothing is evaluated until a
+/// terminal method (`value`, `collect`, `cloned` or `map`) is called.
+pub struct ChainRef<'a, T> {
+    /// The pending references
+    items: Box<dyn Iterator<Item = &'a T> + 'a>,
+}
+
+impl<'a, T> ChainRef<'a, T> {
+    /// Create a new borrowing chain over `data`.
+    pub fn new<S>(data: S) -> Self
+    where
+        S: Source<'a, Item = T>,
+        S::Iter: 'a,
+    {
+        Self {
+            items: Box::new(data.source_iter()),
+        }
+    }
+
+    /// Keep the elements the predicate returns truthy for.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::chain::chain_ref;
+    ///
+    /// let numbers = vec![1, 2, 3, 4];
+    /// assert_eq!(chain_ref(&numbers).filter(|x| *x > 2).value(), vec![&3, &4]);
+    /// ```
+    #[must_use]
+    pub fn filter<F>(self, predicate: F) -> Self
+    where
+        F: Fn(&T) -> bool + 'a,
+    {
+        Self {
+            items: Box::new(self.items.filter(move |item| predicate(item))),
+        }
+    }
+
+    /// Take the first n elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::chain::chain_ref;
+    ///
+    /// assert_eq!(chain_ref(&[1, 2, 3]).take(2).value(), vec![&1, &2]);
+    /// ```
+    #[must_use]
+    pub fn take(self, n: usize) -> Self {
+        Self {
+            items: Box::new(self.items.take(n)),
+        }
+    }
+
+    /// Skip the first n elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::chain::chain_ref;
+    ///
+    /// assert_eq!(chain_ref(&[1, 2, 3]).skip(2).value(), vec![&3]);
+    /// ```
+    #[must_use]
+    pub fn skip(self, n: usize) -> Self {
+        Self {
+            items: Box::new(self.items.skip(n)),
+        }
+    }
+
+    /// Reverse the order of elements. This collects the pending references.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::chain::chain_ref;
+    ///
+    /// assert_eq!(chain_ref(&[1, 2, 3]).reverse().value(), vec![&3, &2, &1]);
+    /// ```
+    #[must_use]
+    pub fn reverse(self) -> Self {
+        let items: Vec<&'a T> = self.items.collect();
+        Self {
+            items: Box::new(items.into_iter().rev()),
+        }
+    }
+
+    /// Get references to the remaining elements.
+    #[must_use]
+    pub fn value(self) -> Vec<&'a T> {
+        self.items.collect()
+    }
+
+    /// Alias for `value`.
+    #[must_use]
+    pub fn collect(self) -> Vec<&'a T> {
+        self.value()
+    }
+
+    /// Map the remaining elements into owned values and continue with an
+    /// owning chain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::chain::chain_ref;
+    ///
+    /// let words = vec!["a".to_string(), "bb".to_string(), "ccc".to_string()];
+    /// let lengths = chain_ref(&words).skip(1).map(String::len).reverse().value();
+    /// assert_eq!(lengths, vec![3, 2]);
+    /// ```
+    #[must_use]
+    pub fn map<U, F>(self, mapper: F) -> Chain<U>
+    where
+        F: Fn(&T) -> U,
+    {
+        Chain::from_vec(self.items.map(mapper).collect())
+    }
+}
+
+impl<T> ChainRef<'_, T>
+where
+    T: Clone,
+{
+    /// Clone the remaining elements and continue with an owning chain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::chain::chain_ref;
+    ///
+    /// let numbers = vec![5, 1, 4, 2];
+    /// let result = chain_ref(&numbers)
+    ///     .filter(|x| x % 2 == 0)
+    ///     .cloned()
+    ///     .map(|x| x * 10)
+    ///     .value();
+    /// assert_eq!(result, vec![40, 20]);
+    /// ```
+    #[must_use]
+    pub fn cloned(self) -> Chain<T> {
+        Chain::from_vec(self.items.cloned().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::collections::BTreeSet;
+    use alloc::vec;
+    use core::cell::Cell;
+
+    // Deliberately not `Clone`.
+    #[derive(Debug, PartialEq)]
+    struct Big {
+        id: usize,
+    }
+
+    #[test]
+    fn test_chain_ref_without_clone() {
+        let items: Vec<Big> = (0..10).map(|id| Big { id }).collect();
+        let picked = chain_ref(&items)
+            .filter(|b| b.id % 3 == 0)
+            .skip(1)
+            .take(2)
+            .reverse()
+            .value();
+        assert_eq!(picked, vec![&items[6], &items[3]]);
+    }
+
+    #[test]
+    fn test_chain_ref_is_lazy() {
+        let calls = Cell::new(0);
+        let numbers: Vec<u32> = (0..1000).collect();
+        let first = chain_ref(&numbers)
+            .filter(|x| {
+                calls.set(calls.get() + 1);
+                x % 2 == 1
+            })
+            .take(2)
+            .cloned()
+            .value();
+        assert_eq!(first, vec![1, 3]);
+        assert_eq!(calls.get(), 4);
+    }
+
+    #[test]
+    fn test_chain_ref_over_other_sources() {
+        let set = BTreeSet::from([3, 1, 2]);
+        assert_eq!(chain_ref(&set).map(|x| x * 2).value(), vec![2, 4, 6]);
+        assert_eq!(chain_ref(set.iter()).skip(2).value(), vec![&3]);
+    }
+}
@@ -1,6 +1,8 @@
 //! Chain builder module for constructing complex operation chains.
 
-use crate::chain::{Chain, Operation};
+use crate::chain::{Chain, Input, Operation};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
 
 #[cfg(feature = "async")]
 use crate::chain::{AsyncChain, AsyncOperation};
@@ -45,7 +47,7 @@ impl<T> ChainBuilder<T> {
     #[must_use]
     pub fn build(self) -> Chain<T> {
         Chain {
-            data: self.data,
+            input: Input::Data(self.data),
             operations: self.operations,
         }
     }
@@ -72,7 +74,7 @@ impl<T> AsyncChainBuilder<T> {
     pub fn map_async<F, Fut>(mut self, mapper: F) -> Self
     where
         F: Fn(&T) -> Fut + Send + Sync + 'static,
-        Fut: std::future::Future<Output = T> + Send + Sync + 'static,
+        Fut: core::future::Future<Output = T> + Send + Sync + 'static,
     {
         self.operations.push(AsyncOperation::MapAsync(Box::new(move |x| {
             Box::pin(mapper(x))
@@ -84,7 +86,7 @@ impl<T> AsyncChainBuilder<T> {
     pub fn filter_async<F, Fut>(mut self, predicate: F) -> Self
     where
         F: Fn(&T) -> Fut + Send + Sync + 'static,
-        Fut: std::future::Future<Output = bool> + Send + Sync + 'static,
+        Fut: core::future::Future<Output = bool> + Send + Sync + 'static,
     {
         self.operations.push(AsyncOperation::FilterAsync(Box::new(move |x| {
             Box::pin(predicate(x))
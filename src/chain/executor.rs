@@ -1,6 +1,7 @@
 //! Chain executor module for executing operation chains.
 
 use crate::chain::{Chain, Operation};
+use alloc::vec::Vec;
 
 #[cfg(feature = "async")]
 use crate::chain::{AsyncChain, AsyncOperation};
@@ -20,7 +21,7 @@ impl<T> ChainExecutor<T> {
     /// Execute the chain and return the result.
     #[must_use]
     pub fn execute(self) -> Vec<T> {
-        let mut result = self.chain.data;
+        let mut result = self.chain.input.into_vec();
         
         for operation in self.chain.operations {
             match operation {
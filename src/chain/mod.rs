@@ -5,14 +5,32 @@ This module provides the fluent method chaining system that allows
 composing multiple operations in a readable and efficient way.
 */
 
+pub mod borrowed;
 pub mod builder;
 pub mod executor;
+#[cfg(feature = "serialize")]
+pub mod pipeline;
 
-use crate::collection::Collection;
-// Note: These imports are kept for future use in error handling
-// use crate::utils::{LodashError, Result};
+pub use borrowed::{chain_ref, ChainRef};
+#[cfg(feature = "serialize")]
+pub use pipeline::Pipeline;
+
+use crate::collection::source::Source;
+use crate::collection::transform::{self, NanPolicy};
+use crate::collection::{join, window, Collection};
+#[cfg(feature = "std")]
+use crate::math::statistics::{self, Bins, Histogram, PercentileMethod};
+use crate::math::Numeric;
+use crate::utils::hash::HashSet;
+#[cfg(feature = "std")]
+use crate::utils::Result;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::hash::Hash;
 
 /// Create a chain wrapper that enables method chaining.
+///
+/// The elements are cloned into the chain; see [`Chain::new`].
 /// 
 /// # Examples
 /// 
@@ -24,14 +42,72 @@ use crate::collection::Collection;
 ///     .map(|x| x * 3)
 ///     .collect();
 /// assert_eq!(result, vec![6, 12]);
+///
+/// // Any source works, such as a set or a map's values
+/// let stock = std::collections::BTreeMap::from([("fig", 3), ("kiwi", 0), ("pear", 7)]);
+/// let available = chain(stock.values()).filter(|n| *n > 0).collect();
+/// assert_eq!(available, vec![3, 7]);
 /// ```
-pub fn chain<T>(data: &[T]) -> Chain<T>
+pub fn chain<'a, S>(data: S) -> Chain<S::Item>
 where
-    T: Clone,
+    S: Source<'a>,
+    S::Item: Clone,
 {
     Chain::new(data)
 }
 
+/// Create a chain that owns `data`. Unlike [`chain`] nothing is cloned, so
+/// `T` need not be `Clone`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::chain::chain_owned;
+///
+/// struct Job {
+///     id: u32,
+///     done: bool,
+/// }
+///
+/// let jobs = vec![Job { id: 1, done: true }, Job { id: 2, done: false }];
+/// let pending = chain_owned(jobs).filter(|job| !job.done).value();
+/// assert_eq!(pending.len(), 1);
+/// assert_eq!(pending[0].id, 2);
+/// ```
+#[must_use]
+pub fn chain_owned<T>(data: Vec<T>) -> Chain<T> {
+    Chain::from_vec(data)
+}
+
+/// Create a chain that pulls its elements lazily from `iter`, for example
+/// records streamed from a file.
+///
+/// Leading `map`, `filter`, `take` and `skip` steps run as elements are
+/// pulled, so only the elements that survive them are held in memory, and a
+/// `take` stops reading early. Later steps, and steps after a `reverse`, run
+/// on the collected elements as usual.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::chain::chain_iter;
+///
+/// // An endless source is fine as long as a `take` bounds it
+/// let result = chain_iter(1..).filter(|x| x % 3 == 0).map(|x| x * 2).take(3).value();
+/// assert_eq!(result, vec![6, 12, 18]);
+/// ```
+#[must_use]
+pub fn chain_iter<I>(iter: I) -> Chain<I::Item>
+where
+    I: IntoIterator,
+    I::IntoIter: Send + Sync + 'static,
+{
+    Chain {
+        input: Input::Lazy(Box::new(iter.into_iter())),
+        operations: Vec::new(),
+    }
+}
+
 /// Create an async chain wrapper that enables async method chaining.
 /// 
 /// # Examples
@@ -61,11 +137,53 @@ where
 /// Operations are lazily evaluated and only executed when `collect()` or `value()` is called.
 pub struct Chain<T> {
     /// The underlying data
-    data: Vec<T>,
+    input: Input<T>,
     /// Operations to be applied
     operations: Vec<Operation<T>>,
 }
 
+/// Where a chain's input comes from: materialized data, a deferred
+/// upstream chain whose element type differs, or a lazy iterator, evaluated
+/// on `value()`.
+pub(crate) enum Input<T> {
+    /// Materialized data
+    Data(Vec<T>),
+    /// Deferred upstream evaluation
+    Deferred(Box<dyn FnOnce() -> Vec<T> + Send + Sync>),
+    /// Elements pulled on demand
+    Lazy(Box<dyn Iterator<Item = T> + Send + Sync>),
+}
+
+impl<T> Input<T> {
+    /// Produce the input data.
+    pub(crate) fn into_vec(self) -> Vec<T> {
+        match self {
+            Self::Data(data) => data,
+            Self::Deferred(produce) => produce(),
+            Self::Lazy(iter) => iter.collect(),
+        }
+    }
+}
+
+/// Apply the leading streaming operations (everything before the first
+/// `reverse`) to `iter` as it is pulled, and collect the survivors.
+fn collect_streaming<'a, T: 'a>(
+    iter: Box<dyn Iterator<Item = T> + 'a>,
+    operations: &mut core::iter::Peekable<alloc::vec::IntoIter<Operation<T>>>,
+) -> Vec<T> {
+    let mut iter = iter;
+    while let Some(operation) = operations.next_if(|operation| !matches!(operation, Operation::Reverse)) {
+        iter = match operation {
+            Operation::Map(mapper) => Box::new(iter.map(move |x| mapper(&x))),
+            Operation::Filter(predicate) => Box::new(iter.filter(move |x| predicate(x))),
+            Operation::Take(n) => Box::new(iter.take(n)),
+            Operation::Skip(n) => Box::new(iter.skip(n)),
+            Operation::Reverse => unreachable!("`reverse` ends the streaming prefix"),
+        };
+    }
+    iter.collect()
+}
+
 /// Async chain wrapper for asynchronous operations.
 #[cfg(feature = "async")]
 pub struct AsyncChain<T> {
@@ -93,9 +211,9 @@ pub enum Operation<T> {
 #[cfg(feature = "async")]
 pub enum AsyncOperation<T> {
     /// Async map operation
-    MapAsync(Box<dyn Fn(&T) -> std::pin::Pin<Box<dyn std::future::Future<Output = T> + Send + Sync>> + Send + Sync>),
+    MapAsync(Box<dyn Fn(&T) -> core::pin::Pin<Box<dyn core::future::Future<Output = T> + Send + Sync>> + Send + Sync>),
     /// Async filter operation
-    FilterAsync(Box<dyn Fn(&T) -> std::pin::Pin<Box<dyn std::future::Future<Output = bool> + Send + Sync>> + Send + Sync>),
+    FilterAsync(Box<dyn Fn(&T) -> core::pin::Pin<Box<dyn core::future::Future<Output = bool> + Send + Sync>> + Send + Sync>),
     /// Take operation
     Take(usize),
     /// Skip operation
@@ -104,14 +222,26 @@ pub enum AsyncOperation<T> {
     Reverse,
 }
 
-impl<T> Chain<T>
-where
-    T: Clone,
-{
+impl<T> Chain<T> {
     /// Create a new chain with the given data.
-    pub fn new(data: &[T]) -> Self {
+    ///
+    /// A `Chain` owns its elements, so they are cloned from the source into
+    /// the chain when it is created. To work on borrowed data without
+    /// cloning, use [`chain_ref`] or build a chain of references with
+    /// [`chain_owned`]; [`chain_iter`] pulls owned elements on demand.
+    pub fn new<'a, S>(data: S) -> Self
+    where
+        S: Source<'a, Item = T>,
+        T: Clone + 'a,
+    {
+        Self::from_vec(data.source_iter().cloned().collect())
+    }
+
+    /// Create a new chain that owns `data`. Nothing is cloned.
+    #[must_use]
+    pub fn from_vec(data: Vec<T>) -> Self {
         Self {
-            data: data.to_vec(),
+            input: Input::Data(data),
             operations: Vec::new(),
         }
     }
@@ -243,9 +373,13 @@ where
     /// ```
     #[must_use]
     pub fn value(self) -> Vec<T> {
-        let mut result = self.data;
+        let mut operations = self.operations.into_iter().peekable();
+        let mut result = match self.input {
+            Input::Lazy(iter) => collect_streaming(iter, &mut operations),
+            input => input.into_vec(),
+        };
         
-        for operation in self.operations {
+        for operation in operations {
             match operation {
                 Operation::Map(mapper) => {
                     result = result.into_iter().map(|x| mapper(&x)).collect();
@@ -286,6 +420,621 @@ where
     }
 }
 
+/// Statistical terminal operations.
+#[cfg(feature = "std")]
+impl<T> Chain<T> {
+    /// Apply all operations and compute the median of the values generated
+    /// by `iteratee`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LodashError::EmptyCollection` if there are no non-`NaN` values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::chain::chain;
+    ///
+    /// let median = chain(&[1, 2, 3, 4, 5, 6])
+    ///     .filter(|x| x % 2 == 0)
+    ///     .median_by(|x| *x)
+    ///     .unwrap();
+    /// assert_eq!(median, 4.0);
+    /// ```
+    pub fn median_by<N, F>(self, iteratee: F) -> Result<f64>
+    where
+        N: Numeric,
+        F: Fn(&T) -> N,
+    {
+        statistics::median_by(&self.value(), iteratee)
+    }
+
+    /// Apply all operations and compute the `percentile` of the values
+    /// generated by `iteratee`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LodashError::InvalidInput` if `percentile` is not between 0
+    /// and 100, and `LodashError::EmptyCollection` if there are no non-`NaN`
+    /// values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::chain::chain;
+    /// use rust_lodash::math::PercentileMethod;
+    ///
+    /// let p90 = chain(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10])
+    ///     .percentile_by(90.0, PercentileMethod::NearestRank, |x| *x)
+    ///     .unwrap();
+    /// assert_eq!(p90, 9.0);
+    /// ```
+    pub fn percentile_by<N, F>(
+        self,
+        percentile: f64,
+        method: PercentileMethod,
+        iteratee: F,
+    ) -> Result<f64>
+    where
+        N: Numeric,
+        F: Fn(&T) -> N,
+    {
+        statistics::percentile_by(&self.value(), percentile, method, iteratee)
+    }
+
+    /// Apply all operations and compute the population variance of the
+    /// values generated by `iteratee`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LodashError::EmptyCollection` if there are no non-`NaN` values.
+    pub fn variance_by<N, F>(self, iteratee: F) -> Result<f64>
+    where
+        N: Numeric,
+        F: Fn(&T) -> N,
+    {
+        statistics::variance_by(&self.value(), iteratee)
+    }
+
+    /// Apply all operations and compute the population standard deviation of
+    /// the values generated by `iteratee`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LodashError::EmptyCollection` if there are no non-`NaN` values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::chain::chain;
+    ///
+    /// let std_dev = chain(&[2, 4, 4, 4, 5, 5, 7, 9]).std_dev_by(|x| *x).unwrap();
+    /// assert_eq!(std_dev, 2.0);
+    /// ```
+    pub fn std_dev_by<N, F>(self, iteratee: F) -> Result<f64>
+    where
+        N: Numeric,
+        F: Fn(&T) -> N,
+    {
+        statistics::std_dev_by(&self.value(), iteratee)
+    }
+
+    /// Apply all operations and find the most frequent key generated by
+    /// `iteratee`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LodashError::EmptyCollection` if no elements remain.
+    pub fn mode_by<K, F>(self, iteratee: F) -> Result<K>
+    where
+        K: Eq + Hash,
+        F: Fn(&T) -> K,
+    {
+        statistics::mode_by(&self.value(), iteratee)
+    }
+
+    /// Apply all operations and build a histogram of the values generated by
+    /// `iteratee`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LodashError::InvalidInput` for invalid `bins`, and
+    /// `LodashError::EmptyCollection` if there are no non-`NaN` values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::chain::chain;
+    /// use rust_lodash::math::Bins;
+    ///
+    /// let histogram = chain(&[1, 2, 3, 4, 10])
+    ///     .filter(|x| *x < 10)
+    ///     .histogram_by(Bins::Width(2.0), |x| *x)
+    ///     .unwrap();
+    /// assert_eq!(histogram.counts, vec![2, 2]);
+    /// ```
+    pub fn histogram_by<N, F>(self, bins: Bins, iteratee: F) -> Result<Histogram>
+    where
+        N: Numeric,
+        F: Fn(&T) -> N,
+    {
+        statistics::histogram_by(&self.value(), bins, iteratee)
+    }
+}
+
+/// Relational join steps. These are deferred like the window steps: `right`
+/// is copied (or, for `semi_join_by` and `anti_join_by`, only its keys are
+/// kept) and the join runs when the resulting chain is evaluated.
+impl<T> Chain<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    /// Inner join with `right` by key. See [`join::join_by`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::chain::chain;
+    ///
+    /// let names = vec![(1, "ann"), (2, "bob")];
+    /// let result = chain(&[1, 2, 3])
+    ///     .filter(|x| *x > 1)
+    ///     .join_by(&names, |x| *x, |n| n.0)
+    ///     .map(|(x, n)| (x * 10, *n))
+    ///     .value();
+    /// assert_eq!(result, vec![(20, (2, "bob"))]);
+    /// ```
+    #[must_use]
+    pub fn join_by<B, K, FA, FB>(self, right: &[B], left_key: FA, right_key: FB) -> Chain<(T, B)>
+    where
+        B: Clone + Send + Sync + 'static,
+        K: Hash + Eq,
+        FA: Fn(&T) -> K + Send + Sync + 'static,
+        FB: Fn(&B) -> K + Send + Sync + 'static,
+    {
+        let right = right.to_vec();
+        self.then(move |values| join::join_by(&values, &right, left_key, right_key))
+    }
+
+    /// Left join with `right` by key. See [`join::left_join_by`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::chain::chain;
+    ///
+    /// let result = chain(&[1, 2]).left_join_by(&[2], |x| *x, |x| *x).value();
+    /// assert_eq!(result, vec![(1, None), (2, Some(2))]);
+    /// ```
+    #[must_use]
+    pub fn left_join_by<B, K, FA, FB>(
+        self,
+        right: &[B],
+        left_key: FA,
+        right_key: FB,
+    ) -> Chain<(T, Option<B>)>
+    where
+        B: Clone + Send + Sync + 'static,
+        K: Hash + Eq,
+        FA: Fn(&T) -> K + Send + Sync + 'static,
+        FB: Fn(&B) -> K + Send + Sync + 'static,
+    {
+        let right = right.to_vec();
+        self.then(move |values| join::left_join_by(&values, &right, left_key, right_key))
+    }
+
+    /// Full outer join with `right` by key. See [`join::outer_join_by`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::chain::chain;
+    ///
+    /// let result = chain(&[1, 2]).outer_join_by(&[2, 3], |x| *x, |x| *x).value();
+    /// assert_eq!(result, vec![(Some(1), None), (Some(2), Some(2)), (None, Some(3))]);
+    /// ```
+    #[must_use]
+    pub fn outer_join_by<B, K, FA, FB>(
+        self,
+        right: &[B],
+        left_key: FA,
+        right_key: FB,
+    ) -> Chain<(Option<T>, Option<B>)>
+    where
+        B: Clone + Send + Sync + 'static,
+        K: Hash + Eq,
+        FA: Fn(&T) -> K + Send + Sync + 'static,
+        FB: Fn(&B) -> K + Send + Sync + 'static,
+    {
+        let right = right.to_vec();
+        self.then(move |values| join::outer_join_by(&values, &right, left_key, right_key))
+    }
+
+    /// Keep the elements with a match in `right`. See [`join::semi_join_by`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::chain::chain;
+    ///
+    /// let result = chain(&[1, 2, 3]).semi_join_by(&[3, 1], |x| *x, |x| *x).value();
+    /// assert_eq!(result, vec![1, 3]);
+    /// ```
+    #[must_use]
+    pub fn semi_join_by<B, K, FA, FB>(self, right: &[B], left_key: FA, right_key: FB) -> Self
+    where
+        K: Hash + Eq + Send + Sync + 'static,
+        FA: Fn(&T) -> K + Send + Sync + 'static,
+        FB: Fn(&B) -> K,
+    {
+        let keys: HashSet<K> = right.iter().map(right_key).collect();
+        self.then(move |values| {
+            values
+                .into_iter()
+                .filter(|item| keys.contains(&left_key(item)))
+                .collect()
+        })
+    }
+
+    /// Keep the elements without a match in `right`. See
+    /// [`join::anti_join_by`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::chain::chain;
+    ///
+    /// let result = chain(&[1, 2, 3]).anti_join_by(&[3, 1], |x| *x, |x| *x).value();
+    /// assert_eq!(result, vec![2]);
+    /// ```
+    #[must_use]
+    pub fn anti_join_by<B, K, FA, FB>(self, right: &[B], left_key: FA, right_key: FB) -> Self
+    where
+        K: Hash + Eq + Send + Sync + 'static,
+        FA: Fn(&T) -> K + Send + Sync + 'static,
+        FB: Fn(&B) -> K,
+    {
+        let keys: HashSet<K> = right.iter().map(right_key).collect();
+        self.then(move |values| {
+            values
+                .into_iter()
+                .filter(|item| !keys.contains(&left_key(item)))
+                .collect()
+        })
+    }
+}
+
+/// Window steps. These change the element type, so they are deferred: the
+/// upstream chain runs only when the resulting chain is evaluated.
+impl<T> Chain<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    fn then<U, F>(self, step: F) -> Chain<U>
+    where
+        F: FnOnce(Vec<T>) -> Vec<U> + Send + Sync + 'static,
+    {
+        Chain {
+            input: Input::Deferred(Box::new(move || step(self.value()))),
+            operations: Vec::new(),
+        }
+    }
+
+    /// Every contiguous window of `size` elements. See [`window::windows`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::chain::chain;
+    ///
+    /// let sums = chain(&[1, 2, 3, 4])
+    ///     .windows(2)
+    ///     .map(|w| vec![w.iter().sum()])
+    ///     .value();
+    /// assert_eq!(sums, vec![vec![3], vec![5], vec![7]]);
+    /// ```
+    #[must_use]
+    pub fn windows(self, size: usize) -> Chain<Vec<T>> {
+        self.then(move |values| window::windows(&values, size))
+    }
+
+    /// Windows of `size` elements starting every `step` elements. See
+    /// [`window::sliding`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::chain::chain;
+    ///
+    /// let result = chain(&[1, 2, 3, 4, 5]).sliding(2, 2).value();
+    /// assert_eq!(result, vec![vec![1, 2], vec![3, 4]]);
+    /// ```
+    #[must_use]
+    pub fn sliding(self, size: usize, step: usize) -> Chain<Vec<T>> {
+        self.then(move |values| window::sliding(&values, size, step))
+    }
+
+    /// Runs of consecutive elements with the same key. See
+    /// [`window::chunk_by`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::chain::chain;
+    ///
+    /// let runs = chain(&[1, 1, 2, 1]).chunk_by(|x| *x).value();
+    /// assert_eq!(runs, vec![vec![1, 1], vec![2], vec![1]]);
+    /// ```
+    #[must_use]
+    pub fn chunk_by<K, F>(self, key: F) -> Chain<Vec<T>>
+    where
+        K: PartialEq,
+        F: Fn(&T) -> K + Send + Sync + 'static,
+    {
+        self.then(move |values| window::chunk_by(&values, key))
+    }
+
+    /// Every intermediate accumulator of a `reduce`. See [`window::scan`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::chain::chain;
+    ///
+    /// let running = chain(&[1, 2, 3, 4])
+    ///     .filter(|x| x % 2 == 0)
+    ///     .scan(0, |acc, x| acc + x)
+    ///     .value();
+    /// assert_eq!(running, vec![2, 6]);
+    /// ```
+    #[must_use]
+    pub fn scan<U, F>(self, initial: U, iteratee: F) -> Chain<U>
+    where
+        U: Clone + Send + Sync + 'static,
+        F: Fn(&U, &T) -> U + Send + Sync + 'static,
+    {
+        self.then(move |values| window::scan(&values, initial, iteratee))
+    }
+
+    /// Like `scan`, starting from the first element. See
+    /// [`window::reduce_scan`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::chain::chain;
+    ///
+    /// let maxima = chain(&[2, 1, 5, 3]).reduce_scan(|a, b| *a.max(b)).value();
+    /// assert_eq!(maxima, vec![2, 2, 5, 5]);
+    /// ```
+    #[must_use]
+    pub fn reduce_scan<F>(self, iteratee: F) -> Self
+    where
+        F: Fn(&T, &T) -> T + Send + Sync + 'static,
+    {
+        self.then(move |values| window::reduce_scan(&values, iteratee))
+    }
+
+    /// Pairs of each element and the one after it. See
+    /// [`window::pairwise`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::chain::chain;
+    ///
+    /// let deltas = chain(&[1, 4, 9])
+    ///     .pairwise()
+    ///     .map(|(a, b)| (*b - *a, 0))
+    ///     .value();
+    /// assert_eq!(deltas, vec![(3, 0), (5, 0)]);
+    /// ```
+    #[must_use]
+    pub fn pairwise(self) -> Chain<(T, T)> {
+        self.then(|values| window::pairwise(&values))
+    }
+
+    /// Sums of every contiguous window of `window` values. See
+    /// [`window::rolling_sum`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::chain::chain;
+    ///
+    /// let sums = chain(&[1, 2, 3, 4]).rolling_sum(3).value();
+    /// assert_eq!(sums, vec![6, 9]);
+    /// ```
+    #[must_use]
+    pub fn rolling_sum(self, window: usize) -> Self
+    where
+        T: Numeric,
+    {
+        self.then(move |values| window::rolling_sum(&values, window))
+    }
+
+    /// Means of every contiguous window of `window` values. See
+    /// [`window::rolling_mean`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::chain::chain;
+    ///
+    /// let means = chain(&[1, 2, 3, 4]).rolling_mean(2).take(2).value();
+    /// assert_eq!(means, vec![1.5, 2.5]);
+    /// ```
+    #[must_use]
+    pub fn rolling_mean(self, window: usize) -> Chain<f64>
+    where
+        T: Numeric,
+    {
+        self.then(move |values| window::rolling_mean(&values, window))
+    }
+}
+
+/// Sort steps. Sorting needs every element, so these are deferred like the
+/// window steps.
+impl<T> Chain<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    /// Sort by an `Ord` key, keeping equal elements in order. See
+    /// [`transform::sort_by`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::chain::chain;
+    ///
+    /// let sorted = chain(&["ccc", "a", "bb"]).sort_by(|s| s.len()).value();
+    /// assert_eq!(sorted, vec!["a", "bb", "ccc"]);
+    /// ```
+    #[must_use]
+    pub fn sort_by<K, F>(self, iteratee: F) -> Self
+    where
+        K: Ord,
+        F: Fn(&T) -> K + Send + Sync + 'static,
+    {
+        self.then(move |mut values| {
+            values.sort_by_key(iteratee);
+            values
+        })
+    }
+
+    /// Sort by a `PartialOrd` key, placing NaN keys as `nan` says. See
+    /// [`transform::sort_by_partial`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::chain::chain;
+    /// use rust_lodash::collection::transform::NanPolicy;
+    ///
+    /// let sorted = chain(&[0.5, f64::NAN, -2.0])
+    ///     .sort_by_partial(|x| *x, NanPolicy::Last)
+    ///     .take(2)
+    ///     .value();
+    /// assert_eq!(sorted, vec![-2.0, 0.5]);
+    /// ```
+    #[must_use]
+    pub fn sort_by_partial<K, F>(self, iteratee: F, nan: NanPolicy) -> Self
+    where
+        K: PartialOrd,
+        F: Fn(&T) -> K + Send + Sync + 'static,
+    {
+        self.then(move |mut values| {
+            values.sort_by(|a, b| {
+                transform::compare_partial(&iteratee(a), &iteratee(b), nan, true)
+            });
+            values
+        })
+    }
+
+    /// Sort by a float key, NaN keys last. See
+    /// [`transform::sort_by_float`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::chain::chain;
+    ///
+    /// let top = chain(&[("a", 0.2), ("b", 0.9), ("c", 0.5)])
+    ///     .sort_by_float(|(_, score)| -score)
+    ///     .map(|(name, _)| (*name, 0.0))
+    ///     .take(2)
+    ///     .value();
+    /// assert_eq!(top, vec![("b", 0.0), ("c", 0.0)]);
+    /// ```
+    #[must_use]
+    pub fn sort_by_float<F>(self, iteratee: F) -> Self
+    where
+        F: Fn(&T) -> f64 + Send + Sync + 'static,
+    {
+        self.then(move |mut values| {
+            values.sort_by(|a, b| transform::compare_float(iteratee(a), iteratee(b), true));
+            values
+        })
+    }
+
+    /// Order by a `PartialOrd` key in either direction, placing NaN keys as
+    /// `nan` says. See [`transform::order_by_partial`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::chain::chain;
+    /// use rust_lodash::collection::transform::NanPolicy;
+    ///
+    /// let sorted = chain(&[0.5, f64::NAN, -2.0])
+    ///     .order_by_partial(|x| *x, false, NanPolicy::Last)
+    ///     .take(2)
+    ///     .value();
+    /// assert_eq!(sorted, vec![0.5, -2.0]);
+    /// ```
+    #[must_use]
+    pub fn order_by_partial<K, F>(self, iteratee: F, ascending: bool, nan: NanPolicy) -> Self
+    where
+        K: PartialOrd,
+        F: Fn(&T) -> K + Send + Sync + 'static,
+    {
+        self.then(move |mut values| {
+            values.sort_by(|a, b| {
+                transform::compare_partial(&iteratee(a), &iteratee(b), nan, ascending)
+            });
+            values
+        })
+    }
+
+    /// Order by a float key in either direction, NaN last. See
+    /// [`transform::order_by_float`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::chain::chain;
+    ///
+    /// let top = chain(&[("a", 0.2), ("b", f64::NAN), ("c", 0.5)])
+    ///     .order_by_float(|(_, score)| *score, false)
+    ///     .take(2)
+    ///     .value();
+    /// assert_eq!(top, vec![("c", 0.5), ("a", 0.2)]);
+    /// ```
+    #[must_use]
+    pub fn order_by_float<F>(self, iteratee: F, ascending: bool) -> Self
+    where
+        F: Fn(&T) -> f64 + Send + Sync + 'static,
+    {
+        self.then(move |mut values| {
+            values.sort_by(|a, b| transform::compare_float(iteratee(a), iteratee(b), ascending));
+            values
+        })
+    }
+
+    /// Sort by a key computed once per element. See
+    /// [`transform::sort_by_cached_key`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::chain::chain;
+    ///
+    /// let sorted = chain(&[3, 20, 100]).sort_by_cached_key(|x| x.to_string()).value();
+    /// assert_eq!(sorted, vec![100, 20, 3]);
+    /// ```
+    #[must_use]
+    pub fn sort_by_cached_key<K, F>(self, iteratee: F) -> Self
+    where
+        K: Ord,
+        F: Fn(&T) -> K + Send + Sync + 'static,
+    {
+        self.then(move |mut values| {
+            values.sort_by_cached_key(iteratee);
+            values
+        })
+    }
+}
+
 #[cfg(feature = "async")]
 impl<T> AsyncChain<T>
 where
@@ -316,7 +1065,7 @@ where
     pub fn map_async<F, Fut>(mut self, mapper: F) -> Self
     where
         F: Fn(&T) -> Fut + Send + Sync + 'static,
-        Fut: std::future::Future<Output = T> + Send + Sync + 'static,
+        Fut: core::future::Future<Output = T> + Send + Sync + 'static,
     {
         self.operations.push(AsyncOperation::MapAsync(Box::new(move |x| {
             Box::pin(mapper(x))
@@ -341,7 +1090,7 @@ where
     pub fn filter_async<F, Fut>(mut self, predicate: F) -> Self
     where
         F: Fn(&T) -> Fut + Send + Sync + 'static,
-        Fut: std::future::Future<Output = bool> + Send + Sync + 'static,
+        Fut: core::future::Future<Output = bool> + Send + Sync + 'static,
     {
         self.operations.push(AsyncOperation::FilterAsync(Box::new(move |x| {
             Box::pin(predicate(x))
@@ -427,6 +1176,8 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::string::ToString;
+    use alloc::vec;
 
     #[test]
     fn test_chain_map() {
@@ -503,6 +1254,134 @@ mod tests {
         assert!(result.is_empty());
     }
 
+    #[test]
+    fn test_chain_joins() {
+        let prices = vec![("tea", 3), ("jam", 5)];
+        let result = chain(&["tea", "pie", "jam", "tea"])
+            .left_join_by(&prices, |item| *item, |p| p.0)
+            .map(|(item, price)| (*item, price.map(|p| (p.0, p.1 * 2))))
+            .filter(|(_, price)| price.is_some())
+            .value();
+        assert_eq!(
+            result,
+            vec![
+                ("tea", Some(("tea", 6))),
+                ("jam", Some(("jam", 10))),
+                ("tea", Some(("tea", 6))),
+            ]
+        );
+        assert_eq!(
+            chain(&[1, 2, 3]).anti_join_by(&[2], |x| *x, |x| *x).reverse().value(),
+            vec![3, 1]
+        );
+    }
+
+    #[test]
+    fn test_chain_window_steps_are_lazy() {
+        use alloc::sync::Arc;
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&calls);
+        let pending = chain(&[1, 2, 3, 4, 5])
+            .map(move |x| {
+                counter.fetch_add(1, Ordering::SeqCst);
+                x * 10
+            })
+            .pairwise()
+            .filter(|(a, _)| *a > 10)
+            .scan(0, |acc, (a, b)| acc + b - a);
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+        assert_eq!(pending.value(), vec![10, 20, 30]);
+        assert_eq!(calls.load(Ordering::SeqCst), 5);
+    }
+
+    #[test]
+    fn test_chain_join_steps_are_lazy() {
+        use alloc::sync::Arc;
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&calls);
+        let names = vec![(2, "bob"), (3, "cy")];
+        let pending = chain(&[1, 2, 3])
+            .map(move |x| {
+                counter.fetch_add(1, Ordering::SeqCst);
+                *x
+            })
+            .join_by(&names, |x| *x, |n| n.0)
+            .semi_join_by(&[3], |(x, _)| *x, |x| *x);
+        drop(names);
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+        assert_eq!(pending.value(), vec![(3, (3, "cy"))]);
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn test_chain_iter_streams_leading_steps() {
+        use alloc::sync::Arc;
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        let pulled = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&pulled);
+        let source = (1..=100).inspect(move |_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+        let result = chain_iter(source)
+            .skip(1)
+            .filter(|x| x % 2 == 0)
+            .take(3)
+            .map(|x| x * 10)
+            .value();
+        assert_eq!(result, vec![20, 40, 60]);
+        assert_eq!(pulled.load(Ordering::SeqCst), 6);
+
+        let result = chain_iter(vec![1, 2, 3, 4])
+            .filter(|x| *x > 1)
+            .reverse()
+            .take(2)
+            .value();
+        assert_eq!(result, vec![4, 3]);
+        assert_eq!(chain_iter(vec![3, 1, 2]).pairwise().value(), vec![(3, 1), (1, 2)]);
+    }
+
+    #[test]
+    fn test_chain_sort_steps() {
+        let scores = [("ann", 0.5), ("bob", f64::NAN), ("cy", 0.9), ("dee", 0.5)];
+        let names = |chain: Chain<(&'static str, f64)>| -> Vec<&str> {
+            chain.map(|row| (row.0, 0.0)).value().into_iter().map(|row| row.0).collect()
+        };
+        assert_eq!(
+            names(chain(&scores).sort_by_float(|row| row.1)),
+            ["ann", "dee", "cy", "bob"]
+        );
+        assert_eq!(
+            names(chain(&scores).sort_by_partial(|row| row.1, NanPolicy::First).take(2)),
+            ["bob", "ann"]
+        );
+        assert_eq!(
+            names(chain(&scores).filter(|row| !row.1.is_nan()).sort_by(|row| row.0.len()).reverse()),
+            ["dee", "ann", "cy"]
+        );
+        assert_eq!(
+            names(chain(&scores).sort_by_cached_key(|row| core::cmp::Reverse(row.0.to_string()))),
+            ["dee", "cy", "bob", "ann"]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_chain_statistics() {
+        let data = [5, 1, 4, 2, 3, 100];
+        assert_eq!(chain(&data).take(5).median_by(|x| *x), Ok(3.0));
+        assert_eq!(chain(&data).take(5).variance_by(|x| *x), Ok(2.0));
+        assert_eq!(chain(&data).map(|x| x % 2).mode_by(|x| *x), Ok(1));
+        assert_eq!(
+            chain(&data).filter(|x| *x > 100).median_by(|x| *x),
+            Err(crate::utils::LodashError::EmptyCollection)
+        );
+    }
+
     #[cfg(feature = "async")]
     #[tokio::test]
     async fn test_chain_async_map() {
@@ -0,0 +1,894 @@
+//! Declarative pipelines over JSON data.
+//!
+//! A [`Pipeline`] is a list of steps written as data, for example in a JSON
+//! or YAML config file, that compiles to a [`Chain`] over
+//! `serde_json::Value`:
+//!
+//! ```json
+//! {"steps": [
+//!     {"filter": {"matches": {"active": true}}},
+//!     {"sort_by": {"property": "age", "order": "desc"}},
+//!     {"map": {"property": "name"}},
+//!     {"take": 2}
+//! ]}
+//! ```
+//!
+//! Properties are Lodash-style paths such as `user.tags[0]`; a missing
+//! property resolves to `null`. The available steps are:
+//!
+//! - `filter`: `{"matches": value}` keeps elements that partially deep-match
+//!   `value`, `{"property": path}` keeps elements whose property is truthy,
+//!   and `{"expr": "age >= 18"}` keeps elements for which the
+//!   [expression](crate::utils::expr) is truthy.
+//! - `map`: replace each element with a property, or with the result of
+//!   `{"expr": "upper(name)"}`.
+//! - `sort_by`: stable sort by a property, with `"order": "asc"` (the
+//!   default) or `"desc"`. Numbers sort before strings, booleans, arrays and
+//!   objects, and `null` sorts last in either order.
+//! - `uniq_by`: keep the first element for each property value.
+//! - `take`, `skip`: keep or drop the first n elements.
+//! - `reverse`: reverse the elements.
+//! - `group_by`, `count_by`: group or count the elements by a property. The
+//!   result is an object keyed by the property value, with the keys in the
+//!   order they were first seen, so these must be the last step.
+//!
+//! `map`, `sort_by`, `uniq_by`, `group_by` and `count_by` accept either
+//! `{"property": path}` or the path itself as a string, and `reverse` may be
+//! written as the bare string `"reverse"`.
+
+use crate::chain::Chain;
+use crate::collection::ordered_map::OrderedMap;
+use crate::collection::transform::uniq_by;
+use crate::utils::expr::{is_truthy, Expr};
+use crate::utils::path::{get_path, parse_path, PathPart};
+use crate::utils::{LodashError, Result};
+use serde_json::{Map, Value};
+use std::cmp::Ordering;
+
+/// The names of the supported steps, for error messages.
+const STEP_NAMES: &str = "filter, map, sort_by, uniq_by, take, skip, reverse, group_by, count_by";
+
+/// A declarative pipeline of steps over JSON values.
+///
+/// Build one from a spec with [`Pipeline::from_value`] or
+/// [`Pipeline::from_json`], or deserialize it from any self-describing serde
+/// format. The spec is either an array of steps or an object with a `steps`
+/// array. A pipeline serializes back to the spec it was built from.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::chain::Pipeline;
+/// use serde_json::json;
+///
+/// let pipeline = Pipeline::from_json(r#"[
+///     {"filter": {"matches": {"active": true}}},
+///     {"sort_by": {"property": "age", "order": "desc"}},
+///     {"map": "name"}
+/// ]"#).unwrap();
+///
+/// let users = json!([
+///     {"name": "ann", "age": 31, "active": true},
+///     {"name": "bob", "age": 17, "active": false},
+///     {"name": "cy", "age": 45, "active": true}
+/// ]);
+/// assert_eq!(pipeline.run(&users).unwrap(), json!(["cy", "ann"]));
+///
+/// let error = Pipeline::from_json(r#"[{"take": 1}, {"mapp": "name"}]"#).unwrap_err();
+/// assert_eq!(
+///     error.to_string(),
+///     "Invalid input: step 1: unknown step `mapp`, expected one of filter, map, sort_by, \
+///      uniq_by, take, skip, reverse, group_by, count_by"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pipeline {
+    /// The spec the pipeline was built from
+    spec: Value,
+    /// The parsed steps
+    steps: Vec<Step>,
+}
+
+/// A parsed pipeline step.
+#[derive(Debug, Clone, PartialEq)]
+enum Step {
+    Filter(Predicate),
+    Map(Projection),
+    SortBy {
+        path: Vec<PathPart>,
+        descending: bool,
+    },
+    UniqBy(Vec<PathPart>),
+    Take(usize),
+    Skip(usize),
+    Reverse,
+    GroupBy(Vec<PathPart>),
+    CountBy(Vec<PathPart>),
+}
+
+/// A `filter` predicate.
+#[derive(Debug, Clone, PartialEq)]
+enum Predicate {
+    Matches(Value),
+    Property(Vec<PathPart>),
+    Expr(Expr),
+}
+
+/// A `map` projection.
+#[derive(Debug, Clone, PartialEq)]
+enum Projection {
+    Property(Vec<PathPart>),
+    Expr(Expr),
+}
+
+impl Pipeline {
+    /// Build a pipeline from a spec value.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LodashError::InvalidInput` if the spec is not an array of
+    /// steps or an object with a `steps` array, or if a step is unknown, has
+    /// invalid arguments or an invalid property path, or is a `group_by` or
+    /// `count_by` that is not the last step. Step errors start with the index
+    /// of the step, counting from 0.
+    pub fn from_value(spec: &Value) -> Result<Self> {
+        let steps = match spec {
+            Value::Array(steps) => steps,
+            Value::Object(object) => {
+                if let Some(field) = object.keys().find(|key| *key != "steps") {
+                    return Err(LodashError::invalid_input(format!(
+                        "unknown pipeline field `{field}`, expected `steps`"
+                    )));
+                }
+                match object.get("steps") {
+                    Some(Value::Array(steps)) => steps,
+                    _ => {
+                        return Err(LodashError::invalid_input(
+                            "pipeline `steps` must be an array",
+                        ))
+                    }
+                }
+            }
+            _ => {
+                return Err(LodashError::invalid_input(
+                    "pipeline spec must be an array of steps or an object with `steps`",
+                ))
+            }
+        };
+
+        let parsed = steps
+            .iter()
+            .enumerate()
+            .map(|(index, step)| Step::parse(step).map_err(|message| step_error(index, &message)))
+            .collect::<Result<Vec<_>>>()?;
+        let last = parsed.len().saturating_sub(1);
+        if let Some(index) = parsed[..last].iter().position(Step::is_grouping) {
+            let name = step_name(&steps[index]).unwrap_or_default();
+            return Err(step_error(
+                index,
+                &format!("`{name}` must be the last step"),
+            ));
+        }
+
+        Ok(Self {
+            spec: spec.clone(),
+            steps: parsed,
+        })
+    }
+
+    /// Build a pipeline from a JSON spec.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LodashError::InvalidInput` if `spec` is not valid JSON, or
+    /// for any of the reasons listed on [`Pipeline::from_value`].
+    pub fn from_json(spec: &str) -> Result<Self> {
+        let value: Value = serde_json::from_str(spec).map_err(|error| {
+            LodashError::invalid_input(format!("invalid pipeline JSON: {error}"))
+        })?;
+        Self::from_value(&value)
+    }
+
+    /// Build a pipeline from the command-line syntax, steps separated by `|`:
+    ///
+    /// ```text
+    /// filter(matches({active: true})) | sort_by(age, desc) | take(10) | map(name)
+    /// ```
+    ///
+    /// `filter(...)` and `map(...)` take an [expression](crate::utils::expr),
+    /// or `filter(matches(value))` with `value` written as an expression
+    /// literal. `sort_by` takes a property and an optional `asc` or `desc`,
+    /// and the other steps take their argument as written, so
+    /// `group_by(team.id)` is the spec step `{"group_by": "team.id"}`. Steps
+    /// without arguments such as `reverse` may omit the parentheses.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LodashError::InvalidInput` for a malformed step, or for any
+    /// of the reasons listed on [`Pipeline::from_value`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::chain::Pipeline;
+    /// use serde_json::json;
+    ///
+    /// let pipeline = Pipeline::parse("filter(age >= 18) | sort_by(age, desc) | map(name)").unwrap();
+    /// let users = json!([{"name": "ann", "age": 31}, {"name": "bob", "age": 17}, {"name": "cy", "age": 45}]);
+    /// assert_eq!(pipeline.run(&users).unwrap(), json!(["cy", "ann"]));
+    /// ```
+    pub fn parse(text: &str) -> Result<Self> {
+        let steps = split_top_level(text, '|')
+            .into_iter()
+            .enumerate()
+            .map(|(index, step)| {
+                parse_text_step(step.trim()).map_err(|message| step_error(index, &message))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Self::from_value(&Value::Array(steps))
+    }
+
+    /// The number of steps.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Whether the pipeline has no steps.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Append the steps to `chain`. A final `group_by` or `count_by` step
+    /// leaves a single object in the chain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::chain::{chain, Pipeline};
+    /// use serde_json::json;
+    ///
+    /// let pipeline = Pipeline::from_value(&json!([{"uniq_by": "id"}, {"take": 2}])).unwrap();
+    /// let rows = vec![json!({"id": 1}), json!({"id": 1}), json!({"id": 2}), json!({"id": 3})];
+    /// let result = pipeline.compile(chain(&rows)).value();
+    /// assert_eq!(result, vec![json!({"id": 1}), json!({"id": 2})]);
+    /// ```
+    #[must_use]
+    pub fn compile(&self, chain: Chain<Value>) -> Chain<Value> {
+        self.steps
+            .iter()
+            .cloned()
+            .fold(chain, |chain, step| step.apply(chain))
+    }
+
+    /// Run the pipeline over the elements of the array `data`.
+    ///
+    /// Returns an array, or an object if the last step is `group_by` or
+    /// `count_by`. Object keys are in first-seen order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LodashError::InvalidInput` if `data` is not an array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::chain::Pipeline;
+    /// use serde_json::json;
+    ///
+    /// let pipeline = Pipeline::from_value(&json!({"steps": [{"count_by": "team.name"}]})).unwrap();
+    /// let players = json!([
+    ///     {"team": {"name": "red"}},
+    ///     {"team": {"name": "blue"}},
+    ///     {"team": {"name": "red"}}
+    /// ]);
+    /// let counts = pipeline.run(&players).unwrap();
+    /// assert_eq!(counts, json!({"red": 2, "blue": 1}));
+    /// assert_eq!(counts.as_object().unwrap().keys().collect::<Vec<_>>(), ["red", "blue"]);
+    /// ```
+    pub fn run(&self, data: &Value) -> Result<Value> {
+        let Value::Array(items) = data else {
+            return Err(LodashError::invalid_input(
+                "pipeline input must be an array",
+            ));
+        };
+        let mut values = self.compile(Chain::from_vec(items.clone())).value();
+        if self.steps.last().is_some_and(Step::is_grouping) {
+            Ok(values.pop().unwrap_or(Value::Null))
+        } else {
+            Ok(Value::Array(values))
+        }
+    }
+}
+
+impl TryFrom<Value> for Pipeline {
+    type Error = LodashError;
+
+    fn try_from(spec: Value) -> Result<Self> {
+        Self::from_value(&spec)
+    }
+}
+
+impl serde::Serialize for Pipeline {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        self.spec.serialize(serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Pipeline {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let spec = Value::deserialize(deserializer)?;
+        Self::from_value(&spec).map_err(serde::de::Error::custom)
+    }
+}
+
+impl Step {
+    /// Parse one step, returning an error message without the step index.
+    fn parse(step: &Value) -> std::result::Result<Self, String> {
+        let entry = match step {
+            Value::String(name) => Some((name, &Value::Null)),
+            Value::Object(object) if object.len() == 1 => object.iter().next(),
+            _ => None,
+        };
+        let Some((name, args)) = entry else {
+            return Err(format!(
+                "expected an object with a single step name, found `{step}`"
+            ));
+        };
+        let with_name = |message: String| format!("`{name}` {message}");
+        match name.as_str() {
+            "filter" => parse_predicate(args).map(Step::Filter).map_err(with_name),
+            "map" => parse_projection(args).map(Step::Map).map_err(with_name),
+            "sort_by" => parse_sort(args).map_err(with_name),
+            "uniq_by" => parse_property(args).map(Step::UniqBy).map_err(with_name),
+            "take" => parse_count(args).map(Step::Take).map_err(with_name),
+            "skip" => parse_count(args).map(Step::Skip).map_err(with_name),
+            "reverse" => match args {
+                Value::Null | Value::Bool(true) => Ok(Step::Reverse),
+                _ => Err(with_name(format!("takes no arguments, found `{args}`"))),
+            },
+            "group_by" => parse_property(args).map(Step::GroupBy).map_err(with_name),
+            "count_by" => parse_property(args).map(Step::CountBy).map_err(with_name),
+            _ => Err(format!(
+                "unknown step `{name}`, expected one of {STEP_NAMES}"
+            )),
+        }
+    }
+
+    fn is_grouping(&self) -> bool {
+        matches!(self, Step::GroupBy(_) | Step::CountBy(_))
+    }
+
+    fn apply(self, chain: Chain<Value>) -> Chain<Value> {
+        match self {
+            Step::Filter(Predicate::Matches(source)) => {
+                chain.filter(move |value| is_match(value, &source))
+            }
+            Step::Filter(Predicate::Property(path)) => {
+                chain.filter(move |value| get_path(value, &path).is_some_and(is_truthy))
+            }
+            Step::Filter(Predicate::Expr(expr)) => chain.filter(move |value| expr.matches(value)),
+            Step::Map(Projection::Property(path)) => chain.map(move |value| property(value, &path)),
+            Step::Map(Projection::Expr(expr)) => chain.map(move |value| expr.eval(value)),
+            Step::SortBy { path, descending } => chain.then(move |mut values| {
+                static NULL: Value = Value::Null;
+                values.sort_by(|a, b| {
+                    let a = get_path(a, &path).unwrap_or(&NULL);
+                    let b = get_path(b, &path).unwrap_or(&NULL);
+                    match (a.is_null(), b.is_null(), descending) {
+                        (false, false, true) => compare_values(b, a),
+                        _ => compare_values(a, b),
+                    }
+                });
+                values
+            }),
+            Step::UniqBy(path) => {
+                chain.then(move |values| uniq_by(&values, |value| key_of(&property(value, &path))))
+            }
+            Step::Take(n) => chain.take(n),
+            Step::Skip(n) => chain.skip(n),
+            Step::Reverse => chain.reverse(),
+            Step::GroupBy(path) => chain.then(move |values| {
+                let mut groups: OrderedMap<String, Vec<Value>> = OrderedMap::new();
+                for value in values {
+                    let key = key_of(&property(&value, &path));
+                    match groups.get_mut(&key) {
+                        Some(group) => group.push(value),
+                        None => {
+                            groups.insert(key, vec![value]);
+                        }
+                    }
+                }
+                let object: Map<String, Value> = groups
+                    .into_iter()
+                    .map(|(key, group)| (key, Value::Array(group)))
+                    .collect();
+                vec![Value::Object(object)]
+            }),
+            Step::CountBy(path) => chain.then(move |values| {
+                let mut counts: OrderedMap<String, usize> = OrderedMap::new();
+                for value in &values {
+                    let key = key_of(&property(value, &path));
+                    match counts.get_mut(&key) {
+                        Some(count) => *count += 1,
+                        None => {
+                            counts.insert(key, 1);
+                        }
+                    }
+                }
+                let object: Map<String, Value> = counts
+                    .into_iter()
+                    .map(|(key, count)| (key, Value::from(count)))
+                    .collect();
+                vec![Value::Object(object)]
+            }),
+        }
+    }
+}
+
+fn step_error(index: usize, message: &str) -> LodashError {
+    LodashError::invalid_input(format!("step {index}: {message}"))
+}
+
+fn step_name(step: &Value) -> Option<&str> {
+    match step {
+        Value::String(name) => Some(name),
+        Value::Object(object) => object.keys().next().map(String::as_str),
+        _ => None,
+    }
+}
+
+/// Split `text` at each `separator` outside brackets and string literals.
+/// A doubled `|` is the `||` operator rather than a separator.
+fn split_top_level(text: &str, separator: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let (mut depth, mut quote, mut escaped, mut start) = (0_usize, None, false, 0);
+    let mut chars = text.char_indices().peekable();
+    while let Some((index, c)) = chars.next() {
+        if let Some(open) = quote {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                _ if c == open => quote = None,
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            '"' | '\'' => quote = Some(c),
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth = depth.saturating_sub(1),
+            '|' if separator == '|' && chars.peek().is_some_and(|&(_, next)| next == '|') => {
+                chars.next();
+            }
+            _ if c == separator && depth == 0 => {
+                parts.push(&text[start..index]);
+                start = index + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    parts.push(&text[start..]);
+    parts
+}
+
+/// Convert one step of the command-line syntax to a spec step.
+fn parse_text_step(step: &str) -> std::result::Result<Value, String> {
+    let name_end = step
+        .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .unwrap_or(step.len());
+    let (name, rest) = step.split_at(name_end);
+    if name.is_empty() {
+        return Err(format!("expected a step name, found `{step}`"));
+    }
+    let rest = rest.trim();
+    if rest.is_empty() {
+        return Ok(Value::String(name.to_string()));
+    }
+    let Some(args) = rest
+        .strip_prefix('(')
+        .and_then(|rest| rest.strip_suffix(')'))
+        .map(str::trim)
+    else {
+        return Err(format!(
+            "`{name}` expects its arguments in parentheses, found `{rest}`"
+        ));
+    };
+    if args.is_empty() {
+        return Ok(Value::String(name.to_string()));
+    }
+    let args = match name {
+        "filter" => match args
+            .strip_prefix("matches")
+            .map(str::trim_start)
+            .and_then(|rest| rest.strip_prefix('('))
+            .and_then(|rest| rest.strip_suffix(')'))
+        {
+            Some(source) => {
+                let value = Expr::parse(source)
+                    .map_err(|error| format!("`filter` has an invalid `matches` value: {error}"))?
+                    .eval(&Value::Null);
+                serde_json::json!({ "matches": value })
+            }
+            None => serde_json::json!({ "expr": args }),
+        },
+        "map" => serde_json::json!({ "expr": args }),
+        "sort_by" => match split_top_level(args, ',').as_slice() {
+            [property] => Value::String(property.trim().to_string()),
+            [property, order] => {
+                serde_json::json!({ "property": property.trim(), "order": order.trim() })
+            }
+            _ => {
+                return Err(format!(
+                    "`sort_by` expects a property and an optional order, found `{args}`"
+                ))
+            }
+        },
+        "take" | "skip" => args
+            .parse::<u64>()
+            .map_or_else(|_| Value::String(args.to_string()), Value::from),
+        _ => Value::String(args.to_string()),
+    };
+    let mut object = Map::new();
+    object.insert(name.to_string(), args);
+    Ok(Value::Object(object))
+}
+
+/// Parse a property path argument, given as a string or `{"property": path}`.
+fn parse_property(args: &Value) -> std::result::Result<Vec<PathPart>, String> {
+    let path = match args {
+        Value::String(path) => path,
+        Value::Object(object) => match object.get("property") {
+            Some(Value::String(path)) if object.len() == 1 => path,
+            _ => {
+                return Err(format!(
+                    "expects a path or `{{\"property\": path}}`, found `{args}`"
+                ))
+            }
+        },
+        _ => {
+            return Err(format!(
+                "expects a path or `{{\"property\": path}}`, found `{args}`"
+            ))
+        }
+    };
+    parse_path(path).ok_or_else(|| format!("has an invalid property path `{path}`"))
+}
+
+/// Parse an `{"expr": source}` argument, or `None` if `args` has no `expr`.
+fn parse_expr(args: &Value) -> Option<std::result::Result<Expr, String>> {
+    let Value::Object(object) = args else {
+        return None;
+    };
+    let source = object.get("expr")?;
+    Some(match source.as_str() {
+        Some(source) if object.len() == 1 => {
+            Expr::parse(source).map_err(|error| format!("has an invalid expression: {error}"))
+        }
+        _ => Err(format!("expects `{{\"expr\": string}}`, found `{args}`")),
+    })
+}
+
+fn parse_predicate(args: &Value) -> std::result::Result<Predicate, String> {
+    if let Some(expr) = parse_expr(args) {
+        return expr.map(Predicate::Expr);
+    }
+    let Value::Object(object) = args else {
+        return Err(format!(
+            "expects `matches`, `property` or `expr`, found `{args}`"
+        ));
+    };
+    match (object.len(), object.get("matches")) {
+        (1, Some(source)) => Ok(Predicate::Matches(source.clone())),
+        (1, None) if object.contains_key("property") => {
+            parse_property(args).map(Predicate::Property)
+        }
+        _ => Err(format!(
+            "expects `matches`, `property` or `expr`, found `{args}`"
+        )),
+    }
+}
+
+fn parse_projection(args: &Value) -> std::result::Result<Projection, String> {
+    match parse_expr(args) {
+        Some(expr) => expr.map(Projection::Expr),
+        None => parse_property(args).map(Projection::Property),
+    }
+}
+
+fn parse_sort(args: &Value) -> std::result::Result<Step, String> {
+    let (property, order) = match args {
+        Value::Object(object) => {
+            if let Some(field) = object
+                .keys()
+                .find(|key| !matches!(key.as_str(), "property" | "order"))
+            {
+                return Err(format!("has an unknown field `{field}`"));
+            }
+            let property = object.get("property").cloned().unwrap_or(Value::Null);
+            (property, object.get("order"))
+        }
+        other => (other.clone(), None),
+    };
+    let descending = match order.map(|order| order.as_str()) {
+        None | Some(Some("asc")) => false,
+        Some(Some("desc")) => true,
+        Some(_) => {
+            return Err(format!(
+                "expects `order` to be \"asc\" or \"desc\", found `{}`",
+                order.unwrap_or(&Value::Null)
+            ))
+        }
+    };
+    let path = parse_property(&property)?;
+    Ok(Step::SortBy { path, descending })
+}
+
+fn parse_count(args: &Value) -> std::result::Result<usize, String> {
+    args.as_u64()
+        .and_then(|n| usize::try_from(n).ok())
+        .ok_or_else(|| format!("expects a non-negative integer, found `{args}`"))
+}
+
+/// The property at `path`, or `null` if it is missing.
+fn property(value: &Value, path: &[PathPart]) -> Value {
+    get_path(value, path).cloned().unwrap_or(Value::Null)
+}
+
+/// The object key for a property value: strings as they are, anything else
+/// as compact JSON.
+fn key_of(value: &Value) -> String {
+    match value {
+        Value::String(string) => string.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Lodash's `isMatch`: objects match if every property of `source` matches,
+/// arrays if every element of `source` matches some element of `value`.
+fn is_match(value: &Value, source: &Value) -> bool {
+    match (value, source) {
+        (Value::Object(value), Value::Object(source)) => source.iter().all(|(key, expected)| {
+            value
+                .get(key)
+                .is_some_and(|actual| is_match(actual, expected))
+        }),
+        (Value::Array(value), Value::Array(source)) => source
+            .iter()
+            .all(|expected| value.iter().any(|actual| is_match(actual, expected))),
+        (Value::Number(a), Value::Number(b)) => a.as_f64() == b.as_f64(),
+        _ => value == source,
+    }
+}
+
+/// Order values by type (numbers, strings, booleans, arrays, objects, then
+/// `null`) and within a type by value.
+fn compare_values(a: &Value, b: &Value) -> Ordering {
+    fn rank(value: &Value) -> u8 {
+        match value {
+            Value::Number(_) => 0,
+            Value::String(_) => 1,
+            Value::Bool(_) => 2,
+            Value::Array(_) => 3,
+            Value::Object(_) => 4,
+            Value::Null => 5,
+        }
+    }
+    match (a, b) {
+        (Value::Number(a), Value::Number(b)) => a
+            .as_f64()
+            .partial_cmp(&b.as_f64())
+            .unwrap_or(Ordering::Equal),
+        (Value::String(a), Value::String(b)) => a.cmp(b),
+        (Value::Bool(a), Value::Bool(b)) => a.cmp(b),
+        _ => rank(a).cmp(&rank(b)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn users() -> Value {
+        json!([
+            {"name": "ann", "age": 31, "tags": ["admin", "dev"], "team": {"id": 1}},
+            {"name": "bob", "age": 17, "tags": ["dev"], "team": {"id": 2}},
+            {"name": "cy", "age": 45, "tags": [], "team": {"id": 1}},
+            {"name": "dee", "tags": ["ops"], "team": null}
+        ])
+    }
+
+    fn run(spec: &Value) -> Value {
+        Pipeline::from_value(spec).unwrap().run(&users()).unwrap()
+    }
+
+    #[test]
+    fn test_filter_and_map() {
+        assert_eq!(
+            run(&json!([{"filter": {"matches": {"team": {"id": 1}}}}, {"map": "name"}])),
+            json!(["ann", "cy"])
+        );
+        assert_eq!(
+            run(
+                &json!([{"filter": {"matches": {"tags": ["dev"]}}}, {"map": {"property": "name"}}])
+            ),
+            json!(["ann", "bob"])
+        );
+        assert_eq!(
+            run(&json!([{"filter": {"property": "tags[0]"}}, {"map": "team.id"}])),
+            json!([1, 2, null])
+        );
+        assert_eq!(
+            run(&json!([
+                {"filter": {"expr": "age >= 30 || tags contains 'ops'"}},
+                {"map": {"expr": "upper(name) + ':' + str(team.id)"}}
+            ])),
+            json!(["ANN:1", "CY:1", "DEE:"])
+        );
+    }
+
+    #[test]
+    fn test_sort_take_skip_reverse_uniq() {
+        assert_eq!(
+            run(&json!([{"sort_by": "age"}, {"map": "name"}])),
+            json!(["bob", "ann", "cy", "dee"])
+        );
+        assert_eq!(
+            run(
+                &json!([{"sort_by": {"property": "age", "order": "desc"}}, {"skip": 1}, {"take": 2}, {"map": "name"}])
+            ),
+            json!(["ann", "bob"])
+        );
+        assert_eq!(
+            run(&json!([{"uniq_by": "team.id"}, "reverse", {"map": "name"}])),
+            json!(["dee", "bob", "ann"])
+        );
+    }
+
+    #[test]
+    fn test_grouping_steps_return_objects() {
+        assert_eq!(
+            run(&json!([{"filter": {"property": "age"}}, {"group_by": "team.id"}, ])),
+            json!({
+                "1": [users()[0].clone(), users()[2].clone()],
+                "2": [users()[1].clone()]
+            })
+        );
+        assert_eq!(
+            run(&json!({"steps": [{"count_by": "tags[0]"}]})),
+            json!({"admin": 1, "dev": 1, "null": 1, "ops": 1})
+        );
+    }
+
+    #[test]
+    fn test_grouping_steps_keep_first_seen_order() {
+        let keys = |value: &Value| {
+            value
+                .as_object()
+                .unwrap()
+                .keys()
+                .cloned()
+                .collect::<Vec<_>>()
+        };
+        let data = json!([{"k": "b"}, {"k": "c"}, {"k": "a"}, {"k": "b"}, {"k": 10}, {"k": 9}]);
+        for step in ["group_by", "count_by"] {
+            let pipeline = Pipeline::from_value(&json!([{ step: "k" }])).unwrap();
+            assert_eq!(
+                keys(&pipeline.run(&data).unwrap()),
+                ["b", "c", "a", "10", "9"]
+            );
+        }
+        let reversed = Pipeline::parse("reverse | group_by(k)").unwrap();
+        assert_eq!(
+            keys(&reversed.run(&data).unwrap()),
+            ["9", "10", "b", "a", "c"]
+        );
+    }
+
+    #[test]
+    fn test_errors_name_the_step() {
+        let cases = [
+            (
+                json!([{"take": 1}, {"sortby": "a"}]),
+                "step 1: unknown step `sortby`",
+            ),
+            (
+                json!([{"map": "a..b"}]),
+                "step 0: `map` has an invalid property path `a..b`",
+            ),
+            (
+                json!([{"take": -1}]),
+                "step 0: `take` expects a non-negative integer, found `-1`",
+            ),
+            (
+                json!([{"sort_by": {"property": "a", "order": "up"}}]),
+                "step 0: `sort_by` expects `order`",
+            ),
+            (
+                json!([{"filter": {"equals": 1}}]),
+                "step 0: `filter` expects `matches`, `property` or `expr`",
+            ),
+            (
+                json!([{"filter": {"expr": "age >"}}]),
+                "step 0: `filter` has an invalid expression: expected an expression, found end of input at 5..5",
+            ),
+            (
+                json!([{"map": {"expr": 1}}]),
+                "step 0: `map` expects `{\"expr\": string}`",
+            ),
+            (
+                json!([{"count_by": "a"}, {"take": 1}]),
+                "step 0: `count_by` must be the last step",
+            ),
+            (
+                json!([{"map": "a", "take": 1}]),
+                "step 0: expected an object with a single step name",
+            ),
+            (json!({"stpes": []}), "unknown pipeline field `stpes`"),
+            (json!(3), "pipeline spec must be an array"),
+        ];
+        for (spec, expected) in cases {
+            let error = Pipeline::from_value(&spec).unwrap_err();
+            assert!(matches!(error, LodashError::InvalidInput { .. }));
+            assert!(error.to_string().contains(expected), "{error} / {expected}");
+        }
+
+        let pipeline = Pipeline::from_value(&json!([])).unwrap();
+        assert!(pipeline.is_empty());
+        assert!(pipeline.run(&json!({"not": "an array"})).is_err());
+    }
+
+    #[test]
+    fn test_parse_text_syntax() {
+        let pipeline = Pipeline::parse(
+            "filter(matches({team: {id: 1}})) | sort_by(age, desc) | map(upper(name))",
+        )
+        .unwrap();
+        assert_eq!(pipeline.run(&users()).unwrap(), json!(["CY", "ANN"]));
+        assert_eq!(
+            serde_json::to_value(&pipeline).unwrap(),
+            json!([
+                {"filter": {"matches": {"team": {"id": 1}}}},
+                {"sort_by": {"property": "age", "order": "desc"}},
+                {"map": {"expr": "upper(name)"}}
+            ])
+        );
+        assert_eq!(
+            run(&Pipeline::parse(
+                "filter(age < 20 || tags contains 'ops') | reverse | take(1) | count_by(name)"
+            )
+            .unwrap()
+            .spec),
+            json!({"dee": 1})
+        );
+
+        for (text, expected) in [
+            ("take(1) | | map(a)", "step 1: expected a step name, found ``"),
+            ("take 1", "step 0: `take` expects its arguments in parentheses, found `1`"),
+            ("sort_by(a, desc, b)", "step 0: `sort_by` expects a property and an optional order"),
+            ("take(-1)", "step 0: `take` expects a non-negative integer, found `\"-1\"`"),
+            ("filter(matches({a: }))", "step 0: `filter` has an invalid `matches` value: expected an expression, found `}`"),
+        ] {
+            let error = Pipeline::parse(text).unwrap_err();
+            assert!(error.to_string().contains(expected), "{error} / {expected}");
+        }
+    }
+
+    #[test]
+    fn test_pipeline_serde() {
+        let spec = json!({"steps": [{"map": "name"}, {"take": 1}]});
+        let pipeline: Pipeline = serde_json::from_value(spec.clone()).unwrap();
+        assert_eq!(pipeline.len(), 2);
+        assert_eq!(serde_json::to_value(&pipeline).unwrap(), spec);
+
+        let error = serde_json::from_value::<Pipeline>(json!([{"pick": "a"}])).unwrap_err();
+        assert!(error.to_string().contains("step 0: unknown step `pick`"));
+    }
+}
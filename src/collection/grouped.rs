@@ -0,0 +1,802 @@
+/*!
+Grouped aggregation for Lodash-RS.
+
+This module provides `GroupedCollection`, a lazy view of a collection grouped
+by a key. Aggregates such as `count`, `sum_by` and `mean_by` are computed in a
+single pass over the collection, keeping one accumulator per group instead of
+materializing the groups. Results are returned in the order each key was
+first seen.
+
+Custom and multiple aggregations are built with the [`Aggregator`] trait.
+Tuples of aggregators are themselves aggregators, so several aggregates can
+be computed in the same pass:
+
+```
+use rust_lodash::collection::grouped::grouped_by;
+
+let sales = vec![("north", 10.0), ("south", 5.0), ("north", 2.0)];
+let summary = grouped_by(&sales, |sale| sale.0)
+    .agg(|a| (a.count(), a.sum_by(|sale| sale.1), a.max_by(|sale| sale.1)));
+assert_eq!(
+    summary,
+    vec![
+        ("north", (2, 12.0, Some(&("north", 10.0)))),
+        ("south", (1, 5.0, Some(&("south", 5.0)))),
+    ]
+);
+```
+*/
+
+use crate::collection::source::Source;
+use crate::collection::Collection;
+use crate::math::Numeric;
+use crate::utils::hash::HashMap;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::hash::Hash;
+use core::marker::PhantomData;
+
+/// A single-pass, per-group accumulation.
+///
+/// `init` creates the state for a new group, `update` folds one element into
+/// it and `finish` turns it into the output.
+pub trait Aggregator<'a, T> {
+    /// The per-group accumulator.
+    type State;
+    /// The aggregated value for a group.
+    type Output;
+
+    /// Create the accumulator for a new group.
+    fn init(&self) -> Self::State;
+
+    /// Fold `item` into `state`.
+    fn update(&self, state: &mut Self::State, item: &'a T);
+
+    /// Produce the output for a group.
+    fn finish(&self, state: Self::State) -> Self::Output;
+}
+
+/// Count the elements of each group.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Count;
+
+impl<'a, T> Aggregator<'a, T> for Count {
+    type State = usize;
+    type Output = usize;
+
+    fn init(&self) -> usize {
+        0
+    }
+
+    fn update(&self, state: &mut usize, _item: &'a T) {
+        *state += 1;
+    }
+
+    fn finish(&self, state: usize) -> usize {
+        state
+    }
+}
+
+/// Sum the values produced by an iteratee. Float sums are compensated, as in
+/// [`crate::math::sum_by`].
+#[derive(Debug, Clone, Copy)]
+pub struct SumBy<F, N> {
+    iteratee: F,
+    marker: PhantomData<fn() -> N>,
+}
+
+impl<F, N> SumBy<F, N> {
+    /// Create a sum aggregator over `iteratee`.
+    pub fn new(iteratee: F) -> Self {
+        Self {
+            iteratee,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T, N, F> Aggregator<'a, T> for SumBy<F, N>
+where
+    N: Numeric,
+    F: Fn(&T) -> N,
+{
+    type State = (N, N);
+    type Output = N;
+
+    fn init(&self) -> (N, N) {
+        (N::ZERO, N::ZERO)
+    }
+
+    fn update(&self, state: &mut (N, N), item: &'a T) {
+        N::sum_step(state, (self.iteratee)(item));
+    }
+
+    fn finish(&self, state: (N, N)) -> N {
+        N::sum_finish(state)
+    }
+}
+
+/// Average the values produced by an iteratee, using a running mean.
+#[derive(Debug, Clone, Copy)]
+pub struct MeanBy<F, N> {
+    iteratee: F,
+    marker: PhantomData<fn() -> N>,
+}
+
+impl<F, N> MeanBy<F, N> {
+    /// Create a mean aggregator over `iteratee`.
+    pub fn new(iteratee: F) -> Self {
+        Self {
+            iteratee,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T, N, F> Aggregator<'a, T> for MeanBy<F, N>
+where
+    N: Numeric,
+    F: Fn(&T) -> N,
+{
+    type State = (f64, f64);
+    type Output = f64;
+
+    fn init(&self) -> (f64, f64) {
+        (0.0, 0.0)
+    }
+
+    fn update(&self, state: &mut (f64, f64), item: &'a T) {
+        let (count, mean) = state;
+        *count += 1.0;
+        *mean += ((self.iteratee)(item).to_f64() - *mean) / *count;
+    }
+
+    fn finish(&self, (count, mean): (f64, f64)) -> f64 {
+        if count > 0.0 {
+            mean
+        } else {
+            f64::NAN
+        }
+    }
+}
+
+/// Select the element with the smallest or largest key produced by an
+/// iteratee. Ties keep the first element and incomparable keys (such as
+/// `NaN`) are only selected if no comparable key was seen.
+#[derive(Debug, Clone, Copy)]
+pub struct ExtremumBy<F, K> {
+    iteratee: F,
+    wanted: Ordering,
+    marker: PhantomData<fn() -> K>,
+}
+
+impl<F, K> ExtremumBy<F, K> {
+    /// Create an aggregator selecting the element with the smallest key.
+    pub fn min(iteratee: F) -> Self {
+        Self {
+            iteratee,
+            wanted: Ordering::Less,
+            marker: PhantomData,
+        }
+    }
+
+    /// Create an aggregator selecting the element with the largest key.
+    pub fn max(iteratee: F) -> Self {
+        Self {
+            iteratee,
+            wanted: Ordering::Greater,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T, K, F> Aggregator<'a, T> for ExtremumBy<F, K>
+where
+    T: 'a,
+    K: PartialOrd,
+    F: Fn(&T) -> K,
+{
+    type State = Option<(&'a T, K)>;
+    type Output = Option<&'a T>;
+
+    fn init(&self) -> Self::State {
+        None
+    }
+
+    fn update(&self, state: &mut Self::State, item: &'a T) {
+        let key = (self.iteratee)(item);
+        let replace = match state.as_ref() {
+            None => true,
+            Some((_, best)) => match key.partial_cmp(best) {
+                Some(ordering) => ordering == self.wanted,
+                // Prefer a comparable key over an incomparable one.
+                None => best.partial_cmp(best).is_none() && key.partial_cmp(&key).is_some(),
+            },
+        };
+        if replace {
+            *state = Some((item, key));
+        }
+    }
+
+    fn finish(&self, state: Self::State) -> Option<&'a T> {
+        state.map(|(item, _)| item)
+    }
+}
+
+/// Select the first or last element of each group.
+#[derive(Debug, Clone, Copy)]
+pub struct Pick {
+    last: bool,
+}
+
+impl Pick {
+    /// Create an aggregator selecting the first element.
+    #[must_use]
+    pub fn first() -> Self {
+        Self { last: false }
+    }
+
+    /// Create an aggregator selecting the last element.
+    #[must_use]
+    pub fn last() -> Self {
+        Self { last: true }
+    }
+}
+
+impl<'a, T: 'a> Aggregator<'a, T> for Pick {
+    type State = Option<&'a T>;
+    type Output = Option<&'a T>;
+
+    fn init(&self) -> Self::State {
+        None
+    }
+
+    fn update(&self, state: &mut Self::State, item: &'a T) {
+        if self.last || state.is_none() {
+            *state = Some(item);
+        }
+    }
+
+    fn finish(&self, state: Self::State) -> Option<&'a T> {
+        state
+    }
+}
+
+/// Collect references to the elements of each group.
+struct Members;
+
+impl<'a, T: 'a> Aggregator<'a, T> for Members {
+    type State = Vec<&'a T>;
+    type Output = Vec<&'a T>;
+
+    fn init(&self) -> Self::State {
+        Vec::new()
+    }
+
+    fn update(&self, state: &mut Self::State, item: &'a T) {
+        state.push(item);
+    }
+
+    fn finish(&self, state: Self::State) -> Self::Output {
+        state
+    }
+}
+
+/// Fold each group into an accumulator, starting from a clone of `initial`.
+#[derive(Debug, Clone, Copy)]
+pub struct Fold<U, F> {
+    initial: U,
+    step: F,
+}
+
+impl<U, F> Fold<U, F> {
+    /// Create a fold aggregator.
+    pub fn new(initial: U, step: F) -> Self {
+        Self { initial, step }
+    }
+}
+
+impl<'a, T, U, F> Aggregator<'a, T> for Fold<U, F>
+where
+    U: Clone,
+    F: Fn(&mut U, &T),
+{
+    type State = U;
+    type Output = U;
+
+    fn init(&self) -> U {
+        self.initial.clone()
+    }
+
+    fn update(&self, state: &mut U, item: &'a T) {
+        (self.step)(state, item);
+    }
+
+    fn finish(&self, state: U) -> U {
+        state
+    }
+}
+
+macro_rules! impl_aggregator_tuple {
+    ($(($name:ident, $index:tt)),+) => {
+        impl<'a, T, $($name),+> Aggregator<'a, T> for ($($name,)+)
+        where
+            $($name: Aggregator<'a, T>,)+
+        {
+            type State = ($($name::State,)+);
+            type Output = ($($name::Output,)+);
+
+            fn init(&self) -> Self::State {
+                ($(self.$index.init(),)+)
+            }
+
+            fn update(&self, state: &mut Self::State, item: &'a T) {
+                $(self.$index.update(&mut state.$index, item);)+
+            }
+
+            fn finish(&self, state: Self::State) -> Self::Output {
+                ($(self.$index.finish(state.$index),)+)
+            }
+        }
+    };
+}
+
+impl<'a, T> Aggregator<'a, T> for () {
+    type State = ();
+    type Output = ();
+
+    fn init(&self) {}
+
+    fn update(&self, _state: &mut (), _item: &'a T) {}
+
+    fn finish(&self, _state: ()) {}
+}
+
+impl_aggregator_tuple!((A, 0));
+impl_aggregator_tuple!((A, 0), (B, 1));
+impl_aggregator_tuple!((A, 0), (B, 1), (C, 2));
+impl_aggregator_tuple!((A, 0), (B, 1), (C, 2), (D, 3));
+impl_aggregator_tuple!((A, 0), (B, 1), (C, 2), (D, 3), (E, 4));
+impl_aggregator_tuple!((A, 0), (B, 1), (C, 2), (D, 3), (E, 4), (G, 5));
+
+/// Builder passed to [`GroupedCollection::agg`]. Its methods create
+/// aggregators for the element type `T`, so iteratee closures need no type
+/// annotations.
+pub struct Aggs<T> {
+    marker: PhantomData<fn(&T)>,
+}
+
+impl<T> Aggs<T> {
+    pub(crate) fn new() -> Self {
+        Self {
+            marker: PhantomData,
+        }
+    }
+
+    /// Count the elements of each group.
+    #[must_use]
+    pub fn count(&self) -> Count {
+        Count
+    }
+
+    /// Sum the values produced by `iteratee`.
+    pub fn sum_by<N, F>(&self, iteratee: F) -> SumBy<F, N>
+    where
+        N: Numeric,
+        F: Fn(&T) -> N,
+    {
+        SumBy::new(iteratee)
+    }
+
+    /// Average the values produced by `iteratee`.
+    pub fn mean_by<N, F>(&self, iteratee: F) -> MeanBy<F, N>
+    where
+        N: Numeric,
+        F: Fn(&T) -> N,
+    {
+        MeanBy::new(iteratee)
+    }
+
+    /// Select the element with the smallest key produced by `iteratee`.
+    pub fn min_by<K, F>(&self, iteratee: F) -> ExtremumBy<F, K>
+    where
+        K: PartialOrd,
+        F: Fn(&T) -> K,
+    {
+        ExtremumBy::min(iteratee)
+    }
+
+    /// Select the element with the largest key produced by `iteratee`.
+    pub fn max_by<K, F>(&self, iteratee: F) -> ExtremumBy<F, K>
+    where
+        K: PartialOrd,
+        F: Fn(&T) -> K,
+    {
+        ExtremumBy::max(iteratee)
+    }
+
+    /// Select the first element.
+    #[must_use]
+    pub fn first(&self) -> Pick {
+        Pick::first()
+    }
+
+    /// Select the last element.
+    #[must_use]
+    pub fn last(&self) -> Pick {
+        Pick::last()
+    }
+
+    /// Fold the elements into a clone of `initial` with `step`.
+    pub fn fold<U, F>(&self, initial: U, step: F) -> Fold<U, F>
+    where
+        U: Clone,
+        F: Fn(&mut U, &T),
+    {
+        Fold::new(initial, step)
+    }
+}
+
+/// A collection grouped by the key produced by an iteratee.
+///
+/// Created with [`grouped_by`] or [`Collection::grouped_by`]. Nothing is
+/// computed until an aggregate is requested; every aggregate is a single pass
+/// over the collection and returns `(key, value)` pairs in the order the keys
+/// were first seen.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::grouped::grouped_by;
+///
+/// let words = vec!["apple", "avocado", "banana", "blueberry", "cherry"];
+/// let by_letter = grouped_by(&words, |word| word.chars().next().unwrap());
+/// assert_eq!(by_letter.count(), vec![('a', 2), ('b', 2), ('c', 1)]);
+/// assert_eq!(
+///     by_letter.first(),
+///     vec![('a', &"apple"), ('b', &"banana"), ('c', &"cherry")]
+/// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct GroupedCollection<'a, T, K, F, S = &'a [T]> {
+    source: S,
+    iteratee: F,
+    marker: PhantomData<fn() -> K>,
+    items: PhantomData<&'a T>,
+}
+
+impl<'a, T, K, F, S> GroupedCollection<'a, T, K, F, S>
+where
+    S: Source<'a, Item = T> + Clone,
+    K: Hash + Eq,
+    F: Fn(&T) -> K,
+{
+    /// Group `source` by the key produced by `iteratee`. The source is
+    /// cloned for each aggregate, so it should be cheap to clone, as
+    /// references and borrowing iterators are.
+    pub fn new(source: S, iteratee: F) -> Self {
+        Self {
+            source,
+            iteratee,
+            marker: PhantomData,
+            items: PhantomData,
+        }
+    }
+
+    /// Run `aggregator` over every group in a single pass.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::collection::grouped::{grouped_by, Count, SumBy};
+    ///
+    /// let numbers = vec![1, 2, 3, 4, 5];
+    /// let parity = grouped_by(&numbers, |n| n % 2 == 0)
+    ///     .aggregate((Count, SumBy::new(|n: &i32| *n)));
+    /// assert_eq!(parity, vec![(false, (3, 9)), (true, (2, 6))]);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn aggregate<A>(&self, aggregator: A) -> Vec<(K, A::Output)>
+    where
+        A: Aggregator<'a, T>,
+    {
+        let mut index: HashMap<K, usize> = HashMap::new();
+        let mut states: Vec<A::State> = Vec::new();
+        for item in self.source.clone().source_iter() {
+            let position = *index.entry((self.iteratee)(item)).or_insert_with(|| {
+                states.push(aggregator.init());
+                states.len() - 1
+            });
+            aggregator.update(&mut states[position], item);
+        }
+
+        let mut keys: Vec<(K, usize)> = index.into_iter().collect();
+        keys.sort_unstable_by_key(|&(_, position)| position);
+        keys.into_iter()
+            .zip(states)
+            .map(|((key, _), state)| (key, aggregator.finish(state)))
+            .collect()
+    }
+
+    /// Build aggregators with `build` and run them over every group in a
+    /// single pass. Return a tuple of aggregators to compute several
+    /// aggregates at once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::collection::grouped::grouped_by;
+    ///
+    /// let scores = vec![("ann", 90), ("bob", 70), ("ann", 80)];
+    /// let stats = grouped_by(&scores, |s| s.0)
+    ///     .agg(|a| (a.mean_by(|s| s.1), a.fold(Vec::new(), |acc, s| acc.push(s.1))));
+    /// assert_eq!(stats, vec![("ann", (85.0, vec![90, 80])), ("bob", (70.0, vec![70]))]);
+    /// ```
+    pub fn agg<A, B>(&self, build: B) -> Vec<(K, A::Output)>
+    where
+        A: Aggregator<'a, T>,
+        B: FnOnce(Aggs<T>) -> A,
+    {
+        self.aggregate(build(Aggs::new()))
+    }
+
+    /// Count the elements of each group.
+    pub fn count(&self) -> Vec<(K, usize)> {
+        self.aggregate(Count)
+    }
+
+    /// Sum the values produced by `iteratee` for each group.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::collection::grouped::grouped_by;
+    ///
+    /// let orders = vec![("tea", 3), ("coffee", 5), ("tea", 4)];
+    /// let totals = grouped_by(&orders, |o| o.0).sum_by(|o| o.1);
+    /// assert_eq!(totals, vec![("tea", 7), ("coffee", 5)]);
+    /// ```
+    pub fn sum_by<N, G>(&self, iteratee: G) -> Vec<(K, N)>
+    where
+        N: Numeric,
+        G: Fn(&T) -> N,
+    {
+        self.aggregate(SumBy::new(iteratee))
+    }
+
+    /// Average the values produced by `iteratee` for each group.
+    pub fn mean_by<N, G>(&self, iteratee: G) -> Vec<(K, f64)>
+    where
+        N: Numeric,
+        G: Fn(&T) -> N,
+    {
+        self.aggregate(MeanBy::new(iteratee))
+    }
+
+    /// Select the element with the smallest key produced by `iteratee` in
+    /// each group.
+    pub fn min_by<V, G>(&self, iteratee: G) -> Vec<(K, &'a T)>
+    where
+        V: PartialOrd,
+        G: Fn(&T) -> V,
+    {
+        Self::present(self.aggregate(ExtremumBy::min(iteratee)))
+    }
+
+    /// Select the element with the largest key produced by `iteratee` in
+    /// each group.
+    pub fn max_by<V, G>(&self, iteratee: G) -> Vec<(K, &'a T)>
+    where
+        V: PartialOrd,
+        G: Fn(&T) -> V,
+    {
+        Self::present(self.aggregate(ExtremumBy::max(iteratee)))
+    }
+
+    /// Select the first element of each group.
+    pub fn first(&self) -> Vec<(K, &'a T)> {
+        Self::present(self.aggregate(Pick::first()))
+    }
+
+    /// Select the last element of each group.
+    pub fn last(&self) -> Vec<(K, &'a T)> {
+        Self::present(self.aggregate(Pick::last()))
+    }
+
+    /// Get the distinct keys in the order they were first seen.
+    pub fn keys(&self) -> Vec<K> {
+        self.aggregate(())
+            .into_iter()
+            .map(|(key, ())| key)
+            .collect()
+    }
+
+    /// Apply `f` to the elements of each group. Unlike the other aggregates
+    /// this collects references to each group's elements before calling `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::collection::grouped::grouped_by;
+    ///
+    /// let numbers = vec![3, 1, 4, 1, 5, 9, 2, 6];
+    /// let spans = grouped_by(&numbers, |n| n % 2).apply(|group| group.len());
+    /// assert_eq!(spans, vec![(1, 5), (0, 3)]);
+    /// ```
+    pub fn apply<U, G>(&self, f: G) -> Vec<(K, U)>
+    where
+        G: Fn(&[&'a T]) -> U,
+    {
+        self.aggregate(Members)
+            .into_iter()
+            .map(|(key, group)| (key, f(&group)))
+            .collect()
+    }
+
+    // Groups are never empty, so element-selecting aggregates always find one.
+    fn present(groups: Vec<(K, Option<&'a T>)>) -> Vec<(K, &'a T)> {
+        groups
+            .into_iter()
+            .filter_map(|(key, item)| item.map(|item| (key, item)))
+            .collect()
+    }
+}
+
+/// Group `collection` by the key produced by `iteratee`, for single-pass
+/// aggregation. See [`GroupedCollection`].
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::grouped::grouped_by;
+///
+/// let temperatures = vec![("mon", 12.0), ("tue", 15.5), ("mon", 14.0)];
+/// let means = grouped_by(&temperatures, |t| t.0).mean_by(|t| t.1);
+/// assert_eq!(means, vec![("mon", 13.0), ("tue", 15.5)]);
+/// ```
+pub fn grouped_by<'a, S, K, F>(collection: S, iteratee: F) -> GroupedCollection<'a, S::Item, K, F, S>
+where
+    S: Source<'a> + Clone,
+    K: Hash + Eq,
+    F: Fn(&S::Item) -> K,
+{
+    GroupedCollection::new(collection, iteratee)
+}
+
+/// Collection methods that work on the `Collection` type.
+impl<T> Collection<T> {
+    /// Group the collection by the key produced by `iteratee`, for
+    /// single-pass aggregation. See [`GroupedCollection`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::collection::Collection;
+    ///
+    /// let collection = Collection::new(vec![1, 2, 3, 4, 5, 6]);
+    /// let sums = collection.grouped_by(|x| x % 3).sum_by(|x| *x);
+    /// assert_eq!(sums, vec![(1, 5), (2, 7), (0, 9)]);
+    /// ```
+    pub fn grouped_by<K, F>(&self, iteratee: F) -> GroupedCollection<'_, T, K, F>
+    where
+        K: Hash + Eq,
+        F: Fn(&T) -> K,
+    {
+        GroupedCollection::new(self.data().as_slice(), iteratee)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[derive(Debug, PartialEq)]
+    struct Sale {
+        region: &'static str,
+        amount: f64,
+    }
+
+    fn sales() -> Vec<Sale> {
+        vec![
+            Sale {
+                region: "west",
+                amount: 4.0,
+            },
+            Sale {
+                region: "east",
+                amount: 1.5,
+            },
+            Sale {
+                region: "west",
+                amount: f64::NAN,
+            },
+            Sale {
+                region: "north",
+                amount: 2.0,
+            },
+            Sale {
+                region: "east",
+                amount: 3.5,
+            },
+            Sale {
+                region: "west",
+                amount: 1.0,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_groups_keep_insertion_order() {
+        let sales = sales();
+        let grouped = grouped_by(&sales, |s| s.region);
+        assert_eq!(grouped.keys(), vec!["west", "east", "north"]);
+        assert_eq!(
+            grouped.count(),
+            vec![("west", 3), ("east", 2), ("north", 1)]
+        );
+        assert_eq!(
+            grouped
+                .last()
+                .into_iter()
+                .map(|(k, s)| (k, s.amount))
+                .collect::<Vec<_>>(),
+            vec![("west", 1.0), ("east", 3.5), ("north", 2.0)]
+        );
+    }
+
+    #[test]
+    fn test_min_max_skip_nan() {
+        let sales = sales();
+        let grouped = grouped_by(&sales, |s| s.region);
+        let min: Vec<_> = grouped
+            .min_by(|s| s.amount)
+            .into_iter()
+            .map(|(k, s)| (k, s.amount))
+            .collect();
+        let max: Vec<_> = grouped
+            .max_by(|s| s.amount)
+            .into_iter()
+            .map(|(k, s)| (k, s.amount))
+            .collect();
+        assert_eq!(min, vec![("west", 1.0), ("east", 1.5), ("north", 2.0)]);
+        assert_eq!(max, vec![("west", 4.0), ("east", 3.5), ("north", 2.0)]);
+
+        let only_nan = vec![f64::NAN, 1.0];
+        assert_eq!(
+            grouped_by(&only_nan, |_| ()).min_by(|x| *x),
+            vec![((), &1.0)]
+        );
+    }
+
+    #[test]
+    fn test_multi_aggregation() {
+        let sales = sales();
+        let summary = grouped_by(&sales, |s| s.region).agg(|a| {
+            (
+                a.count(),
+                a.sum_by(|s| s.amount),
+                a.mean_by(|s| s.amount),
+                a.first(),
+            )
+        });
+        let (key, (count, sum, mean, first)) = &summary[1];
+        assert_eq!((*key, *count, *sum, *mean), ("east", 2, 5.0, 2.5));
+        assert_eq!(first.unwrap().amount, 1.5);
+        assert!(summary[0].1 .1.is_nan());
+    }
+
+    #[test]
+    fn test_compensated_group_sum() {
+        let values = vec![1.0, 1e100, 1.0, -1e100];
+        assert_eq!(grouped_by(&values, |_| 0).sum_by(|x| *x), vec![(0, 2.0)]);
+    }
+
+    #[test]
+    fn test_empty_collection() {
+        let empty: Vec<i32> = Vec::new();
+        assert!(grouped_by(&empty, |x| *x).count().is_empty());
+        assert!(Collection::new(empty).grouped_by(|x| *x).first().is_empty());
+    }
+}
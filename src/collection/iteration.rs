@@ -5,7 +5,9 @@ This module provides iteration methods like `each`, `map`, `filter`, `reduce`, e
 These are the core methods for processing collections.
 */
 
+use crate::collection::source::{Indexed, Source};
 use crate::collection::Collection;
+use alloc::vec::Vec;
 // Note: These imports are kept for future use in error handling and type constraints
 // use crate::utils::{LodashError, Result, Predicate, Mapper, Reducer};
 
@@ -20,11 +22,12 @@ use crate::collection::Collection;
 /// each(&[1, 2, 3], |x| sum += x);
 /// assert_eq!(sum, 6);
 /// ```
-pub fn each<T, F>(collection: &[T], mut iteratee: F)
+pub fn each<'a, S, F>(collection: S, mut iteratee: F)
 where
-    F: FnMut(&T),
+    S: Source<'a>,
+    F: FnMut(&S::Item),
 {
-    for item in collection {
+    for item in collection.source_iter() {
         iteratee(item);
     }
 }
@@ -40,9 +43,10 @@ where
 /// for_each(&[1, 2, 3], |x| sum += x);
 /// assert_eq!(sum, 6);
 /// ```
-pub fn for_each<T, F>(collection: &[T], iteratee: F)
+pub fn for_each<'a, S, F>(collection: S, iteratee: F)
 where
-    F: FnMut(&T),
+    S: Source<'a>,
+    F: FnMut(&S::Item),
 {
     each(collection, iteratee);
 }
@@ -61,11 +65,12 @@ where
 /// let strings = map(&[1, 2, 3], |x| x.to_string());
 /// assert_eq!(strings, vec!["1", "2", "3"]);
 /// ```
-pub fn map<T, U, F>(collection: &[T], iteratee: F) -> Vec<U>
+pub fn map<'a, S, U, F>(collection: S, iteratee: F) -> Vec<U>
 where
-    F: Fn(&T) -> U,
+    S: Source<'a>,
+    F: Fn(&S::Item) -> U,
 {
-    collection.iter().map(iteratee).collect()
+    collection.source_iter().map(iteratee).collect()
 }
 
 /// Iterate over elements of collection, returning an array of all elements
@@ -79,12 +84,13 @@ where
 /// let evens = filter(&[1, 2, 3, 4, 5], |x| x % 2 == 0);
 /// assert_eq!(evens, vec![2, 4]);
 /// ```
-pub fn filter<T, F>(collection: &[T], predicate: F) -> Vec<T>
+pub fn filter<'a, S, F>(collection: S, predicate: F) -> Vec<S::Item>
 where
-    T: Clone,
-    F: Fn(&T) -> bool,
+    S: Source<'a>,
+    S::Item: Clone,
+    F: Fn(&S::Item) -> bool,
 {
-    collection.iter()
+    collection.source_iter()
         .filter(|item| predicate(item))
         .cloned()
         .collect()
@@ -106,11 +112,12 @@ where
 /// let result = reduce(&["a", "b", "c"], |acc, x| format!("{}{}", acc, x), String::new());
 /// assert_eq!(result, "abc");
 /// ```
-pub fn reduce<T, U, F>(collection: &[T], iteratee: F, initial: U) -> U
+pub fn reduce<'a, S, U, F>(collection: S, iteratee: F, initial: U) -> U
 where
-    F: Fn(U, &T) -> U,
+    S: Source<'a>,
+    F: Fn(U, &S::Item) -> U,
 {
-    collection.iter().fold(initial, iteratee)
+    collection.source_iter().fold(initial, iteratee)
 }
 
 /// This method is like `reduce` except that it iterates over elements of
@@ -124,11 +131,12 @@ where
 /// let result = reduce_right(&["a", "b", "c"], |acc, x| format!("{}{}", acc, x), String::new());
 /// assert_eq!(result, "cba");
 /// ```
-pub fn reduce_right<T, U, F>(collection: &[T], iteratee: F, initial: U) -> U
+pub fn reduce_right<'a, S, U, F>(collection: S, iteratee: F, initial: U) -> U
 where
-    F: Fn(U, &T) -> U,
+    S: Source<'a>,
+    F: Fn(U, &S::Item) -> U,
 {
-    collection.iter().rev().fold(initial, iteratee)
+    Indexed::new(collection).iter_rev().fold(initial, iteratee)
 }
 
 /// This method is like `each` except that it iterates over elements of
@@ -143,11 +151,12 @@ where
 /// for_each_right(&[1, 2, 3], |x| result.push(*x));
 /// assert_eq!(result, vec![3, 2, 1]);
 /// ```
-pub fn for_each_right<T, F>(collection: &[T], mut iteratee: F)
+pub fn for_each_right<'a, S, F>(collection: S, mut iteratee: F)
 where
-    F: FnMut(&T),
+    S: Source<'a>,
+    F: FnMut(&S::Item),
 {
-    for item in collection.iter().rev() {
+    for item in Indexed::new(collection).iter_rev() {
         iteratee(item);
     }
 }
@@ -271,6 +280,8 @@ impl<T> Collection<T> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::string::{String, ToString};
+    use alloc::{format, vec};
 
     #[test]
     fn test_each() {
@@ -291,7 +302,7 @@ mod tests {
         let doubled = map(&[1, 2, 3], |x| x * 2);
         assert_eq!(doubled, vec![2, 4, 6]);
 
-        let strings = map(&[1, 2, 3], std::string::ToString::to_string);
+        let strings = map(&[1, 2, 3], ToString::to_string);
         assert_eq!(strings, vec!["1", "2", "3"]);
     }
 
@@ -310,6 +321,29 @@ mod tests {
         assert_eq!(result, "abc");
     }
 
+    #[test]
+    fn test_non_slice_sources() {
+        use crate::collection::source::refs;
+        use crate::utils::hash::HashMap;
+        use alloc::collections::{BTreeSet, VecDeque};
+
+        let set = BTreeSet::from([3, 1, 2]);
+        assert_eq!(map(&set, |x| x * 2), vec![2, 4, 6]);
+        assert_eq!(filter(set.iter(), |x| *x > 1), vec![2, 3]);
+
+        let queue = VecDeque::from(vec!["a", "b", "c"]);
+        let joined = reduce_right(&queue, |acc, x| format!("{acc}{x}"), String::new());
+        assert_eq!(joined, "cba");
+
+        let ages: HashMap<&str, i32> = [("ann", 31), ("bob", 27)].into_iter().collect();
+        assert_eq!(reduce(ages.values(), |acc, age| acc + age, 0), 58);
+
+        let numbers = [1, 2, 3, 4, 5, 6];
+        let mut seen = Vec::new();
+        for_each_right(refs(numbers.iter().step_by(2)), |x| seen.push(*x));
+        assert_eq!(seen, vec![5, 3, 1]);
+    }
+
     #[test]
     fn test_reduce_right() {
         let result = reduce_right(&["a", "b", "c"], |acc, x| format!("{acc}{x}"), String::new());
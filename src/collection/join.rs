@@ -0,0 +1,813 @@
+/*!
+Join methods for Lodash-RS.
+
+This module provides relational joins between two collections by key:
+`join_by` (inner), `left_join_by`, `outer_join_by`, `semi_join_by` and
+`anti_join_by`. Each takes a key extractor for either side.
+
+The `*_by` functions use a hash join and need `Hash + Eq` keys. The `*_by_ord`
+functions use a sort-merge join instead, for keys that are `Ord` but not
+`Hash`. Both produce the same rows in the same order: left elements in their
+original order, each followed by its matches in right-hand order. Outer joins
+then list the unmatched right elements in their original order.
+*/
+
+use crate::collection::source::{Indexed, Source};
+use crate::collection::Collection;
+use crate::utils::hash::HashMap;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::hash::Hash;
+
+/// Index the right-hand side of a hash join by key.
+pub(crate) fn hash_index<'a, B, K, FB>(
+    right: impl IntoIterator<Item = &'a B>,
+    right_key: FB,
+) -> HashMap<K, Vec<usize>>
+where
+    B: 'a,
+    K: Hash + Eq,
+    FB: Fn(&B) -> K,
+{
+    let mut index: HashMap<K, Vec<usize>> = HashMap::new();
+    for (position, item) in right.into_iter().enumerate() {
+        index.entry(right_key(item)).or_default().push(position);
+    }
+    index
+}
+
+/// Matching `(left, right)` index pairs, found with a hash join.
+fn hash_matches<A, B, K, FA, FB>(
+    left: &Indexed<'_, A>,
+    right: &Indexed<'_, B>,
+    left_key: FA,
+    right_key: FB,
+) -> Vec<(usize, usize)>
+where
+    K: Hash + Eq,
+    FA: Fn(&A) -> K,
+    FB: Fn(&B) -> K,
+{
+    let index = hash_index(right.iter(), right_key);
+    let mut pairs = Vec::new();
+    for (i, item) in left.iter().enumerate() {
+        if let Some(matches) = index.get(&left_key(item)) {
+            pairs.extend(matches.iter().map(|&j| (i, j)));
+        }
+    }
+    pairs
+}
+
+/// Matching `(left, right)` index pairs, found with a sort-merge join and
+/// returned in the same order as `hash_matches`.
+fn merge_matches<A, B, K, FA, FB>(
+    left: &Indexed<'_, A>,
+    right: &Indexed<'_, B>,
+    left_key: FA,
+    right_key: FB,
+) -> Vec<(usize, usize)>
+where
+    K: Ord,
+    FA: Fn(&A) -> K,
+    FB: Fn(&B) -> K,
+{
+    let mut lefts: Vec<(K, usize)> = left.iter().map(left_key).zip(0..).collect();
+    let mut rights: Vec<(K, usize)> = right.iter().map(right_key).zip(0..).collect();
+    lefts.sort_unstable();
+    rights.sort_unstable();
+
+    let mut pairs = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < lefts.len() && j < rights.len() {
+        match lefts[i].0.cmp(&rights[j].0) {
+            Ordering::Less => i += 1,
+            Ordering::Greater => j += 1,
+            Ordering::Equal => {
+                let left_end = run_end(&lefts, i);
+                let right_end = run_end(&rights, j);
+                for (_, l) in &lefts[i..left_end] {
+                    pairs.extend(rights[j..right_end].iter().map(|&(_, r)| (*l, r)));
+                }
+                i = left_end;
+                j = right_end;
+            }
+        }
+    }
+    pairs.sort_unstable();
+    pairs
+}
+
+// The end of the run of keys equal to `keys[start]`.
+fn run_end<K: Ord>(keys: &[(K, usize)], start: usize) -> usize {
+    keys[start..]
+        .iter()
+        .position(|(key, _)| *key != keys[start].0)
+        .map_or(keys.len(), |offset| start + offset)
+}
+
+pub(crate) fn inner_rows<A, B>(
+    left: &Indexed<'_, A>,
+    right: &Indexed<'_, B>,
+    pairs: &[(usize, usize)],
+) -> Vec<(A, B)>
+where
+    A: Clone,
+    B: Clone,
+{
+    pairs
+        .iter()
+        .map(|&(i, j)| (left[i].clone(), right[j].clone()))
+        .collect()
+}
+
+pub(crate) fn left_rows<A, B>(
+    left: &Indexed<'_, A>,
+    right: &Indexed<'_, B>,
+    pairs: &[(usize, usize)],
+) -> Vec<(A, Option<B>)>
+where
+    A: Clone,
+    B: Clone,
+{
+    let mut rows = Vec::with_capacity(left.len().max(pairs.len()));
+    let mut pairs = pairs.iter().peekable();
+    for (i, item) in left.iter().enumerate() {
+        let mut matched = false;
+        while let Some(&&(_, j)) = pairs.peek().filter(|&&&(l, _)| l == i) {
+            rows.push((item.clone(), Some(right[j].clone())));
+            matched = true;
+            pairs.next();
+        }
+        if !matched {
+            rows.push((item.clone(), None));
+        }
+    }
+    rows
+}
+
+pub(crate) fn outer_rows<A, B>(
+    left: &Indexed<'_, A>,
+    right: &Indexed<'_, B>,
+    pairs: &[(usize, usize)],
+) -> Vec<(Option<A>, Option<B>)>
+where
+    A: Clone,
+    B: Clone,
+{
+    let mut matched = vec![false; right.len()];
+    for &(_, j) in pairs {
+        matched[j] = true;
+    }
+    let mut rows: Vec<(Option<A>, Option<B>)> = left_rows(left, right, pairs)
+        .into_iter()
+        .map(|(a, b)| (Some(a), b))
+        .collect();
+    rows.extend(
+        right
+            .iter()
+            .zip(matched)
+            .filter(|(_, matched)| !matched)
+            .map(|(item, _)| (None, Some(item.clone()))),
+    );
+    rows
+}
+
+pub(crate) fn filter_rows<A>(
+    left: &Indexed<'_, A>,
+    pairs: &[(usize, usize)],
+    keep_matched: bool,
+) -> Vec<A>
+where
+    A: Clone,
+{
+    let mut matched = vec![false; left.len()];
+    for &(i, _) in pairs {
+        matched[i] = true;
+    }
+    left.iter()
+        .zip(matched)
+        .filter(|&(_, matched)| matched == keep_matched)
+        .map(|(item, _)| item.clone())
+        .collect()
+}
+
+/// Inner join: pair every left element with every right element that has
+/// the same key.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::join::join_by;
+///
+/// let users = vec![(1, "ann"), (2, "bob"), (3, "cy")];
+/// let orders = vec![("tea", 2), ("pie", 1), ("jam", 2)];
+/// let joined = join_by(&users, &orders, |u| u.0, |o| o.1);
+/// assert_eq!(
+///     joined,
+///     vec![
+///         ((1, "ann"), ("pie", 1)),
+///         ((2, "bob"), ("tea", 2)),
+///         ((2, "bob"), ("jam", 2)),
+///     ]
+/// );
+/// ```
+pub fn join_by<'a, A, B, SA, SB, K, FA, FB>(
+    left: SA,
+    right: SB,
+    left_key: FA,
+    right_key: FB,
+) -> Vec<(A, B)>
+where
+    A: 'a,
+    B: 'a,
+    SA: Source<'a, Item = A>,
+    SB: Source<'a, Item = B>,
+    A: Clone,
+    B: Clone,
+    K: Hash + Eq,
+    FA: Fn(&A) -> K,
+    FB: Fn(&B) -> K,
+{
+    let (left, right) = (Indexed::new(left), Indexed::new(right));
+    inner_rows(
+        &left,
+        &right,
+        &hash_matches(&left, &right, left_key, right_key),
+    )
+}
+
+/// Left join: like `join_by`, but left elements without a match are kept,
+/// paired with `None`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::join::left_join_by;
+///
+/// let users = vec![(1, "ann"), (3, "cy")];
+/// let orders = vec![("pie", 1)];
+/// let joined = left_join_by(&users, &orders, |u| u.0, |o| o.1);
+/// assert_eq!(joined, vec![((1, "ann"), Some(("pie", 1))), ((3, "cy"), None)]);
+/// ```
+pub fn left_join_by<'a, A, B, SA, SB, K, FA, FB>(
+    left: SA,
+    right: SB,
+    left_key: FA,
+    right_key: FB,
+) -> Vec<(A, Option<B>)>
+where
+    A: 'a,
+    B: 'a,
+    SA: Source<'a, Item = A>,
+    SB: Source<'a, Item = B>,
+    A: Clone,
+    B: Clone,
+    K: Hash + Eq,
+    FA: Fn(&A) -> K,
+    FB: Fn(&B) -> K,
+{
+    let (left, right) = (Indexed::new(left), Indexed::new(right));
+    left_rows(
+        &left,
+        &right,
+        &hash_matches(&left, &right, left_key, right_key),
+    )
+}
+
+/// Full outer join: like `left_join_by`, followed by the right elements
+/// without a match, paired with `None`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::join::outer_join_by;
+///
+/// let left = vec![1, 2];
+/// let right = vec![2, 4];
+/// let joined = outer_join_by(&left, &right, |x| *x, |x| *x);
+/// assert_eq!(
+///     joined,
+///     vec![(Some(1), None), (Some(2), Some(2)), (None, Some(4))]
+/// );
+/// ```
+pub fn outer_join_by<'a, A, B, SA, SB, K, FA, FB>(
+    left: SA,
+    right: SB,
+    left_key: FA,
+    right_key: FB,
+) -> Vec<(Option<A>, Option<B>)>
+where
+    A: 'a,
+    B: 'a,
+    SA: Source<'a, Item = A>,
+    SB: Source<'a, Item = B>,
+    A: Clone,
+    B: Clone,
+    K: Hash + Eq,
+    FA: Fn(&A) -> K,
+    FB: Fn(&B) -> K,
+{
+    let (left, right) = (Indexed::new(left), Indexed::new(right));
+    outer_rows(
+        &left,
+        &right,
+        &hash_matches(&left, &right, left_key, right_key),
+    )
+}
+
+/// Semi join: the left elements that have at least one match.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::join::semi_join_by;
+///
+/// let users = vec![(1, "ann"), (2, "bob"), (3, "cy")];
+/// let orders = vec![("tea", 2), ("jam", 2)];
+/// assert_eq!(semi_join_by(&users, &orders, |u| u.0, |o| o.1), vec![(2, "bob")]);
+/// ```
+pub fn semi_join_by<'a, A, B, SA, SB, K, FA, FB>(
+    left: SA,
+    right: SB,
+    left_key: FA,
+    right_key: FB,
+) -> Vec<A>
+where
+    A: 'a,
+    B: 'a,
+    SA: Source<'a, Item = A>,
+    SB: Source<'a, Item = B>,
+    A: Clone,
+    K: Hash + Eq,
+    FA: Fn(&A) -> K,
+    FB: Fn(&B) -> K,
+{
+    let (left, right) = (Indexed::new(left), Indexed::new(right));
+    filter_rows(
+        &left,
+        &hash_matches(&left, &right, left_key, right_key),
+        true,
+    )
+}
+
+/// Anti join: the left elements that have no match.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::join::anti_join_by;
+///
+/// let users = vec![(1, "ann"), (2, "bob"), (3, "cy")];
+/// let orders = vec![("tea", 2), ("jam", 2)];
+/// assert_eq!(
+///     anti_join_by(&users, &orders, |u| u.0, |o| o.1),
+///     vec![(1, "ann"), (3, "cy")]
+/// );
+/// ```
+pub fn anti_join_by<'a, A, B, SA, SB, K, FA, FB>(
+    left: SA,
+    right: SB,
+    left_key: FA,
+    right_key: FB,
+) -> Vec<A>
+where
+    A: 'a,
+    B: 'a,
+    SA: Source<'a, Item = A>,
+    SB: Source<'a, Item = B>,
+    A: Clone,
+    K: Hash + Eq,
+    FA: Fn(&A) -> K,
+    FB: Fn(&B) -> K,
+{
+    let (left, right) = (Indexed::new(left), Indexed::new(right));
+    filter_rows(
+        &left,
+        &hash_matches(&left, &right, left_key, right_key),
+        false,
+    )
+}
+
+/// Sort-merge version of `join_by`, for `Ord` keys.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::join::join_by_ord;
+///
+/// let left = vec![vec![1], vec![2]];
+/// let right = vec![vec![2], vec![2], vec![3]];
+/// let joined = join_by_ord(&left, &right, Clone::clone, Clone::clone);
+/// assert_eq!(joined, vec![(vec![2], vec![2]), (vec![2], vec![2])]);
+/// ```
+pub fn join_by_ord<'a, A, B, SA, SB, K, FA, FB>(
+    left: SA,
+    right: SB,
+    left_key: FA,
+    right_key: FB,
+) -> Vec<(A, B)>
+where
+    A: 'a,
+    B: 'a,
+    SA: Source<'a, Item = A>,
+    SB: Source<'a, Item = B>,
+    A: Clone,
+    B: Clone,
+    K: Ord,
+    FA: Fn(&A) -> K,
+    FB: Fn(&B) -> K,
+{
+    let (left, right) = (Indexed::new(left), Indexed::new(right));
+    inner_rows(
+        &left,
+        &right,
+        &merge_matches(&left, &right, left_key, right_key),
+    )
+}
+
+/// Sort-merge version of `left_join_by`, for `Ord` keys.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::join::left_join_by_ord;
+///
+/// let joined = left_join_by_ord(&[3, 1], &[1], |x| *x, |x| *x);
+/// assert_eq!(joined, vec![(3, None), (1, Some(1))]);
+/// ```
+pub fn left_join_by_ord<'a, A, B, SA, SB, K, FA, FB>(
+    left: SA,
+    right: SB,
+    left_key: FA,
+    right_key: FB,
+) -> Vec<(A, Option<B>)>
+where
+    A: 'a,
+    B: 'a,
+    SA: Source<'a, Item = A>,
+    SB: Source<'a, Item = B>,
+    A: Clone,
+    B: Clone,
+    K: Ord,
+    FA: Fn(&A) -> K,
+    FB: Fn(&B) -> K,
+{
+    let (left, right) = (Indexed::new(left), Indexed::new(right));
+    left_rows(
+        &left,
+        &right,
+        &merge_matches(&left, &right, left_key, right_key),
+    )
+}
+
+/// Sort-merge version of `outer_join_by`, for `Ord` keys.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::join::outer_join_by_ord;
+///
+/// let joined = outer_join_by_ord(&[1, 2], &[4, 2], |x| *x, |x| *x);
+/// assert_eq!(joined, vec![(Some(1), None), (Some(2), Some(2)), (None, Some(4))]);
+/// ```
+pub fn outer_join_by_ord<'a, A, B, SA, SB, K, FA, FB>(
+    left: SA,
+    right: SB,
+    left_key: FA,
+    right_key: FB,
+) -> Vec<(Option<A>, Option<B>)>
+where
+    A: 'a,
+    B: 'a,
+    SA: Source<'a, Item = A>,
+    SB: Source<'a, Item = B>,
+    A: Clone,
+    B: Clone,
+    K: Ord,
+    FA: Fn(&A) -> K,
+    FB: Fn(&B) -> K,
+{
+    let (left, right) = (Indexed::new(left), Indexed::new(right));
+    outer_rows(
+        &left,
+        &right,
+        &merge_matches(&left, &right, left_key, right_key),
+    )
+}
+
+/// Sort-merge version of `semi_join_by`, for `Ord` keys.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::join::semi_join_by_ord;
+///
+/// assert_eq!(semi_join_by_ord(&[1, 2, 3], &[3, 1], |x| *x, |x| *x), vec![1, 3]);
+/// ```
+pub fn semi_join_by_ord<'a, A, B, SA, SB, K, FA, FB>(
+    left: SA,
+    right: SB,
+    left_key: FA,
+    right_key: FB,
+) -> Vec<A>
+where
+    A: 'a,
+    B: 'a,
+    SA: Source<'a, Item = A>,
+    SB: Source<'a, Item = B>,
+    A: Clone,
+    K: Ord,
+    FA: Fn(&A) -> K,
+    FB: Fn(&B) -> K,
+{
+    let (left, right) = (Indexed::new(left), Indexed::new(right));
+    filter_rows(
+        &left,
+        &merge_matches(&left, &right, left_key, right_key),
+        true,
+    )
+}
+
+/// Sort-merge version of `anti_join_by`, for `Ord` keys.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::join::anti_join_by_ord;
+///
+/// assert_eq!(anti_join_by_ord(&[1, 2, 3], &[3, 1], |x| *x, |x| *x), vec![2]);
+/// ```
+pub fn anti_join_by_ord<'a, A, B, SA, SB, K, FA, FB>(
+    left: SA,
+    right: SB,
+    left_key: FA,
+    right_key: FB,
+) -> Vec<A>
+where
+    A: 'a,
+    B: 'a,
+    SA: Source<'a, Item = A>,
+    SB: Source<'a, Item = B>,
+    A: Clone,
+    K: Ord,
+    FA: Fn(&A) -> K,
+    FB: Fn(&B) -> K,
+{
+    let (left, right) = (Indexed::new(left), Indexed::new(right));
+    filter_rows(
+        &left,
+        &merge_matches(&left, &right, left_key, right_key),
+        false,
+    )
+}
+
+/// Collection methods that work on the `Collection` type.
+impl<T> Collection<T>
+where
+    T: Clone,
+{
+    /// Inner join with `right` by key. See [`join_by`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::collection::Collection;
+    ///
+    /// let users = Collection::new(vec![(1, "ann"), (2, "bob")]);
+    /// let joined = users.join_by(&[(2, 9.5)], |u| u.0, |s| s.0);
+    /// assert_eq!(joined, vec![((2, "bob"), (2, 9.5))]);
+    /// ```
+    pub fn join_by<'a, B, S, K, FA, FB>(
+        &'a self,
+        right: S,
+        left_key: FA,
+        right_key: FB,
+    ) -> Vec<(T, B)>
+    where
+        B: Clone + 'a,
+        S: Source<'a, Item = B>,
+        K: Hash + Eq,
+        FA: Fn(&T) -> K,
+        FB: Fn(&B) -> K,
+    {
+        join_by(&self.data, right, left_key, right_key)
+    }
+
+    /// Left join with `right` by key. See [`left_join_by`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::collection::Collection;
+    ///
+    /// let users = Collection::new(vec![(1, "ann"), (2, "bob")]);
+    /// let joined = users.left_join_by(&[(2, 9.5)], |u| u.0, |s| s.0);
+    /// assert_eq!(joined, vec![((1, "ann"), None), ((2, "bob"), Some((2, 9.5)))]);
+    /// ```
+    pub fn left_join_by<'a, B, S, K, FA, FB>(
+        &'a self,
+        right: S,
+        left_key: FA,
+        right_key: FB,
+    ) -> Vec<(T, Option<B>)>
+    where
+        B: Clone + 'a,
+        S: Source<'a, Item = B>,
+        K: Hash + Eq,
+        FA: Fn(&T) -> K,
+        FB: Fn(&B) -> K,
+    {
+        left_join_by(&self.data, right, left_key, right_key)
+    }
+
+    /// Full outer join with `right` by key. See [`outer_join_by`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::collection::Collection;
+    ///
+    /// let left = Collection::new(vec![1, 2]);
+    /// let joined = left.outer_join_by(&[3], |x| *x, |x| *x);
+    /// assert_eq!(joined, vec![(Some(1), None), (Some(2), None), (None, Some(3))]);
+    /// ```
+    pub fn outer_join_by<'a, B, S, K, FA, FB>(
+        &'a self,
+        right: S,
+        left_key: FA,
+        right_key: FB,
+    ) -> Vec<(Option<T>, Option<B>)>
+    where
+        B: Clone + 'a,
+        S: Source<'a, Item = B>,
+        K: Hash + Eq,
+        FA: Fn(&T) -> K,
+        FB: Fn(&B) -> K,
+    {
+        outer_join_by(&self.data, right, left_key, right_key)
+    }
+
+    /// The elements with a match in `right`. See [`semi_join_by`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::collection::Collection;
+    ///
+    /// let ids = Collection::new(vec![1, 2, 3]);
+    /// assert_eq!(ids.semi_join_by(&["2", "3"], |x| x.to_string(), |s| s.to_string()), vec![2, 3]);
+    /// ```
+    pub fn semi_join_by<'a, B, S, K, FA, FB>(
+        &'a self,
+        right: S,
+        left_key: FA,
+        right_key: FB,
+    ) -> Vec<T>
+    where
+        B: 'a,
+        S: Source<'a, Item = B>,
+        K: Hash + Eq,
+        FA: Fn(&T) -> K,
+        FB: Fn(&B) -> K,
+    {
+        semi_join_by(&self.data, right, left_key, right_key)
+    }
+
+    /// The elements without a match in `right`. See [`anti_join_by`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::collection::Collection;
+    ///
+    /// let ids = Collection::new(vec![1, 2, 3]);
+    /// assert_eq!(ids.anti_join_by(&[2], |x| *x, |x| *x), vec![1, 3]);
+    /// ```
+    pub fn anti_join_by<'a, B, S, K, FA, FB>(
+        &'a self,
+        right: S,
+        left_key: FA,
+        right_key: FB,
+    ) -> Vec<T>
+    where
+        B: 'a,
+        S: Source<'a, Item = B>,
+        K: Hash + Eq,
+        FA: Fn(&T) -> K,
+        FB: Fn(&B) -> K,
+    {
+        anti_join_by(&self.data, right, left_key, right_key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Sides = (Vec<(u32, char)>, Vec<(char, u32)>);
+
+    fn sides() -> Sides {
+        let left = vec![(3, 'a'), (1, 'b'), (2, 'c'), (3, 'd'), (5, 'e')];
+        let right = vec![('x', 3), ('y', 4), ('z', 3), ('w', 1), ('v', 4)];
+        (left, right)
+    }
+
+    #[test]
+    fn test_hash_and_merge_joins_agree() {
+        let (left, right) = sides();
+        let (lk, rk) = (|l: &(u32, char)| l.0, |r: &(char, u32)| r.1);
+
+        let inner = join_by(&left, &right, lk, rk);
+        assert_eq!(
+            inner,
+            vec![
+                ((3, 'a'), ('x', 3)),
+                ((3, 'a'), ('z', 3)),
+                ((1, 'b'), ('w', 1)),
+                ((3, 'd'), ('x', 3)),
+                ((3, 'd'), ('z', 3)),
+            ]
+        );
+        assert_eq!(join_by_ord(&left, &right, lk, rk), inner);
+        assert_eq!(
+            left_join_by_ord(&left, &right, lk, rk),
+            left_join_by(&left, &right, lk, rk)
+        );
+        assert_eq!(
+            outer_join_by_ord(&left, &right, lk, rk),
+            outer_join_by(&left, &right, lk, rk)
+        );
+        assert_eq!(
+            semi_join_by_ord(&left, &right, lk, rk),
+            semi_join_by(&left, &right, lk, rk)
+        );
+        assert_eq!(
+            anti_join_by_ord(&left, &right, lk, rk),
+            anti_join_by(&left, &right, lk, rk)
+        );
+    }
+
+    #[test]
+    fn test_outer_and_filtering_joins() {
+        let (left, right) = sides();
+        let outer = outer_join_by(&left, &right, |l| l.0, |r| r.1);
+        assert_eq!(outer.len(), 9);
+        assert_eq!(outer[3], (Some((2, 'c')), None));
+        assert_eq!(
+            outer[6..],
+            [
+                (Some((5, 'e')), None),
+                (None, Some(('y', 4))),
+                (None, Some(('v', 4))),
+            ]
+        );
+        assert_eq!(
+            anti_join_by(&left, &right, |l| l.0, |r| r.1),
+            vec![(2, 'c'), (5, 'e')]
+        );
+        assert_eq!(semi_join_by(&left, &right, |l| l.0, |r| r.1).len(), 3);
+    }
+
+    #[test]
+    fn test_empty_sides() {
+        let empty: Vec<i32> = Vec::new();
+        assert!(join_by(&empty, &[1], |x| *x, |x| *x).is_empty());
+        assert_eq!(left_join_by(&[1], &empty, |x| *x, |x| *x), vec![(1, None)]);
+        assert_eq!(
+            outer_join_by(&empty, &[1], |x| *x, |x| *x),
+            vec![(None, Some(1))]
+        );
+        assert_eq!(anti_join_by_ord(&[1], &empty, |x| *x, |x| *x), vec![1]);
+    }
+
+    #[test]
+    fn test_joins_accept_any_source() {
+        use crate::collection::source::refs;
+        use alloc::collections::{BTreeSet, VecDeque};
+
+        let (left, right) = sides();
+        let (lk, rk) = (|l: &(u32, char)| l.0, |r: &(char, u32)| r.1);
+        let queue: VecDeque<(u32, char)> = left.iter().copied().collect();
+        let set: BTreeSet<(char, u32)> = right.iter().copied().collect();
+        let sorted_right: Vec<(char, u32)> = set.iter().copied().collect();
+
+        assert_eq!(
+            join_by(&queue, &set, lk, rk),
+            join_by(&left, &sorted_right, lk, rk)
+        );
+        assert_eq!(
+            outer_join_by_ord(refs(left.iter().rev()), &set, lk, rk),
+            outer_join_by(
+                &left.iter().rev().copied().collect::<Vec<_>>(),
+                &sorted_right,
+                lk,
+                rk
+            )
+        );
+        assert_eq!(
+            semi_join_by(&queue, set.iter(), lk, rk),
+            vec![(3, 'a'), (1, 'b'), (3, 'd')]
+        );
+    }
+}
@@ -10,6 +10,15 @@ pub mod query;
 pub mod transform;
 pub mod operation;
 pub mod async_support;
+pub mod grouped;
+pub mod join;
+pub mod ordered_map;
+#[cfg(feature = "std")]
+pub mod pivot;
+pub mod window;
+pub mod source;
+
+use alloc::vec::Vec;
 
 // Note: These imports are kept for future use in error handling
 // use crate::utils::{LodashError, Result};
@@ -18,7 +27,14 @@ pub mod async_support;
 /// 
 /// This is the main type used throughout Lodash-RS for collection operations.
 /// It provides a type-safe wrapper around `Vec<T>` with additional functionality.
+///
+/// With the `serialize` feature it serializes transparently as a sequence.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct Collection<T> {
     /// The underlying data
     data: Vec<T>,
@@ -34,6 +50,12 @@ impl<T> Collection<T> {
     /// 
     /// let collection = Collection::new(vec![1, 2, 3]);
     /// assert_eq!(collection.len(), 3);
+    ///
+    /// // Borrowing iterators build a collection of references, without
+    /// // cloning the elements
+    /// let set = std::collections::BTreeSet::from([3, 1, 2]);
+    /// let borrowed = Collection::new(set.iter());
+    /// assert_eq!(borrowed.map(|x| **x * 2), vec![2, 4, 6]);
     /// ```
     pub fn new(data: impl IntoIterator<Item = T>) -> Self {
         Self {
@@ -217,7 +239,7 @@ impl<T> Collection<T> {
     /// ```
     #[must_use]
     #[allow(clippy::should_implement_trait)]
-    pub fn into_iter(self) -> std::vec::IntoIter<T> {
+    pub fn into_iter(self) -> alloc::vec::IntoIter<T> {
         self.data.into_iter()
     }
 
@@ -232,7 +254,7 @@ impl<T> Collection<T> {
     /// let sum: i32 = collection.iter().sum();
     /// assert_eq!(sum, 6);
     /// ```
-    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
         self.data.iter()
     }
 
@@ -249,7 +271,7 @@ impl<T> Collection<T> {
     /// }
     /// assert_eq!(collection.data(), &vec![2, 4, 6]);
     /// ```
-    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, T> {
         self.data.iter_mut()
     }
 }
@@ -260,13 +282,19 @@ impl<T> From<Vec<T>> for Collection<T> {
     }
 }
 
+impl<T> FromIterator<T> for Collection<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::new(iter)
+    }
+}
+
 impl<T> From<Collection<T>> for Vec<T> {
     fn from(collection: Collection<T>) -> Self {
         collection.data
     }
 }
 
-impl<T> std::ops::Index<usize> for Collection<T> {
+impl<T> core::ops::Index<usize> for Collection<T> {
     type Output = T;
 
     fn index(&self, index: usize) -> &Self::Output {
@@ -274,7 +302,7 @@ impl<T> std::ops::Index<usize> for Collection<T> {
     }
 }
 
-impl<T> std::ops::IndexMut<usize> for Collection<T> {
+impl<T> core::ops::IndexMut<usize> for Collection<T> {
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
         &mut self.data[index]
     }
@@ -282,7 +310,7 @@ impl<T> std::ops::IndexMut<usize> for Collection<T> {
 
 impl<T> IntoIterator for Collection<T> {
     type Item = T;
-    type IntoIter = std::vec::IntoIter<T>;
+    type IntoIter = alloc::vec::IntoIter<T>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.data.into_iter()
@@ -291,7 +319,7 @@ impl<T> IntoIterator for Collection<T> {
 
 impl<'a, T> IntoIterator for &'a Collection<T> {
     type Item = &'a T;
-    type IntoIter = std::slice::Iter<'a, T>;
+    type IntoIter = core::slice::Iter<'a, T>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.data.iter()
@@ -300,7 +328,7 @@ impl<'a, T> IntoIterator for &'a Collection<T> {
 
 impl<'a, T> IntoIterator for &'a mut Collection<T> {
     type Item = &'a mut T;
-    type IntoIter = std::slice::IterMut<'a, T>;
+    type IntoIter = core::slice::IterMut<'a, T>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.data.iter_mut()
@@ -310,6 +338,7 @@ impl<'a, T> IntoIterator for &'a mut Collection<T> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::vec;
 
     #[test]
     fn test_collection_creation() {
@@ -3,13 +3,22 @@ Collection operation methods for Lodash-RS.
 
 This module provides collection operation methods like `shuffle`, `sample`, `size`, etc.
 These methods are used to manipulate and analyze collections.
+
+The random methods come in two forms: `shuffle_with`, `sample_with` and
+`sample_size_with` take a caller-supplied [`Rng`] and are available on
+`no_std` builds, while `shuffle`, `sample` and `sample_size` use the thread
+local RNG and need the `std` feature.
 */
 
+use crate::collection::source::{source_len, Indexed, Source};
 use crate::collection::Collection;
 // Note: These imports are kept for future use in error handling
 // use crate::utils::{LodashError, Result};
+use alloc::vec::Vec;
 use rand::seq::SliceRandom;
+#[cfg(feature = "std")]
 use rand::thread_rng;
+use rand::Rng;
 
 /// Get the size of collection.
 /// 
@@ -24,8 +33,8 @@ use rand::thread_rng;
 /// let empty: Vec<i32> = vec![];
 /// assert_eq!(size(&empty), 0);
 /// ```
-pub fn size<T>(collection: &[T]) -> usize {
-    collection.len()
+pub fn size<'a, S: Source<'a>>(collection: S) -> usize {
+    source_len(collection)
 }
 
 /// Creates an array of shuffled values.
@@ -40,12 +49,37 @@ pub fn size<T>(collection: &[T]) -> usize {
 /// assert_eq!(shuffled.len(), 5);
 /// // Note: The order will be different, but all elements will be present
 /// ```
-pub fn shuffle<T>(collection: &[T]) -> Vec<T>
+#[cfg(feature = "std")]
+pub fn shuffle<'a, S>(collection: S) -> Vec<S::Item>
+where
+    S: Source<'a>,
+    S::Item: Clone,
+{
+    shuffle_with(collection, &mut thread_rng())
+}
+
+/// Creates an array of shuffled values using the given random number generator.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rust_lodash::collection::operation::shuffle_with;
+/// 
+/// let numbers = vec![1, 2, 3, 4, 5];
+/// let first = shuffle_with(&numbers, &mut StdRng::seed_from_u64(7));
+/// let second = shuffle_with(&numbers, &mut StdRng::seed_from_u64(7));
+/// assert_eq!(first, second);
+/// ```
+pub fn shuffle_with<'a, S, R>(collection: S, rng: &mut R) -> Vec<S::Item>
 where
-    T: Clone,
+    S: Source<'a>,
+    S::Item: Clone,
+    R: Rng + ?Sized,
 {
-    let mut shuffled = collection.to_vec();
-    shuffled.shuffle(&mut thread_rng());
+    let mut shuffled: Vec<S::Item> = collection.source_iter().cloned().collect();
+    shuffled.shuffle(rng);
     shuffled
 }
 
@@ -60,11 +94,33 @@ where
 /// let random = sample(&numbers);
 /// assert!(numbers.contains(random.unwrap()));
 /// ```
-pub fn sample<T>(collection: &[T]) -> Option<&T> {
-    if collection.is_empty() {
-        return None;
+#[cfg(feature = "std")]
+pub fn sample<'a, S: Source<'a>>(collection: S) -> Option<&'a S::Item> {
+    sample_with(collection, &mut thread_rng())
+}
+
+/// Gets a random element from collection using the given random number generator.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rust_lodash::collection::operation::sample_with;
+/// 
+/// let numbers = vec![1, 2, 3, 4, 5];
+/// let random = sample_with(&numbers, &mut StdRng::seed_from_u64(7));
+/// assert!(numbers.contains(random.unwrap()));
+/// ```
+pub fn sample_with<'a, S, R>(collection: S, rng: &mut R) -> Option<&'a S::Item>
+where
+    S: Source<'a>,
+    R: Rng + ?Sized,
+{
+    match Indexed::new(collection) {
+        Indexed::Slice(slice) => slice.choose(rng),
+        Indexed::Refs(items) => items.choose(rng).copied(),
     }
-    collection.choose(&mut thread_rng())
 }
 
 /// Gets n random elements at unique keys from collection up to the size of collection.
@@ -79,17 +135,41 @@ pub fn sample<T>(collection: &[T]) -> Option<&T> {
 /// assert_eq!(samples.len(), 3);
 /// // All samples will be unique elements from the original collection
 /// ```
-pub fn sample_size<T>(collection: &[T], n: usize) -> Vec<T>
+#[cfg(feature = "std")]
+pub fn sample_size<'a, S>(collection: S, n: usize) -> Vec<S::Item>
+where
+    S: Source<'a>,
+    S::Item: Clone,
+{
+    sample_size_with(collection, n, &mut thread_rng())
+}
+
+/// Gets n random elements at unique keys from collection using the given
+/// random number generator.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use rust_lodash::collection::operation::sample_size_with;
+/// 
+/// let numbers = vec![1, 2, 3, 4, 5];
+/// let samples = sample_size_with(&numbers, 3, &mut StdRng::seed_from_u64(7));
+/// assert_eq!(samples.len(), 3);
+/// ```
+pub fn sample_size_with<'a, S, R>(collection: S, n: usize, rng: &mut R) -> Vec<S::Item>
 where
-    T: Clone,
+    S: Source<'a>,
+    S::Item: Clone,
+    R: Rng + ?Sized,
 {
-    if collection.is_empty() || n == 0 {
+    if n == 0 {
         return Vec::new();
     }
     
-    let mut rng = thread_rng();
-    let mut samples = collection.to_vec();
-    samples.shuffle(&mut rng);
+    let mut samples: Vec<S::Item> = collection.source_iter().cloned().collect();
+    samples.shuffle(rng);
     samples.truncate(n);
     samples
 }
@@ -122,6 +202,7 @@ impl<T> Collection<T> {
     /// let shuffled = collection.shuffle();
     /// assert_eq!(shuffled.len(), 5);
     /// ```
+    #[cfg(feature = "std")]
     #[must_use]
     pub fn shuffle(&self) -> Vec<T>
     where
@@ -130,6 +211,14 @@ impl<T> Collection<T> {
         shuffle(&self.data)
     }
 
+    /// Creates an array of shuffled values using the given random number generator.
+    pub fn shuffle_with<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<T>
+    where
+        T: Clone,
+    {
+        shuffle_with(&self.data, rng)
+    }
+
     /// Gets a random element from the collection.
     /// 
     /// # Examples
@@ -141,11 +230,17 @@ impl<T> Collection<T> {
     /// let random = collection.sample();
     /// assert!(collection.data().contains(random.unwrap()));
     /// ```
+    #[cfg(feature = "std")]
     #[must_use]
     pub fn sample(&self) -> Option<&T> {
         sample(&self.data)
     }
 
+    /// Gets a random element from the collection using the given random number generator.
+    pub fn sample_with<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<&T> {
+        sample_with(&self.data, rng)
+    }
+
     /// Gets n random elements at unique keys from the collection.
     /// 
     /// # Examples
@@ -157,6 +252,7 @@ impl<T> Collection<T> {
     /// let samples = collection.sample_size(3);
     /// assert_eq!(samples.len(), 3);
     /// ```
+    #[cfg(feature = "std")]
     #[must_use]
     pub fn sample_size(&self, n: usize) -> Vec<T>
     where
@@ -164,11 +260,21 @@ impl<T> Collection<T> {
     {
         sample_size(&self.data, n)
     }
+
+    /// Gets n random elements at unique keys from the collection using the
+    /// given random number generator.
+    pub fn sample_size_with<R: Rng + ?Sized>(&self, n: usize, rng: &mut R) -> Vec<T>
+    where
+        T: Clone,
+    {
+        sample_size_with(&self.data, n, rng)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::vec;
 
     #[test]
     fn test_size() {
@@ -180,6 +286,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "std")]
     fn test_shuffle() {
         let numbers = vec![1, 2, 3, 4, 5];
         let shuffled = shuffle(&numbers);
@@ -192,6 +299,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "std")]
     fn test_sample() {
         let numbers = vec![1, 2, 3, 4, 5];
         let random = sample(&numbers);
@@ -203,6 +311,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "std")]
     fn test_sample_size() {
         let numbers = vec![1, 2, 3, 4, 5];
         let samples = sample_size(&numbers, 3);
@@ -227,6 +336,28 @@ mod tests {
         assert!(samples_empty.is_empty());
     }
 
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_with_supplied_rng() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let numbers = vec![1, 2, 3, 4, 5];
+        assert_eq!(
+            shuffle_with(&numbers, &mut StdRng::seed_from_u64(3)),
+            shuffle_with(&numbers, &mut StdRng::seed_from_u64(3))
+        );
+        assert_eq!(
+            sample_with(&numbers, &mut StdRng::seed_from_u64(3)),
+            sample_with(&numbers, &mut StdRng::seed_from_u64(3))
+        );
+
+        let collection = Collection::new(numbers.clone());
+        let samples = collection.sample_size_with(2, &mut StdRng::seed_from_u64(3));
+        assert_eq!(samples, sample_size_with(&numbers, 2, &mut StdRng::seed_from_u64(3)));
+        assert_eq!(samples.len(), 2);
+    }
+
     #[test]
     fn test_collection_size() {
         let collection = Collection::new(vec![1, 2, 3, 4, 5]);
@@ -234,6 +365,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "std")]
     fn test_collection_shuffle() {
         let collection = Collection::new(vec![1, 2, 3, 4, 5]);
         let shuffled = collection.shuffle();
@@ -246,6 +378,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "std")]
     fn test_collection_sample() {
         let collection = Collection::new(vec![1, 2, 3, 4, 5]);
         let random = collection.sample();
@@ -257,6 +390,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "std")]
     fn test_collection_sample_size() {
         let collection = Collection::new(vec![1, 2, 3, 4, 5]);
         let samples = collection.sample_size(3);
@@ -269,6 +403,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "std")]
     fn test_empty_collection_operations() {
         let empty: Vec<i32> = vec![];
         assert_eq!(size(&empty), 0);
@@ -0,0 +1,249 @@
+/*!
+Insertion-ordered map for Lodash-RS.
+
+This module provides `OrderedMap`, the map returned by the `*_ordered`
+variants of `group_by`, `count_by` and `key_by`. Like a JavaScript object it
+iterates in the order keys were first inserted, which keeps output stable
+between runs.
+*/
+
+use crate::utils::hash::HashMap;
+use alloc::vec::Vec;
+use core::fmt;
+use core::hash::Hash;
+
+/// A map that iterates in the order keys were first inserted.
+///
+/// Inserting an existing key replaces its value but keeps its position.
+/// With the `serialize` feature it serializes as a map in insertion order.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::ordered_map::OrderedMap;
+///
+/// let mut map = OrderedMap::new();
+/// map.insert("b", 1);
+/// map.insert("a", 2);
+/// map.insert("b", 3);
+/// assert_eq!(map.get(&"b"), Some(&3));
+/// assert_eq!(map.keys().collect::<Vec<_>>(), vec![&"b", &"a"]);
+/// assert_eq!(format!("{map:?}"), r#"{"b": 3, "a": 2}"#);
+/// ```
+#[derive(Clone)]
+pub struct OrderedMap<K, V> {
+    entries: Vec<(K, V)>,
+    index: HashMap<K, usize>,
+}
+
+impl<K, V> OrderedMap<K, V> {
+    /// Create an empty map.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            index: HashMap::new(),
+        }
+    }
+
+    /// The number of entries.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the map has no entries.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Iterate over the entries in insertion order.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.entries.iter().map(|(key, value)| (key, value))
+    }
+
+    /// Iterate over the keys in insertion order.
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.entries.iter().map(|(key, _)| key)
+    }
+
+    /// Iterate over the values in insertion order.
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.entries.iter().map(|(_, value)| value)
+    }
+
+    /// The entries as a slice, in insertion order.
+    #[must_use]
+    pub fn as_slice(&self) -> &[(K, V)] {
+        &self.entries
+    }
+}
+
+impl<K, V> OrderedMap<K, V>
+where
+    K: Hash + Eq + Clone,
+{
+    /// Insert `value` under `key`, returning the previous value. An existing
+    /// key keeps its position.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if let Some(&position) = self.index.get(&key) {
+            return Some(core::mem::replace(&mut self.entries[position].1, value));
+        }
+        self.index.insert(key.clone(), self.entries.len());
+        self.entries.push((key, value));
+        None
+    }
+
+    /// Get the value stored under `key`.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.index
+            .get(key)
+            .map(|&position| &self.entries[position].1)
+    }
+
+    /// Get a mutable reference to the value stored under `key`.
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        self.index
+            .get(key)
+            .map(|&position| &mut self.entries[position].1)
+    }
+
+    /// Whether the map contains `key`.
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.index.contains_key(key)
+    }
+}
+
+impl<K, V> Default for OrderedMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Entries determine the index, so equality compares entries in order.
+impl<K: PartialEq, V: PartialEq> PartialEq for OrderedMap<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.entries == other.entries
+    }
+}
+
+impl<K: Eq, V: Eq> Eq for OrderedMap<K, V> {}
+
+impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for OrderedMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K, V> Extend<(K, V)> for OrderedMap<K, V>
+where
+    K: Hash + Eq + Clone,
+{
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, entries: I) {
+        for (key, value) in entries {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for OrderedMap<K, V>
+where
+    K: Hash + Eq + Clone,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(entries: I) -> Self {
+        let mut map = Self::new();
+        map.extend(entries);
+        map
+    }
+}
+
+impl<K, V> IntoIterator for OrderedMap<K, V> {
+    type Item = (K, V);
+    type IntoIter = alloc::vec::IntoIter<(K, V)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a OrderedMap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = core::iter::Map<core::slice::Iter<'a, (K, V)>, fn(&'a (K, V)) -> (&'a K, &'a V)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.iter().map(|(key, value)| (key, value))
+    }
+}
+
+// Serializes as a map in insertion order, and deserializing keeps the order
+// of the input.
+#[cfg(feature = "serialize")]
+impl<K, V> serde::Serialize for OrderedMap<K, V>
+where
+    K: serde::Serialize,
+    V: serde::Serialize,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.iter())
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<'de, K, V> serde::Deserialize<'de> for OrderedMap<K, V>
+where
+    K: serde::Deserialize<'de> + Hash + Eq + Clone,
+    V: serde::Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct OrderedMapVisitor<K, V>(core::marker::PhantomData<fn() -> (K, V)>);
+
+        impl<'de, K, V> serde::de::Visitor<'de> for OrderedMapVisitor<K, V>
+        where
+            K: serde::Deserialize<'de> + Hash + Eq + Clone,
+            V: serde::Deserialize<'de>,
+        {
+            type Value = OrderedMap<K, V>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a map")
+            }
+
+            fn visit_map<A: serde::de::MapAccess<'de>>(
+                self,
+                mut access: A,
+            ) -> Result<Self::Value, A::Error> {
+                let mut map = OrderedMap::new();
+                while let Some((key, value)) = access.next_entry()? {
+                    map.insert(key, value);
+                }
+                Ok(map)
+            }
+        }
+
+        deserializer.deserialize_map(OrderedMapVisitor(core::marker::PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_insert_keeps_first_position() {
+        let map: OrderedMap<_, _> = vec![("x", 1), ("y", 2), ("x", 3)].into_iter().collect();
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.as_slice(), &[("x", 3), ("y", 2)]);
+        assert!(map.contains_key(&"y"));
+        assert_eq!(map.get(&"z"), None);
+    }
+
+    #[test]
+    fn test_equality_is_order_sensitive() {
+        let a: OrderedMap<_, _> = vec![(1, 'a'), (2, 'b')].into_iter().collect();
+        let b: OrderedMap<_, _> = vec![(2, 'b'), (1, 'a')].into_iter().collect();
+        assert_ne!(a, b);
+        assert_eq!(a.clone(), a);
+    }
+}
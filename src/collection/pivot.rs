@@ -0,0 +1,618 @@
+/*!
+Pivot methods for Lodash-RS.
+
+This module provides `pivot` and `crosstab`, which summarize a collection
+into a dense [`Table`] keyed by two iteratees, and `melt`, which turns wide
+records back into long `(id, column, value)` rows. Row and column keys keep
+the order in which they were first seen; use [`Table::sorted`] for a sorted
+layout.
+*/
+
+use crate::collection::grouped::{Aggregator, Aggs};
+use crate::collection::source::Source;
+use crate::collection::Collection;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+
+/// A dense two-dimensional table with row keys, column keys and one cell per
+/// row and column.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::pivot::crosstab;
+///
+/// let pets = vec![("ann", "cat"), ("bob", "dog"), ("ann", "dog"), ("ann", "cat")];
+/// let table = crosstab(&pets, |p| p.0, |p| p.1);
+/// assert_eq!(table.rows(), &["ann", "bob"]);
+/// assert_eq!(table.columns(), &["cat", "dog"]);
+/// assert_eq!(table.get(&"bob", &"cat"), Some(&0));
+/// assert_eq!(table.to_csv(), ",cat,dog\nann,2,1\nbob,0,1\n");
+/// ```
+///
+/// With the `serialize` feature a table serializes as
+/// `{"rows": [..], "columns": [..], "cells": [[..], ..]}`; deserializing
+/// rejects cells that do not match the row and column keys.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "TableParts<R, C, V>")
+)]
+pub struct Table<R, C, V> {
+    rows: Vec<R>,
+    columns: Vec<C>,
+    cells: Vec<Vec<V>>,
+}
+
+/// The unchecked fields of a deserialized [`Table`].
+#[cfg(feature = "serialize")]
+#[derive(serde::Deserialize)]
+struct TableParts<R, C, V> {
+    rows: Vec<R>,
+    columns: Vec<C>,
+    cells: Vec<Vec<V>>,
+}
+
+#[cfg(feature = "serialize")]
+impl<R, C, V> TryFrom<TableParts<R, C, V>> for Table<R, C, V> {
+    type Error = crate::utils::LodashError;
+
+    fn try_from(parts: TableParts<R, C, V>) -> Result<Self, Self::Error> {
+        let TableParts {
+            rows,
+            columns,
+            cells,
+        } = parts;
+        if cells.len() != rows.len() || cells.iter().any(|row| row.len() != columns.len()) {
+            return Err(crate::utils::LodashError::invalid_input(format!(
+                "table cells must be {} rows of {} columns",
+                rows.len(),
+                columns.len()
+            )));
+        }
+        Ok(Self {
+            rows,
+            columns,
+            cells,
+        })
+    }
+}
+
+impl<R, C, V> Table<R, C, V> {
+    /// The row keys.
+    #[must_use]
+    pub fn rows(&self) -> &[R] {
+        &self.rows
+    }
+
+    /// The column keys.
+    #[must_use]
+    pub fn columns(&self) -> &[C] {
+        &self.columns
+    }
+
+    /// The cells, one `Vec` per row, in column order.
+    #[must_use]
+    pub fn cells(&self) -> &[Vec<V>] {
+        &self.cells
+    }
+
+    /// Get the cell at `row` and `column`.
+    pub fn get(&self, row: &R, column: &C) -> Option<&V>
+    where
+        R: PartialEq,
+        C: PartialEq,
+    {
+        let row = self.rows.iter().position(|key| key == row)?;
+        let column = self.columns.iter().position(|key| key == column)?;
+        Some(&self.cells[row][column])
+    }
+
+    /// Get the cells of `row`, in column order.
+    pub fn row(&self, row: &R) -> Option<&[V]>
+    where
+        R: PartialEq,
+    {
+        let row = self.rows.iter().position(|key| key == row)?;
+        Some(&self.cells[row])
+    }
+
+    /// Transform every cell with `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::collection::pivot::pivot;
+    ///
+    /// let scores = vec![("ann", "math", 90), ("bob", "art", 70)];
+    /// let table = pivot(&scores, |s| s.0, |s| s.1, |a| a.first())
+    ///     .map_cells(|cell| cell.map_or(String::from("-"), |s| s.2.to_string()));
+    /// assert_eq!(table.to_csv(), ",math,art\nann,90,-\nbob,-,70\n");
+    /// ```
+    pub fn map_cells<U, F>(self, f: F) -> Table<R, C, U>
+    where
+        F: Fn(V) -> U,
+    {
+        Table {
+            rows: self.rows,
+            columns: self.columns,
+            cells: self
+                .cells
+                .into_iter()
+                .map(|row| row.into_iter().map(&f).collect())
+                .collect(),
+        }
+    }
+
+    /// Swap rows and columns.
+    #[must_use]
+    pub fn transpose(self) -> Table<C, R, V> {
+        let mut cells: Vec<Vec<V>> = self.columns.iter().map(|_| Vec::new()).collect();
+        for row in self.cells {
+            for (column, cell) in cells.iter_mut().zip(row) {
+                column.push(cell);
+            }
+        }
+        Table {
+            rows: self.columns,
+            columns: self.rows,
+            cells,
+        }
+    }
+
+    /// Sort the rows and columns by key.
+    #[must_use]
+    pub fn sorted(self) -> Self
+    where
+        R: Ord,
+        C: Ord,
+    {
+        let mut column_order: Vec<usize> = (0..self.columns.len()).collect();
+        column_order.sort_by(|&a, &b| self.columns[a].cmp(&self.columns[b]));
+        let mut rows: Vec<(R, Vec<V>)> = self.rows.into_iter().zip(self.cells).collect();
+        rows.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let (rows, cells) = rows
+            .into_iter()
+            .map(|(key, row)| {
+                let mut row: Vec<Option<V>> = row.into_iter().map(Some).collect();
+                let row = column_order
+                    .iter()
+                    .filter_map(|&column| row[column].take())
+                    .collect();
+                (key, row)
+            })
+            .unzip();
+        let mut columns: Vec<Option<C>> = self.columns.into_iter().map(Some).collect();
+        let columns = column_order
+            .iter()
+            .filter_map(|&column| columns[column].take())
+            .collect();
+        Self {
+            rows,
+            columns,
+            cells,
+        }
+    }
+
+    /// Convert the table back into long form: one `(row, column, cell)`
+    /// tuple per cell, row by row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::collection::pivot::crosstab;
+    ///
+    /// let table = crosstab(&[(1, 'a'), (2, 'b')], |x| x.0, |x| x.1);
+    /// assert_eq!(
+    ///     table.unpivot(),
+    ///     vec![(1, 'a', 1), (1, 'b', 0), (2, 'a', 0), (2, 'b', 1)]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn unpivot(self) -> Vec<(R, C, V)>
+    where
+        R: Clone,
+        C: Clone,
+    {
+        let columns = self.columns;
+        self.rows
+            .into_iter()
+            .zip(self.cells)
+            .flat_map(|(row, cells)| {
+                columns
+                    .iter()
+                    .zip(cells)
+                    .map(move |(column, cell)| (row.clone(), column.clone(), cell))
+            })
+            .collect()
+    }
+
+    /// Render the table as CSV. The header row starts with an empty field
+    /// followed by the column keys; each following line starts with the row
+    /// key. Fields are quoted when they contain commas, quotes or newlines.
+    #[must_use]
+    pub fn to_csv(&self) -> String
+    where
+        R: Display,
+        C: Display,
+        V: Display,
+    {
+        let mut csv = String::new();
+        for column in &self.columns {
+            csv.push(',');
+            push_csv_field(&mut csv, column);
+        }
+        csv.push('\n');
+        for (row, cells) in self.rows.iter().zip(&self.cells) {
+            push_csv_field(&mut csv, row);
+            for cell in cells {
+                csv.push(',');
+                push_csv_field(&mut csv, cell);
+            }
+            csv.push('\n');
+        }
+        csv
+    }
+
+    /// Render the table as a JSON object with `rows`, `columns` and `cells`
+    /// arrays.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `LodashError::Custom` wrapping the `serde_json::Error` if a
+    /// key or cell fails to serialize.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::collection::pivot::crosstab;
+    /// use serde_json::json;
+    ///
+    /// let table = crosstab(&[("x", 1), ("y", 2)], |p| p.0, |p| p.1);
+    /// assert_eq!(
+    ///     table.to_json().unwrap(),
+    ///     json!({"rows": ["x", "y"], "columns": [1, 2], "cells": [[1, 0], [0, 1]]})
+    /// );
+    /// ```
+    #[cfg(feature = "serialize")]
+    pub fn to_json(&self) -> crate::utils::Result<serde_json::Value>
+    where
+        R: serde::Serialize,
+        C: serde::Serialize,
+        V: serde::Serialize,
+    {
+        serde_json::to_value(self).map_err(crate::utils::LodashError::from_error)
+    }
+}
+
+fn push_csv_field(csv: &mut String, value: &impl Display) {
+    let field = value.to_string();
+    if field.contains([',', '"', '\n', '\r']) {
+        csv.push('"');
+        csv.push_str(&field.replace('"', "\"\""));
+        csv.push('"');
+    } else {
+        csv.push_str(&field);
+    }
+}
+
+/// Summarize `collection` into a table with one row per `row_key`, one
+/// column per `col_key` and a cell aggregated by the aggregator that `agg`
+/// builds (see [`GroupedCollection::agg`]). The collection is read in a
+/// single pass. Cells with no elements hold the aggregate of an empty group,
+/// such as `0` for counts and sums or `None` for `first`.
+///
+/// [`GroupedCollection::agg`]: crate::collection::grouped::GroupedCollection::agg
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::pivot::pivot;
+///
+/// let sales = vec![
+///     ("north", "jan", 10.0),
+///     ("south", "jan", 4.0),
+///     ("north", "feb", 6.0),
+///     ("north", "jan", 2.0),
+/// ];
+/// let table = pivot(&sales, |s| s.0, |s| s.1, |a| a.sum_by(|s| s.2));
+/// assert_eq!(table.columns(), &["jan", "feb"]);
+/// assert_eq!(table.cells(), &[vec![12.0, 6.0], vec![4.0, 0.0]]);
+/// ```
+pub fn pivot<'a, T, S, R, C, A, FR, FC, B>(
+    collection: S,
+    row_key: FR,
+    col_key: FC,
+    agg: B,
+) -> Table<R, C, A::Output>
+where
+    T: 'a,
+    S: Source<'a, Item = T>,
+    R: Hash + Eq + Clone,
+    C: Hash + Eq + Clone,
+    A: Aggregator<'a, T>,
+    FR: Fn(&T) -> R,
+    FC: Fn(&T) -> C,
+    B: FnOnce(Aggs<T>) -> A,
+{
+    let aggregator = agg(Aggs::new());
+    let mut rows: HashMap<R, usize> = HashMap::new();
+    let mut columns: HashMap<C, usize> = HashMap::new();
+    let (mut row_keys, mut column_keys) = (Vec::new(), Vec::new());
+    let mut states: HashMap<(usize, usize), A::State> = HashMap::new();
+
+    for item in collection.source_iter() {
+        let row = *rows.entry(row_key(item)).or_insert_with_key(|key| {
+            row_keys.push(key.clone());
+            row_keys.len() - 1
+        });
+        let column = *columns.entry(col_key(item)).or_insert_with_key(|key| {
+            column_keys.push(key.clone());
+            column_keys.len() - 1
+        });
+        let state = states
+            .entry((row, column))
+            .or_insert_with(|| aggregator.init());
+        aggregator.update(state, item);
+    }
+
+    let cells = (0..row_keys.len())
+        .map(|row| {
+            (0..column_keys.len())
+                .map(|column| {
+                    let state = states
+                        .remove(&(row, column))
+                        .unwrap_or_else(|| aggregator.init());
+                    aggregator.finish(state)
+                })
+                .collect()
+        })
+        .collect();
+    Table {
+        rows: row_keys,
+        columns: column_keys,
+        cells,
+    }
+}
+
+/// Count the elements of `collection` for every pair of `row_key` and
+/// `col_key`: a two-key `count_by`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::pivot::crosstab;
+///
+/// let numbers = vec![1, 2, 3, 4, 5, 6];
+/// let table = crosstab(&numbers, |x| x % 2 == 0, |x| *x > 3);
+/// assert_eq!(table.get(&true, &true), Some(&2));
+/// assert_eq!(table.get(&false, &false), Some(&2));
+/// ```
+pub fn crosstab<'a, T, S, R, C, FR, FC>(
+    collection: S,
+    row_key: FR,
+    col_key: FC,
+) -> Table<R, C, usize>
+where
+    T: 'a,
+    S: Source<'a, Item = T>,
+    R: Hash + Eq + Clone,
+    C: Hash + Eq + Clone,
+    FR: Fn(&T) -> R,
+    FC: Fn(&T) -> C,
+{
+    pivot(collection, row_key, col_key, |a| a.count())
+}
+
+/// Turn wide records into long form: one `(id, column, value)` tuple for
+/// every `(column, value)` pair that `values` produces for each element.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::pivot::melt;
+///
+/// let grades = vec![("ann", 90, 80), ("bob", 70, 60)];
+/// let long = melt(&grades, |g| g.0, |g| [("q1", g.1), ("q2", g.2)]);
+/// assert_eq!(
+///     long,
+///     vec![("ann", "q1", 90), ("ann", "q2", 80), ("bob", "q1", 70), ("bob", "q2", 60)]
+/// );
+/// ```
+pub fn melt<'a, T, S, I, C, V, FI, FV, P>(collection: S, id: FI, values: FV) -> Vec<(I, C, V)>
+where
+    T: 'a,
+    S: Source<'a, Item = T>,
+    I: Clone,
+    FI: Fn(&T) -> I,
+    FV: Fn(&T) -> P,
+    P: IntoIterator<Item = (C, V)>,
+{
+    let mut rows = Vec::new();
+    for item in collection.source_iter() {
+        let key = id(item);
+        rows.extend(
+            values(item)
+                .into_iter()
+                .map(|(column, value)| (key.clone(), column, value)),
+        );
+    }
+    rows
+}
+
+/// Collection methods that work on the `Collection` type.
+impl<T> Collection<T> {
+    /// Summarize the collection into a table. See [`pivot`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::collection::Collection;
+    ///
+    /// let collection = Collection::new(vec![("a", 1, 5), ("b", 2, 3), ("a", 2, 1)]);
+    /// let table = collection.pivot(|x| x.0, |x| x.1, |a| a.mean_by(|x| x.2));
+    /// assert_eq!(table.row(&"a"), Some(&[5.0, 1.0][..]));
+    /// ```
+    pub fn pivot<'a, R, C, A, FR, FC, B>(
+        &'a self,
+        row_key: FR,
+        col_key: FC,
+        agg: B,
+    ) -> Table<R, C, A::Output>
+    where
+        R: Hash + Eq + Clone,
+        C: Hash + Eq + Clone,
+        A: Aggregator<'a, T>,
+        FR: Fn(&T) -> R,
+        FC: Fn(&T) -> C,
+        B: FnOnce(Aggs<T>) -> A,
+    {
+        pivot(self.data(), row_key, col_key, agg)
+    }
+
+    /// Count the elements for every pair of keys. See [`crosstab`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::collection::Collection;
+    ///
+    /// let collection = Collection::new(vec!["ab", "ba", "aa"]);
+    /// let table = collection.crosstab(|s| s.starts_with('a'), |s| s.ends_with('a'));
+    /// assert_eq!(table.get(&true, &true), Some(&1));
+    /// ```
+    pub fn crosstab<R, C, FR, FC>(&self, row_key: FR, col_key: FC) -> Table<R, C, usize>
+    where
+        R: Hash + Eq + Clone,
+        C: Hash + Eq + Clone,
+        FR: Fn(&T) -> R,
+        FC: Fn(&T) -> C,
+    {
+        crosstab(self.data(), row_key, col_key)
+    }
+
+    /// Turn wide records into long form. See [`melt`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::collection::Collection;
+    ///
+    /// let collection = Collection::new(vec![(1, 'x', 'y')]);
+    /// let long = collection.melt(|r| r.0, |r| [("first", r.1), ("second", r.2)]);
+    /// assert_eq!(long, vec![(1, "first", 'x'), (1, "second", 'y')]);
+    /// ```
+    pub fn melt<I, C, V, FI, FV, P>(&self, id: FI, values: FV) -> Vec<(I, C, V)>
+    where
+        I: Clone,
+        FI: Fn(&T) -> I,
+        FV: Fn(&T) -> P,
+        P: IntoIterator<Item = (C, V)>,
+    {
+        melt(self.data(), id, values)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+
+    fn sales() -> Vec<(&'static str, u32, f64)> {
+        vec![
+            ("west", 2, 4.0),
+            ("east", 1, 1.5),
+            ("west", 1, 2.0),
+            ("east", 1, 3.5),
+            ("north", 3, 1.0),
+        ]
+    }
+
+    #[test]
+    fn test_pivot_first_seen_and_sorted_layout() {
+        let sales = sales();
+        let table = pivot(&sales, |s| s.0, |s| s.1, |a| (a.count(), a.sum_by(|s| s.2)));
+        assert_eq!(table.rows(), &["west", "east", "north"]);
+        assert_eq!(table.columns(), &[2, 1, 3]);
+        assert_eq!(table.get(&"east", &1), Some(&(2, 5.0)));
+        assert_eq!(table.get(&"north", &2), Some(&(0, 0.0)));
+        assert_eq!(table.get(&"south", &2), None);
+
+        let sorted = table.sorted();
+        assert_eq!(sorted.rows(), &["east", "north", "west"]);
+        assert_eq!(sorted.columns(), &[1, 2, 3]);
+        assert_eq!(sorted.row(&"west").unwrap()[..2], [(1, 2.0), (1, 4.0)]);
+    }
+
+    #[test]
+    fn test_transpose_and_unpivot_roundtrip() {
+        let sales = sales();
+        let table = crosstab(&sales, |s| s.0, |s| s.1);
+        let transposed = table.clone().transpose();
+        assert_eq!(transposed.rows(), table.columns());
+        assert_eq!(transposed.get(&3, &"north"), Some(&1));
+
+        let long = table.clone().unpivot();
+        assert_eq!(long.len(), 9);
+        let total: usize = long.iter().map(|(_, _, count)| count).sum();
+        assert_eq!(total, sales.len());
+        let rebuilt = pivot(&long, |l| l.0, |l| l.1, |a| a.sum_by(|l| l.2));
+        assert_eq!(rebuilt, table);
+    }
+
+    #[test]
+    fn test_csv_quoting_and_empty_table() {
+        let rows = vec![("a,b", "say \"hi\"")];
+        let table = crosstab(&rows, |r| r.0, |r| r.1);
+        assert_eq!(table.to_csv(), ",\"say \"\"hi\"\"\"\n\"a,b\",1\n");
+
+        let empty: Vec<(i32, i32)> = Vec::new();
+        assert_eq!(crosstab(&empty, |x| x.0, |x| x.1).to_csv(), "\n");
+    }
+
+    #[test]
+    fn test_pivot_accepts_any_source() {
+        use crate::collection::source::refs;
+        use std::collections::VecDeque;
+
+        let sales = sales();
+        let queue: VecDeque<_> = sales.iter().copied().collect();
+        let table = pivot(&queue, |s| s.0, |s| s.1, |a| a.sum_by(|s| s.2));
+        assert_eq!(
+            table,
+            pivot(&sales, |s| s.0, |s| s.1, |a| a.sum_by(|s| s.2))
+        );
+        let east = crosstab(
+            refs(sales.iter().filter(|s| s.0 == "east")),
+            |s| s.0,
+            |s| s.1,
+        );
+        assert_eq!(east.cells(), &[vec![2]]);
+        assert_eq!(
+            melt(refs(queue.iter().take(1)), |s| s.0, |s| [("units", s.1)]),
+            vec![("west", "units", 2)]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serialize")]
+    fn test_to_json_keeps_serializer_error() {
+        use std::error::Error;
+
+        struct Unserializable;
+
+        impl serde::Serialize for Unserializable {
+            fn serialize<S: serde::Serializer>(&self, _: S) -> Result<S::Ok, S::Error> {
+                Err(serde::ser::Error::custom("cannot serialize"))
+            }
+        }
+
+        let table = crosstab(&[1], |_| "row", |x| *x).map_cells(|_| Unserializable);
+        let err = table.to_json().unwrap_err();
+        assert_eq!(format!("{err:#}"), "Custom error: cannot serialize");
+        assert!(err.source().unwrap().is::<serde_json::Error>());
+    }
+}
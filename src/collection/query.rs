@@ -5,7 +5,13 @@ This module provides query methods like `find`, `includes`, `every`, `some`, etc
 These methods are used to search and test elements in collections.
 */
 
+use crate::collection::grouped::grouped_by;
+use crate::collection::ordered_map::OrderedMap;
+use crate::collection::source::{Indexed, Source};
 use crate::collection::Collection;
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::hash::Hash;
 // Note: These imports are kept for future use in error handling and type constraints
 // use crate::utils::{LodashError, Result, Predicate};
 
@@ -24,11 +30,12 @@ use crate::collection::Collection;
 /// let not_found = find(&numbers, |x| *x > 10);
 /// assert_eq!(not_found, None);
 /// ```
-pub fn find<T, F>(collection: &[T], predicate: F) -> Option<&T>
+pub fn find<'a, S, F>(collection: S, predicate: F) -> Option<&'a S::Item>
 where
-    F: Fn(&T) -> bool,
+    S: Source<'a>,
+    F: Fn(&S::Item) -> bool,
 {
-    collection.iter().find(|item| predicate(item))
+    collection.source_iter().find(|item| predicate(item))
 }
 
 /// This method is like `find` except that it iterates over elements of
@@ -43,11 +50,12 @@ where
 /// let last_even = find_last(&numbers, |x| x % 2 == 0);
 /// assert_eq!(last_even, Some(&4));
 /// ```
-pub fn find_last<T, F>(collection: &[T], predicate: F) -> Option<&T>
+pub fn find_last<'a, S, F>(collection: S, predicate: F) -> Option<&'a S::Item>
 where
-    F: Fn(&T) -> bool,
+    S: Source<'a>,
+    F: Fn(&S::Item) -> bool,
 {
-    collection.iter().rev().find(|item| predicate(item))
+    Indexed::new(collection).iter_rev().find(|item| predicate(item))
 }
 
 /// Check if value is in collection.
@@ -61,11 +69,15 @@ where
 /// assert!(includes(&numbers, &3));
 /// assert!(!includes(&numbers, &6));
 /// ```
-pub fn includes<T>(collection: &[T], value: &T) -> bool
+pub fn includes<'a, S>(collection: S, value: &S::Item) -> bool
 where
-    T: PartialEq,
+    S: Source<'a>,
+    S::Item: PartialEq,
 {
-    collection.contains(value)
+    match collection.as_slice() {
+        Some(slice) => slice.contains(value),
+        None => collection.source_iter().any(|item| item == value),
+    }
 }
 
 /// Check if predicate returns truthy for all elements of collection.
@@ -81,11 +93,12 @@ where
 /// let mixed = vec![2, 4, 5, 8];
 /// assert!(!every(&mixed, |x| x % 2 == 0));
 /// ```
-pub fn every<T, F>(collection: &[T], predicate: F) -> bool
+pub fn every<'a, S, F>(collection: S, predicate: F) -> bool
 where
-    F: Fn(&T) -> bool,
+    S: Source<'a>,
+    F: Fn(&S::Item) -> bool,
 {
-    collection.iter().all(predicate)
+    collection.source_iter().all(predicate)
 }
 
 /// Check if predicate returns truthy for any element of collection.
@@ -101,11 +114,12 @@ where
 /// let odds = vec![1, 3, 5, 7];
 /// assert!(!some(&odds, |x| x % 2 == 0));
 /// ```
-pub fn some<T, F>(collection: &[T], predicate: F) -> bool
+pub fn some<'a, S, F>(collection: S, predicate: F) -> bool
 where
-    F: Fn(&T) -> bool,
+    S: Source<'a>,
+    F: Fn(&S::Item) -> bool,
 {
-    collection.iter().any(predicate)
+    collection.source_iter().any(predicate)
 }
 
 /// Create an object composed of keys generated from the results of running
@@ -123,13 +137,82 @@ where
 /// assert_eq!(counts.get(&6), Some(&2));
 /// assert_eq!(counts.get(&4), Some(&1));
 /// ```
-pub fn count_by<T, K, F>(collection: &[T], iteratee: F) -> std::collections::HashMap<K, usize>
+pub fn count_by<'a, S, K, F>(collection: S, iteratee: F) -> crate::utils::hash::HashMap<K, usize>
 where
-    K: std::hash::Hash + Eq,
-    F: Fn(&T) -> K,
+    S: Source<'a>,
+    K: core::hash::Hash + Eq,
+    F: Fn(&S::Item) -> K,
 {
-    let mut counts = std::collections::HashMap::new();
-    for item in collection {
+    let mut counts = crate::utils::hash::HashMap::new();
+    for item in collection.source_iter() {
+        let key = iteratee(item);
+        *counts.entry(key).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// Like `count_by`, but collect the counts into any `FromIterator`
+/// container. Counts are produced in the order their keys were first seen.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::query::count_by_into;
+///
+/// let words = vec!["one", "two", "three"];
+/// let counts: Vec<(usize, usize)> = count_by_into(&words, |w| w.len());
+/// assert_eq!(counts, vec![(3, 2), (5, 1)]);
+/// ```
+pub fn count_by_into<'a, C, S, K, F>(collection: S, iteratee: F) -> C
+where
+    C: FromIterator<(K, usize)>,
+    S: Source<'a> + Clone,
+    K: Hash + Eq,
+    F: Fn(&S::Item) -> K,
+{
+    grouped_by(collection, iteratee).count().into_iter().collect()
+}
+
+/// Like `count_by`, but return an `OrderedMap` that iterates in the order
+/// keys were first seen, as Lodash does.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::query::count_by_ordered;
+///
+/// let numbers = vec![6.1, 4.2, 6.3];
+/// let counts = count_by_ordered(&numbers, |x: &f64| x.floor() as i32);
+/// assert_eq!(counts.as_slice(), &[(6, 2), (4, 1)]);
+/// ```
+pub fn count_by_ordered<'a, S, K, F>(collection: S, iteratee: F) -> OrderedMap<K, usize>
+where
+    S: Source<'a> + Clone,
+    K: Hash + Eq + Clone,
+    F: Fn(&S::Item) -> K,
+{
+    count_by_into(collection, iteratee)
+}
+
+/// Like `count_by`, but return a `BTreeMap` sorted by key.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::query::count_by_sorted;
+///
+/// let numbers = vec![6.1, 4.2, 6.3];
+/// let counts = count_by_sorted(&numbers, |x: &f64| x.floor() as i32);
+/// assert_eq!(counts.into_iter().collect::<Vec<_>>(), vec![(4, 1), (6, 2)]);
+/// ```
+pub fn count_by_sorted<'a, S, K, F>(collection: S, iteratee: F) -> BTreeMap<K, usize>
+where
+    S: Source<'a>,
+    K: Ord,
+    F: Fn(&S::Item) -> K,
+{
+    let mut counts = BTreeMap::new();
+    for item in collection.source_iter() {
         let key = iteratee(item);
         *counts.entry(key).or_insert(0) += 1;
     }
@@ -150,15 +233,16 @@ where
 /// assert_eq!(evens, vec![2, 4]);
 /// assert_eq!(odds, vec![1, 3, 5]);
 /// ```
-pub fn partition<T, F>(collection: &[T], predicate: F) -> (Vec<T>, Vec<T>)
+pub fn partition<'a, S, F>(collection: S, predicate: F) -> (Vec<S::Item>, Vec<S::Item>)
 where
-    T: Clone,
-    F: Fn(&T) -> bool,
+    S: Source<'a>,
+    S::Item: Clone,
+    F: Fn(&S::Item) -> bool,
 {
     let mut truthy = Vec::new();
     let mut falsy = Vec::new();
     
-    for item in collection {
+    for item in collection.source_iter() {
         if predicate(item) {
             truthy.push(item.clone());
         } else {
@@ -273,14 +357,73 @@ impl<T> Collection<T> {
     /// let counts = collection.count_by(|x| (*x as f64).floor() as i32);
     /// assert_eq!(counts.get(&6), Some(&2));
     /// ```
-    pub fn count_by<K, F>(&self, iteratee: F) -> std::collections::HashMap<K, usize>
+    pub fn count_by<K, F>(&self, iteratee: F) -> crate::utils::hash::HashMap<K, usize>
     where
-        K: std::hash::Hash + Eq,
+        K: core::hash::Hash + Eq,
         F: Fn(&T) -> K,
     {
         count_by(&self.data, iteratee)
     }
 
+    /// Like `count_by`, but collect the counts into any `FromIterator`
+    /// container, in the order their keys were first seen.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::collection::Collection;
+    ///
+    /// let collection = Collection::new(vec![1, 2, 3]);
+    /// let counts: Vec<(bool, usize)> = collection.count_by_into(|x| x % 2 == 1);
+    /// assert_eq!(counts, vec![(true, 2), (false, 1)]);
+    /// ```
+    pub fn count_by_into<C, K, F>(&self, iteratee: F) -> C
+    where
+        C: FromIterator<(K, usize)>,
+        K: Hash + Eq,
+        F: Fn(&T) -> K,
+    {
+        count_by_into(&self.data, iteratee)
+    }
+
+    /// Like `count_by`, but return an insertion-ordered `OrderedMap`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::collection::Collection;
+    ///
+    /// let collection = Collection::new(vec!["b", "a", "b"]);
+    /// let counts = collection.count_by_ordered(|s| *s);
+    /// assert_eq!(counts.as_slice(), &[("b", 2), ("a", 1)]);
+    /// ```
+    pub fn count_by_ordered<K, F>(&self, iteratee: F) -> OrderedMap<K, usize>
+    where
+        K: Hash + Eq + Clone,
+        F: Fn(&T) -> K,
+    {
+        count_by_ordered(&self.data, iteratee)
+    }
+
+    /// Like `count_by`, but return a `BTreeMap` sorted by key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::collection::Collection;
+    ///
+    /// let collection = Collection::new(vec!["b", "a", "b"]);
+    /// let counts = collection.count_by_sorted(|s| *s);
+    /// assert_eq!(counts.into_iter().collect::<Vec<_>>(), vec![("a", 1), ("b", 2)]);
+    /// ```
+    pub fn count_by_sorted<K, F>(&self, iteratee: F) -> BTreeMap<K, usize>
+    where
+        K: Ord,
+        F: Fn(&T) -> K,
+    {
+        count_by_sorted(&self.data, iteratee)
+    }
+
     /// Create an array of elements split into two groups.
     /// 
     /// # Examples
@@ -305,7 +448,7 @@ impl<T> Collection<T> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    // use std::collections::HashMap; // For future use in advanced query operations
+    use alloc::vec;
 
     #[test]
     fn test_find() {
@@ -324,6 +467,22 @@ mod tests {
         assert_eq!(last_even, Some(&4));
     }
 
+    #[test]
+    fn test_queries_over_sets() {
+        use crate::utils::hash::HashSet;
+        use alloc::collections::BTreeSet;
+
+        let set = BTreeSet::from([5, 1, 4, 2]);
+        assert_eq!(find_last(&set, |x| x % 2 == 0), Some(&4));
+        assert!(includes(&set, &5));
+        assert!(every(set.iter(), |x| *x > 0));
+
+        let tags: HashSet<&str> = HashSet::from(["rust", "js", "go"]);
+        assert_eq!(count_by_sorted(&tags, |t| t.len()).into_iter().collect::<Vec<_>>(), vec![(2, 2), (4, 1)]);
+        let (long, short) = partition(&tags, |t| t.len() > 2);
+        assert_eq!((long.len(), short.len()), (1, 2));
+    }
+
     #[test]
     fn test_includes() {
         let numbers = vec![1, 2, 3, 4, 5];
@@ -434,4 +593,14 @@ mod tests {
         assert!(!some(&empty, |x| x % 2 == 0)); // vacuous false
         assert!(!includes(&empty, &1));
     }
+
+    #[test]
+    fn test_count_by_variants() {
+        let words = vec!["b", "a", "c", "a", "b", "a"];
+        assert_eq!(count_by_ordered(&words, |w| *w).as_slice(), &[("b", 2), ("a", 3), ("c", 1)]);
+        let sorted: Vec<_> = count_by_sorted(&words, |w| *w).into_iter().collect();
+        assert_eq!(sorted, vec![("a", 3), ("b", 2), ("c", 1)]);
+        let hashed: crate::utils::hash::HashMap<_, _> = count_by_into(&words, |w| *w);
+        assert_eq!(hashed, count_by(&words, |w| *w));
+    }
 }
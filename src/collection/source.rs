@@ -0,0 +1,333 @@
+/*!
+Collection sources for Lodash-RS.
+
+This module provides the `Source` trait accepted by the collection functions.
+A source is anything that yields references to its elements: slices, arrays,
+`Vec`, `VecDeque`, the standard sets, `Collection`, the borrowing iterators of
+the standard collections (`map.values()`, `set.iter()`, ...) and any other
+iterator of references wrapped with [`refs`]. Callers no longer need to
+collect into a `Vec` before calling `map`, `filter`, `group_by` and friends.
+
+The join, window and pivot functions accept any source too. Those that index
+elements by position use contiguous sources directly and collect references
+from the others.
+*/
+
+use crate::collection::Collection;
+use crate::utils::hash::{hash_map, hash_set, HashSet};
+use alloc::collections::{
+    binary_heap, btree_map, btree_set, linked_list, vec_deque, BTreeSet, BinaryHeap, LinkedList,
+    VecDeque,
+};
+use alloc::vec::Vec;
+
+/// Something the collection functions can iterate over by reference.
+///
+/// Iteratees always receive `&Item`, whatever the source, so existing
+/// closures work unchanged. Contiguous sources also expose their elements as a
+/// slice, which functions that need random access or reverse iteration use
+/// instead of collecting references first.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::iteration::map;
+/// use rust_lodash::collection::source::refs;
+/// use std::collections::{BTreeMap, BTreeSet, VecDeque};
+///
+/// let set: BTreeSet<i32> = [3, 1, 2].into_iter().collect();
+/// assert_eq!(map(&set, |x| x * 10), vec![10, 20, 30]);
+///
+/// let queue: VecDeque<i32> = [1, 2, 3].into_iter().collect();
+/// assert_eq!(map(&queue, |x| x + 1), vec![2, 3, 4]);
+///
+/// let prices = BTreeMap::from([("tea", 3), ("coffee", 5)]);
+/// assert_eq!(map(prices.values(), |p| p * 2), vec![10, 6]);
+///
+/// let numbers = vec![1, 2, 3, 4];
+/// assert_eq!(map(refs(numbers.iter().skip(2)), |x| x * 2), vec![6, 8]);
+/// ```
+pub trait Source<'a>: Sized {
+    /// The element type.
+    type Item: 'a;
+
+    /// The iterator over references to the elements.
+    type Iter: Iterator<Item = &'a Self::Item>;
+
+    /// Iterate over the elements in order.
+    fn source_iter(self) -> Self::Iter;
+
+    /// The elements as a slice, if they are stored contiguously.
+    fn as_slice(&self) -> Option<&'a [Self::Item]> {
+        None
+    }
+}
+
+impl<'a, T> Source<'a> for &'a [T] {
+    type Item = T;
+    type Iter = core::slice::Iter<'a, T>;
+
+    fn source_iter(self) -> Self::Iter {
+        self.iter()
+    }
+
+    fn as_slice(&self) -> Option<&'a [T]> {
+        Some(*self)
+    }
+}
+
+impl<'a, T, const N: usize> Source<'a> for &'a [T; N] {
+    type Item = T;
+    type Iter = core::slice::Iter<'a, T>;
+
+    fn source_iter(self) -> Self::Iter {
+        self.iter()
+    }
+
+    fn as_slice(&self) -> Option<&'a [T]> {
+        let array: &'a [T; N] = self;
+        Some(array)
+    }
+}
+
+impl<'a, T> Source<'a> for &'a Vec<T> {
+    type Item = T;
+    type Iter = core::slice::Iter<'a, T>;
+
+    fn source_iter(self) -> Self::Iter {
+        self.iter()
+    }
+
+    fn as_slice(&self) -> Option<&'a [T]> {
+        let vec: &'a Vec<T> = self;
+        Some(vec)
+    }
+}
+
+impl<'a, T> Source<'a> for &'a Collection<T> {
+    type Item = T;
+    type Iter = core::slice::Iter<'a, T>;
+
+    fn source_iter(self) -> Self::Iter {
+        self.data().iter()
+    }
+
+    fn as_slice(&self) -> Option<&'a [T]> {
+        Some(self.data())
+    }
+}
+
+impl<'a, T> Source<'a> for core::slice::Iter<'a, T> {
+    type Item = T;
+    type Iter = Self;
+
+    fn source_iter(self) -> Self::Iter {
+        self
+    }
+
+    fn as_slice(&self) -> Option<&'a [T]> {
+        Some(core::slice::Iter::as_slice(self))
+    }
+}
+
+// Non-contiguous containers iterate with their own borrowing iterator.
+macro_rules! impl_container_source {
+    ($([$($generics:tt)*] $container:ty => $iter:ty),* $(,)?) => {
+        $(
+            impl<'a, $($generics)*> Source<'a> for &'a $container {
+                type Item = T;
+                type Iter = $iter;
+
+                fn source_iter(self) -> Self::Iter {
+                    self.iter()
+                }
+            }
+        )*
+    };
+}
+
+impl_container_source! {
+    [T] VecDeque<T> => vec_deque::Iter<'a, T>,
+    [T] LinkedList<T> => linked_list::Iter<'a, T>,
+    [T, S] HashSet<T, S> => hash_set::Iter<'a, T>,
+    [T] BTreeSet<T> => btree_set::Iter<'a, T>,
+    [T] BinaryHeap<T> => binary_heap::Iter<'a, T>,
+}
+
+// Borrowing iterators of the standard collections are sources themselves.
+macro_rules! impl_iter_source {
+    ($([$($generics:tt)*] $iter:ty => $item:ty),* $(,)?) => {
+        $(
+            impl<'a, $($generics)*> Source<'a> for $iter {
+                type Item = $item;
+                type Iter = Self;
+
+                fn source_iter(self) -> Self::Iter {
+                    self
+                }
+            }
+        )*
+    };
+}
+
+impl_iter_source! {
+    [T] vec_deque::Iter<'a, T> => T,
+    [T] linked_list::Iter<'a, T> => T,
+    [T] hash_set::Iter<'a, T> => T,
+    [T] btree_set::Iter<'a, T> => T,
+    [T] binary_heap::Iter<'a, T> => T,
+    [K, V] hash_map::Keys<'a, K, V> => K,
+    [K, V] hash_map::Values<'a, K, V> => V,
+    [K, V] btree_map::Keys<'a, K, V> => K,
+    [K, V] btree_map::Values<'a, K, V> => V,
+}
+
+/// A [`Source`] over any iterator of references. Created with [`refs`].
+#[derive(Debug, Clone)]
+pub struct Refs<I>(I);
+
+impl<'a, T, I> Source<'a> for Refs<I>
+where
+    T: 'a,
+    I: Iterator<Item = &'a T>,
+{
+    type Item = T;
+    type Iter = I;
+
+    fn source_iter(self) -> Self::Iter {
+        self.0
+    }
+}
+
+/// Use any iterator of references, such as an adapter chain, as a
+/// [`Source`].
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::source::refs;
+/// use rust_lodash::collection::transform::sort_by;
+///
+/// let words = vec!["pear", "fig", "apple", "kiwi"];
+/// let short = sort_by(refs(words.iter().filter(|w| w.len() < 5)), |w| w.len());
+/// assert_eq!(short, vec!["fig", "pear", "kiwi"]);
+/// ```
+pub fn refs<'a, T, I>(iter: I) -> Refs<I::IntoIter>
+where
+    T: 'a,
+    I: IntoIterator<Item = &'a T>,
+{
+    Refs(iter.into_iter())
+}
+
+/// The elements of a source laid out for random access: the slice itself for
+/// contiguous sources, otherwise the collected references.
+pub(crate) enum Indexed<'a, T> {
+    Slice(&'a [T]),
+    Refs(Vec<&'a T>),
+}
+
+impl<'a, T> Indexed<'a, T> {
+    pub(crate) fn new<S>(source: S) -> Self
+    where
+        S: Source<'a, Item = T>,
+    {
+        match source.as_slice() {
+            Some(slice) => Self::Slice(slice),
+            None => Self::Refs(source.source_iter().collect()),
+        }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        match self {
+            Self::Slice(slice) => slice.len(),
+            Self::Refs(items) => items.len(),
+        }
+    }
+
+    pub(crate) fn get(&self, index: usize) -> Option<&'a T> {
+        match self {
+            Self::Slice(slice) => slice.get(index),
+            Self::Refs(items) => items.get(index).copied(),
+        }
+    }
+
+    /// Iterate from the first element to the last.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = &'a T> + '_ {
+        (0..self.len()).filter_map(|index| self.get(index))
+    }
+
+    /// Iterate from the last element to the first.
+    pub(crate) fn iter_rev(&self) -> impl Iterator<Item = &'a T> + '_ {
+        (0..self.len()).rev().filter_map(|index| self.get(index))
+    }
+}
+
+impl<T> core::ops::Index<usize> for Indexed<'_, T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        match self {
+            Self::Slice(slice) => &slice[index],
+            Self::Refs(items) => items[index],
+        }
+    }
+}
+
+/// Count the elements of a source, in constant time for slices.
+pub(crate) fn source_len<'a, S: Source<'a>>(source: S) -> usize {
+    match source.as_slice() {
+        Some(slice) => slice.len(),
+        None => source.source_iter().count(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::hash::HashMap;
+    use alloc::collections::BTreeMap;
+    use alloc::vec;
+
+    fn sum<'a, S: Source<'a, Item = i32>>(source: S) -> i32 {
+        source.source_iter().sum()
+    }
+
+    #[test]
+    fn test_sources_yield_every_element() {
+        let vec = vec![1, 2, 3];
+        let set: HashSet<i32> = vec.iter().copied().collect();
+        let map: HashMap<&str, i32> = HashMap::from([("a", 1), ("b", 2), ("c", 3)]);
+        let tree: BTreeMap<i32, &str> = BTreeMap::from([(1, "a"), (2, "b"), (3, "c")]);
+
+        assert_eq!(sum(&vec), 6);
+        assert_eq!(sum(&[1, 2, 3]), 6);
+        assert_eq!(sum(vec.as_slice()), 6);
+        assert_eq!(sum(&set), 6);
+        assert_eq!(sum(set.iter()), 6);
+        assert_eq!(sum(map.values()), 6);
+        assert_eq!(sum(tree.keys()), 6);
+        assert_eq!(sum(&VecDeque::from(vec.clone())), 6);
+        assert_eq!(sum(&Collection::new(vec.clone())), 6);
+        assert_eq!(sum(refs(vec.iter().rev())), 6);
+    }
+
+    #[test]
+    fn test_indexed_uses_slice_when_contiguous() {
+        let vec = vec![1, 2, 3];
+        assert!(matches!(Indexed::new(&vec), Indexed::Slice(_)));
+        assert!(matches!(
+            Indexed::new(vec[1..].iter()),
+            Indexed::Slice(&[2, 3])
+        ));
+
+        let queue = VecDeque::from(vec);
+        let indexed = Indexed::new(&queue);
+        assert!(matches!(indexed, Indexed::Refs(_)));
+        assert_eq!(
+            indexed.iter_rev().copied().collect::<Vec<_>>(),
+            vec![3, 2, 1]
+        );
+        assert_eq!(source_len(&queue), 3);
+    }
+}
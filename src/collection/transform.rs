@@ -1,14 +1,21 @@
 /*!
 Transform methods for Lodash-RS.
 
-This module provides transform methods like `group_by`, `key_by`, `sort_by`, etc.
+This module provides transform methods like `group_by`, `key_by`, `sort_by`, `uniq`, etc.
 These methods are used to reorganize and transform collections.
 */
 
+use crate::collection::grouped::grouped_by;
+use crate::collection::ordered_map::OrderedMap;
+use crate::collection::source::Source;
 use crate::collection::Collection;
 // Note: These imports are kept for future use in error handling and type constraints
 // use crate::utils::{LodashError, Result, ToKey, ToComparable};
-use std::collections::HashMap;
+use crate::utils::hash::{HashMap, HashSet};
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::hash::Hash;
 
 /// Create an object composed of keys generated from the results of running
 /// each element of collection through iteratee. The order of grouped values
@@ -25,14 +32,15 @@ use std::collections::HashMap;
 /// assert_eq!(grouped.get(&6), Some(&vec![6.1, 6.3]));
 /// assert_eq!(grouped.get(&4), Some(&vec![4.2]));
 /// ```
-pub fn group_by<T, K, F>(collection: &[T], iteratee: F) -> HashMap<K, Vec<T>>
+pub fn group_by<'a, S, K, F>(collection: S, iteratee: F) -> HashMap<K, Vec<S::Item>>
 where
-    K: std::hash::Hash + Eq,
-    T: Clone,
-    F: Fn(&T) -> K,
+    S: Source<'a>,
+    K: core::hash::Hash + Eq,
+    S::Item: Clone,
+    F: Fn(&S::Item) -> K,
 {
     let mut groups = HashMap::new();
-    for item in collection {
+    for item in collection.source_iter() {
         let key = iteratee(item);
         groups.entry(key).or_insert_with(Vec::new).push(item.clone());
     }
@@ -57,20 +65,220 @@ where
 /// let keyed = key_by(&users, |(name, _)| name.to_string());
 /// assert_eq!(keyed.get("john"), Some(&("john", 30)));
 /// ```
-pub fn key_by<T, K, F>(collection: &[T], iteratee: F) -> HashMap<K, T>
+pub fn key_by<'a, S, K, F>(collection: S, iteratee: F) -> HashMap<K, S::Item>
 where
-    K: std::hash::Hash + Eq,
-    T: Clone,
-    F: Fn(&T) -> K,
+    S: Source<'a>,
+    K: core::hash::Hash + Eq,
+    S::Item: Clone,
+    F: Fn(&S::Item) -> K,
 {
     let mut keyed = HashMap::new();
-    for item in collection {
+    for item in collection.source_iter() {
         let key = iteratee(item);
         keyed.insert(key, item.clone());
     }
     keyed
 }
 
+/// Like `group_by`, but collect the groups into any `FromIterator`
+/// container. Groups are produced in the order their keys were first seen.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::transform::group_by_into;
+///
+/// let numbers = vec![6.1, 4.2, 6.3];
+/// let grouped: Vec<(i32, Vec<f64>)> = group_by_into(&numbers, |x: &f64| x.floor() as i32);
+/// assert_eq!(grouped, vec![(6, vec![6.1, 6.3]), (4, vec![4.2])]);
+/// ```
+pub fn group_by_into<'a, C, S, K, F>(collection: S, iteratee: F) -> C
+where
+    C: FromIterator<(K, Vec<S::Item>)>,
+    S: Source<'a> + Clone,
+    K: Hash + Eq,
+    S::Item: Clone,
+    F: Fn(&S::Item) -> K,
+{
+    grouped_by(collection, iteratee)
+        .agg(|a| a.fold(Vec::new(), |group, item| group.push(item.clone())))
+        .into_iter()
+        .collect()
+}
+
+/// Like `group_by`, but return an `OrderedMap` that iterates in the order
+/// keys were first seen, as Lodash does.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::transform::group_by_ordered;
+///
+/// let words = vec!["one", "two", "three"];
+/// let grouped = group_by_ordered(&words, |w| w.len());
+/// assert_eq!(format!("{grouped:?}"), r#"{3: ["one", "two"], 5: ["three"]}"#);
+/// ```
+pub fn group_by_ordered<'a, S, K, F>(collection: S, iteratee: F) -> OrderedMap<K, Vec<S::Item>>
+where
+    S: Source<'a> + Clone,
+    K: Hash + Eq + Clone,
+    S::Item: Clone,
+    F: Fn(&S::Item) -> K,
+{
+    group_by_into(collection, iteratee)
+}
+
+/// Like `group_by`, but return a `BTreeMap` sorted by key.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::transform::group_by_sorted;
+///
+/// let numbers = vec![3, 1, 2, 5];
+/// let grouped = group_by_sorted(&numbers, |x| x % 2);
+/// assert_eq!(grouped.into_iter().collect::<Vec<_>>(), vec![(0, vec![2]), (1, vec![3, 1, 5])]);
+/// ```
+pub fn group_by_sorted<'a, S, K, F>(collection: S, iteratee: F) -> BTreeMap<K, Vec<S::Item>>
+where
+    S: Source<'a>,
+    K: Ord,
+    S::Item: Clone,
+    F: Fn(&S::Item) -> K,
+{
+    let mut groups = BTreeMap::new();
+    for item in collection.source_iter() {
+        let key = iteratee(item);
+        groups.entry(key).or_insert_with(Vec::new).push(item.clone());
+    }
+    groups
+}
+
+/// Like `key_by`, but collect the entries into any `FromIterator` container.
+/// Entries are produced in the order their keys were first seen, each with
+/// the last element that generated the key.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::transform::key_by_into;
+///
+/// let users = vec![("john", 30), ("jane", 25), ("john", 31)];
+/// let keyed: Vec<(&str, (&str, i32))> = key_by_into(&users, |(name, _)| *name);
+/// assert_eq!(keyed, vec![("john", ("john", 31)), ("jane", ("jane", 25))]);
+/// ```
+pub fn key_by_into<'a, C, S, K, F>(collection: S, iteratee: F) -> C
+where
+    C: FromIterator<(K, S::Item)>,
+    S: Source<'a> + Clone,
+    K: Hash + Eq,
+    S::Item: Clone,
+    F: Fn(&S::Item) -> K,
+{
+    grouped_by(collection, iteratee)
+        .last()
+        .into_iter()
+        .map(|(key, item)| (key, item.clone()))
+        .collect()
+}
+
+/// Like `key_by`, but return an `OrderedMap` that iterates in the order keys
+/// were first seen.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::transform::key_by_ordered;
+///
+/// let users = vec![("john", 30), ("jane", 25)];
+/// let keyed = key_by_ordered(&users, |(name, _)| *name);
+/// assert_eq!(keyed.keys().collect::<Vec<_>>(), vec![&"john", &"jane"]);
+/// ```
+pub fn key_by_ordered<'a, S, K, F>(collection: S, iteratee: F) -> OrderedMap<K, S::Item>
+where
+    S: Source<'a> + Clone,
+    K: Hash + Eq + Clone,
+    S::Item: Clone,
+    F: Fn(&S::Item) -> K,
+{
+    key_by_into(collection, iteratee)
+}
+
+/// Like `key_by`, but return a `BTreeMap` sorted by key.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::transform::key_by_sorted;
+///
+/// let users = vec![("john", 30), ("jane", 25)];
+/// let keyed = key_by_sorted(&users, |(name, _)| *name);
+/// assert_eq!(keyed.keys().collect::<Vec<_>>(), vec![&"jane", &"john"]);
+/// ```
+pub fn key_by_sorted<'a, S, K, F>(collection: S, iteratee: F) -> BTreeMap<K, S::Item>
+where
+    S: Source<'a>,
+    K: Ord,
+    S::Item: Clone,
+    F: Fn(&S::Item) -> K,
+{
+    let mut keyed = BTreeMap::new();
+    for item in collection.source_iter() {
+        let key = iteratee(item);
+        keyed.insert(key, item.clone());
+    }
+    keyed
+}
+
+/// Create a duplicate-free version of the collection, keeping the first
+/// occurrence of each element.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::transform::uniq;
+///
+/// assert_eq!(uniq(&[2, 1, 2, 3, 1]), vec![2, 1, 3]);
+/// ```
+pub fn uniq<'a, S>(collection: S) -> Vec<S::Item>
+where
+    S: Source<'a>,
+    S::Item: Hash + Eq + Clone,
+{
+    let mut seen = HashSet::new();
+    collection
+        .source_iter()
+        .filter(|item| seen.insert(*item))
+        .cloned()
+        .collect()
+}
+
+/// Like `uniq`, but compare elements by the key produced by `iteratee`,
+/// keeping the first element for each key.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::transform::uniq_by;
+///
+/// let numbers = vec![2.1, 1.2, 2.3];
+/// assert_eq!(uniq_by(&numbers, |x: &f64| x.floor() as i64), vec![2.1, 1.2]);
+/// ```
+pub fn uniq_by<'a, S, K, F>(collection: S, iteratee: F) -> Vec<S::Item>
+where
+    S: Source<'a>,
+    S::Item: Clone,
+    K: Hash + Eq,
+    F: Fn(&S::Item) -> K,
+{
+    let mut seen = HashSet::new();
+    collection
+        .source_iter()
+        .filter(|item| seen.insert(iteratee(item)))
+        .cloned()
+        .collect()
+}
+
 /// Invoke the method at path of each element in collection.
 /// 
 /// # Examples
@@ -82,11 +290,12 @@ where
 /// let uppercased = invoke(&strings, |s| s.to_uppercase());
 /// assert_eq!(uppercased, vec!["HELLO", "WORLD"]);
 /// ```
-pub fn invoke<T, U, F>(collection: &[T], method: F) -> Vec<U>
+pub fn invoke<'a, S, U, F>(collection: S, method: F) -> Vec<U>
 where
-    F: Fn(&T) -> U,
+    S: Source<'a>,
+    F: Fn(&S::Item) -> U,
 {
-    collection.iter().map(method).collect()
+    collection.source_iter().map(method).collect()
 }
 
 /// Create an array of elements, sorted in ascending order by the results of
@@ -107,13 +316,14 @@ where
 /// assert_eq!(sorted[1], ("john", 30));
 /// assert_eq!(sorted[2], ("bob", 35));
 /// ```
-pub fn sort_by<T, K, F>(collection: &[T], iteratee: F) -> Vec<T>
+pub fn sort_by<'a, S, K, F>(collection: S, iteratee: F) -> Vec<S::Item>
 where
-    T: Clone,
+    S: Source<'a>,
+    S::Item: Clone,
     K: Ord,
-    F: Fn(&T) -> K,
+    F: Fn(&S::Item) -> K,
 {
-    let mut sorted = collection.to_vec();
+    let mut sorted: Vec<S::Item> = collection.source_iter().cloned().collect();
     sorted.sort_by_key(iteratee);
     sorted
 }
@@ -136,13 +346,14 @@ where
 /// assert_eq!(sorted[1], ("bob", 30, "manager"));
 /// assert_eq!(sorted[2], ("jane", 25, "designer"));
 /// ```
-pub fn order_by<T, K, F>(collection: &[T], iteratee: F, ascending: bool) -> Vec<T>
+pub fn order_by<'a, S, K, F>(collection: S, iteratee: F, ascending: bool) -> Vec<S::Item>
 where
-    T: Clone,
+    S: Source<'a>,
+    S::Item: Clone,
     K: Ord,
-    F: Fn(&T) -> K,
+    F: Fn(&S::Item) -> K,
 {
-    let mut sorted = collection.to_vec();
+    let mut sorted: Vec<S::Item> = collection.source_iter().cloned().collect();
     if ascending {
         sorted.sort_by_key(iteratee);
     } else {
@@ -155,6 +366,187 @@ where
     sorted
 }
 
+/// Where [`sort_by_partial`] places elements whose key is not comparable
+/// with itself, such as NaN.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum NanPolicy {
+    /// Before every comparable key.
+    First,
+    /// After every comparable key.
+    #[default]
+    Last,
+}
+
+/// Compare two partially ordered keys, ascending or descending, placing keys
+/// that are not comparable with themselves according to `nan` either way.
+pub(crate) fn compare_partial<K: PartialOrd>(
+    a: &K,
+    b: &K,
+    nan: NanPolicy,
+    ascending: bool,
+) -> Ordering {
+    let is_nan = |key: &K| key.partial_cmp(key).is_none();
+    let nan_order = match nan {
+        NanPolicy::First => Ordering::Less,
+        NanPolicy::Last => Ordering::Greater,
+    };
+    match (is_nan(a), is_nan(b)) {
+        (false, false) if ascending => a.partial_cmp(b).unwrap_or(Ordering::Equal),
+        (false, false) => b.partial_cmp(a).unwrap_or(Ordering::Equal),
+        (true, true) => Ordering::Equal,
+        (true, false) => nan_order,
+        (false, true) => nan_order.reverse(),
+    }
+}
+
+/// Like `sort_by`, for keys that are only `PartialOrd`, such as `f64`.
+///
+/// Keys that are not comparable with themselves (NaN) go first or last as
+/// `nan` says, keeping their relative order. Any other pair of keys must be
+/// comparable.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::transform::{sort_by_partial, NanPolicy};
+///
+/// let scores = vec![("ann", 0.5), ("bob", f64::NAN), ("cy", -1.0)];
+/// let sorted = sort_by_partial(&scores, |(_, score)| *score, NanPolicy::First);
+/// let names: Vec<&str> = sorted.iter().map(|(name, _)| *name).collect();
+/// assert_eq!(names, ["bob", "cy", "ann"]);
+/// ```
+pub fn sort_by_partial<'a, S, K, F>(collection: S, iteratee: F, nan: NanPolicy) -> Vec<S::Item>
+where
+    S: Source<'a>,
+    S::Item: Clone,
+    K: PartialOrd,
+    F: Fn(&S::Item) -> K,
+{
+    let mut sorted: Vec<S::Item> = collection.source_iter().cloned().collect();
+    sorted.sort_by(|a, b| compare_partial(&iteratee(a), &iteratee(b), nan, true));
+    sorted
+}
+
+/// Like [`order_by`], for keys that are only `PartialOrd`, such as `f64`.
+///
+/// Keys that are not comparable with themselves (NaN) go first or last as
+/// `nan` says in both directions, so a descending order does not need
+/// negated keys that would move NaN to the front.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::transform::{order_by_partial, NanPolicy};
+///
+/// let scores = vec![("ann", 0.5), ("bob", f64::NAN), ("cy", 2.0)];
+/// let sorted = order_by_partial(&scores, |(_, score)| *score, false, NanPolicy::Last);
+/// let names: Vec<&str> = sorted.iter().map(|(name, _)| *name).collect();
+/// assert_eq!(names, ["cy", "ann", "bob"]);
+/// ```
+pub fn order_by_partial<'a, S, K, F>(
+    collection: S,
+    iteratee: F,
+    ascending: bool,
+    nan: NanPolicy,
+) -> Vec<S::Item>
+where
+    S: Source<'a>,
+    S::Item: Clone,
+    K: PartialOrd,
+    F: Fn(&S::Item) -> K,
+{
+    let mut sorted: Vec<S::Item> = collection.source_iter().cloned().collect();
+    sorted.sort_by(|a, b| compare_partial(&iteratee(a), &iteratee(b), nan, ascending));
+    sorted
+}
+
+/// Compare two floats in total order, ascending or descending, with every
+/// NaN last either way.
+pub(crate) fn compare_float(a: f64, b: f64, ascending: bool) -> Ordering {
+    match (a.is_nan(), b.is_nan()) {
+        (false, false) if ascending => a.total_cmp(&b),
+        (false, false) => b.total_cmp(&a),
+        (a_nan, b_nan) => a_nan.cmp(&b_nan),
+    }
+}
+
+/// Sort by a float key from negative to positive infinity, `-0.0` before
+/// `0.0`. NaN keys go last whatever their sign, in their original order, the
+/// same placement as [`order_by_float`].
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::transform::sort_by_float;
+///
+/// let prices = vec![("tea", 2.5), ("cake", f64::NAN), ("water", 0.0)];
+/// let sorted = sort_by_float(&prices, |(_, price)| *price);
+/// assert_eq!(sorted[0], ("water", 0.0));
+/// assert_eq!(sorted[1], ("tea", 2.5));
+/// assert_eq!(sorted[2].0, "cake");
+/// ```
+pub fn sort_by_float<'a, S, F>(collection: S, iteratee: F) -> Vec<S::Item>
+where
+    S: Source<'a>,
+    S::Item: Clone,
+    F: Fn(&S::Item) -> f64,
+{
+    let mut sorted: Vec<S::Item> = collection.source_iter().cloned().collect();
+    sorted.sort_by(|a, b| compare_float(iteratee(a), iteratee(b), true));
+    sorted
+}
+
+/// Like [`order_by`] for a float key: `-0.0` and `0.0` are ordered as in
+/// [`sort_by_float`], and NaN keys of either sign go last whether `ascending`
+/// or not.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::transform::order_by_float;
+///
+/// let prices = vec![("tea", 2.5), ("cake", f64::NAN), ("water", 0.0)];
+/// let sorted = order_by_float(&prices, |(_, price)| *price, false);
+/// assert_eq!(sorted[0], ("tea", 2.5));
+/// assert_eq!(sorted[1], ("water", 0.0));
+/// assert_eq!(sorted[2].0, "cake");
+/// ```
+pub fn order_by_float<'a, S, F>(collection: S, iteratee: F, ascending: bool) -> Vec<S::Item>
+where
+    S: Source<'a>,
+    S::Item: Clone,
+    F: Fn(&S::Item) -> f64,
+{
+    let mut sorted: Vec<S::Item> = collection.source_iter().cloned().collect();
+    sorted.sort_by(|a, b| compare_float(iteratee(a), iteratee(b), ascending));
+    sorted
+}
+
+/// Like `sort_by`, calling `iteratee` once per element instead of once per
+/// comparison. Prefer it when the key is expensive, such as a formatted
+/// string.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::transform::sort_by_cached_key;
+///
+/// let words = vec!["Banana", "apple", "cherry"];
+/// let sorted = sort_by_cached_key(&words, |word| word.to_lowercase());
+/// assert_eq!(sorted, vec!["apple", "Banana", "cherry"]);
+/// ```
+pub fn sort_by_cached_key<'a, S, K, F>(collection: S, iteratee: F) -> Vec<S::Item>
+where
+    S: Source<'a>,
+    S::Item: Clone,
+    K: Ord,
+    F: Fn(&S::Item) -> K,
+{
+    let mut sorted: Vec<S::Item> = collection.source_iter().cloned().collect();
+    sorted.sort_by_cached_key(iteratee);
+    sorted
+}
+
 /// Collection methods that work on the `Collection` type.
 impl<T> Collection<T> {
     /// Create an object composed of keys generated from the results of running
@@ -172,7 +564,7 @@ impl<T> Collection<T> {
     /// ```
     pub fn group_by<K, F>(&self, iteratee: F) -> HashMap<K, Vec<T>>
     where
-        K: std::hash::Hash + Eq,
+        K: core::hash::Hash + Eq,
         T: Clone,
         F: Fn(&T) -> K,
     {
@@ -198,13 +590,175 @@ impl<T> Collection<T> {
     /// ```
     pub fn key_by<K, F>(&self, iteratee: F) -> HashMap<K, T>
     where
-        K: std::hash::Hash + Eq,
+        K: core::hash::Hash + Eq,
         T: Clone,
         F: Fn(&T) -> K,
     {
         key_by(&self.data, iteratee)
     }
 
+    /// Like `group_by`, but collect the groups into any `FromIterator`
+    /// container, in the order their keys were first seen.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::collection::Collection;
+    ///
+    /// let collection = Collection::new(vec![1, 2, 3, 4]);
+    /// let grouped: Vec<(bool, Vec<i32>)> = collection.group_by_into(|x| x % 2 == 0);
+    /// assert_eq!(grouped, vec![(false, vec![1, 3]), (true, vec![2, 4])]);
+    /// ```
+    pub fn group_by_into<C, K, F>(&self, iteratee: F) -> C
+    where
+        C: FromIterator<(K, Vec<T>)>,
+        K: Hash + Eq,
+        T: Clone,
+        F: Fn(&T) -> K,
+    {
+        group_by_into(&self.data, iteratee)
+    }
+
+    /// Like `group_by`, but return an insertion-ordered `OrderedMap`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::collection::Collection;
+    ///
+    /// let collection = Collection::new(vec!["b", "a", "bb"]);
+    /// let grouped = collection.group_by_ordered(|s| s.chars().next().unwrap());
+    /// assert_eq!(grouped.keys().collect::<Vec<_>>(), vec![&'b', &'a']);
+    /// ```
+    pub fn group_by_ordered<K, F>(&self, iteratee: F) -> OrderedMap<K, Vec<T>>
+    where
+        K: Hash + Eq + Clone,
+        T: Clone,
+        F: Fn(&T) -> K,
+    {
+        group_by_ordered(&self.data, iteratee)
+    }
+
+    /// Like `group_by`, but return a `BTreeMap` sorted by key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::collection::Collection;
+    ///
+    /// let collection = Collection::new(vec![3, 1, 2]);
+    /// let grouped = collection.group_by_sorted(|x| x % 2);
+    /// assert_eq!(grouped.keys().collect::<Vec<_>>(), vec![&0, &1]);
+    /// ```
+    pub fn group_by_sorted<K, F>(&self, iteratee: F) -> BTreeMap<K, Vec<T>>
+    where
+        K: Ord,
+        T: Clone,
+        F: Fn(&T) -> K,
+    {
+        group_by_sorted(&self.data, iteratee)
+    }
+
+    /// Like `key_by`, but collect the entries into any `FromIterator`
+    /// container, in the order their keys were first seen.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::collection::Collection;
+    ///
+    /// let collection = Collection::new(vec![("a", 1), ("b", 2), ("a", 3)]);
+    /// let keyed: Vec<(&str, (&str, i32))> = collection.key_by_into(|(k, _)| *k);
+    /// assert_eq!(keyed, vec![("a", ("a", 3)), ("b", ("b", 2))]);
+    /// ```
+    pub fn key_by_into<C, K, F>(&self, iteratee: F) -> C
+    where
+        C: FromIterator<(K, T)>,
+        K: Hash + Eq,
+        T: Clone,
+        F: Fn(&T) -> K,
+    {
+        key_by_into(&self.data, iteratee)
+    }
+
+    /// Like `key_by`, but return an insertion-ordered `OrderedMap`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::collection::Collection;
+    ///
+    /// let collection = Collection::new(vec![("b", 1), ("a", 2)]);
+    /// let keyed = collection.key_by_ordered(|(k, _)| *k);
+    /// assert_eq!(keyed.as_slice(), &[("b", ("b", 1)), ("a", ("a", 2))]);
+    /// ```
+    pub fn key_by_ordered<K, F>(&self, iteratee: F) -> OrderedMap<K, T>
+    where
+        K: Hash + Eq + Clone,
+        T: Clone,
+        F: Fn(&T) -> K,
+    {
+        key_by_ordered(&self.data, iteratee)
+    }
+
+    /// Like `key_by`, but return a `BTreeMap` sorted by key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::collection::Collection;
+    ///
+    /// let collection = Collection::new(vec![("b", 1), ("a", 2)]);
+    /// let keyed = collection.key_by_sorted(|(k, _)| *k);
+    /// assert_eq!(keyed.keys().collect::<Vec<_>>(), vec![&"a", &"b"]);
+    /// ```
+    pub fn key_by_sorted<K, F>(&self, iteratee: F) -> BTreeMap<K, T>
+    where
+        K: Ord,
+        T: Clone,
+        F: Fn(&T) -> K,
+    {
+        key_by_sorted(&self.data, iteratee)
+    }
+
+    /// Create a duplicate-free version of the collection. See [`uniq`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::collection::Collection;
+    ///
+    /// let collection = Collection::new(vec!["a", "b", "a"]);
+    /// assert_eq!(collection.uniq(), vec!["a", "b"]);
+    /// ```
+    #[must_use]
+    pub fn uniq(&self) -> Vec<T>
+    where
+        T: Hash + Eq + Clone,
+    {
+        uniq(self.data())
+    }
+
+    /// Create a duplicate-free version of the collection, comparing by the
+    /// key produced by `iteratee`. See [`uniq_by`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::collection::Collection;
+    ///
+    /// let collection = Collection::new(vec!["apple", "avocado", "banana"]);
+    /// assert_eq!(collection.uniq_by(|s| s.chars().next()), vec!["apple", "banana"]);
+    /// ```
+    pub fn uniq_by<K, F>(&self, iteratee: F) -> Vec<T>
+    where
+        T: Clone,
+        K: Hash + Eq,
+        F: Fn(&T) -> K,
+    {
+        uniq_by(self.data(), iteratee)
+    }
+
     /// Invoke the method at path of each element.
     /// 
     /// # Examples
@@ -272,12 +826,114 @@ impl<T> Collection<T> {
     {
         order_by(&self.data, iteratee, ascending)
     }
+
+    /// Sort by a `PartialOrd` key, placing NaN keys as `nan` says. See
+    /// [`sort_by_partial`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::collection::Collection;
+    /// use rust_lodash::collection::transform::NanPolicy;
+    ///
+    /// let collection = Collection::new(vec![2.0, f64::NAN, 1.0]);
+    /// let sorted = collection.sort_by_partial(|x| *x, NanPolicy::Last);
+    /// assert_eq!(sorted[..2], [1.0, 2.0]);
+    /// ```
+    pub fn sort_by_partial<K, F>(&self, iteratee: F, nan: NanPolicy) -> Vec<T>
+    where
+        T: Clone,
+        K: PartialOrd,
+        F: Fn(&T) -> K,
+    {
+        sort_by_partial(&self.data, iteratee, nan)
+    }
+
+    /// Sort by a float key, NaN keys last. See [`sort_by_float`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::collection::Collection;
+    ///
+    /// let collection = Collection::new(vec![("a", 0.3), ("b", -0.1)]);
+    /// assert_eq!(collection.sort_by_float(|(_, x)| *x)[0], ("b", -0.1));
+    /// ```
+    pub fn sort_by_float<F>(&self, iteratee: F) -> Vec<T>
+    where
+        T: Clone,
+        F: Fn(&T) -> f64,
+    {
+        sort_by_float(&self.data, iteratee)
+    }
+
+    /// Order by a `PartialOrd` key in either direction, placing NaN keys as
+    /// `nan` says. See [`order_by_partial`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::collection::Collection;
+    /// use rust_lodash::collection::transform::NanPolicy;
+    ///
+    /// let collection = Collection::new(vec![1.0, f64::NAN, 2.0]);
+    /// let sorted = collection.order_by_partial(|x| *x, false, NanPolicy::Last);
+    /// assert_eq!(sorted[..2], [2.0, 1.0]);
+    /// ```
+    pub fn order_by_partial<K, F>(&self, iteratee: F, ascending: bool, nan: NanPolicy) -> Vec<T>
+    where
+        T: Clone,
+        K: PartialOrd,
+        F: Fn(&T) -> K,
+    {
+        order_by_partial(&self.data, iteratee, ascending, nan)
+    }
+
+    /// Order by a float key in either direction, NaN last. See
+    /// [`order_by_float`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::collection::Collection;
+    ///
+    /// let collection = Collection::new(vec![("a", 0.3), ("b", -0.1)]);
+    /// assert_eq!(collection.order_by_float(|(_, x)| *x, false)[0], ("a", 0.3));
+    /// ```
+    pub fn order_by_float<F>(&self, iteratee: F, ascending: bool) -> Vec<T>
+    where
+        T: Clone,
+        F: Fn(&T) -> f64,
+    {
+        order_by_float(&self.data, iteratee, ascending)
+    }
+
+    /// Sort by a key computed once per element. See
+    /// [`sort_by_cached_key`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::collection::Collection;
+    ///
+    /// let collection = Collection::new(vec![10, 9, 100]);
+    /// assert_eq!(collection.sort_by_cached_key(|x| x.to_string()), vec![10, 100, 9]);
+    /// ```
+    pub fn sort_by_cached_key<K, F>(&self, iteratee: F) -> Vec<T>
+    where
+        T: Clone,
+        K: Ord,
+        F: Fn(&T) -> K,
+    {
+        sort_by_cached_key(&self.data, iteratee)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    // use std::collections::HashMap; // Already imported at module level
+    use alloc::string::ToString;
+    use alloc::vec;
 
     #[test]
     fn test_group_by() {
@@ -292,6 +948,106 @@ mod tests {
         assert_eq!(grouped.get(&4), Some(&vec![4.2]));
     }
 
+    #[test]
+    fn test_transforms_over_map_values() {
+        use alloc::collections::VecDeque;
+
+        let scores = BTreeMap::from([("ann", 90), ("bob", 72), ("cy", 85)]);
+        assert_eq!(sort_by(scores.values(), |s| core::cmp::Reverse(*s)), vec![90, 85, 72]);
+        let passed = group_by_ordered(scores.values(), |s| *s >= 80);
+        assert_eq!(passed.as_slice(), &[(true, vec![90, 85]), (false, vec![72])]);
+
+        let queue = VecDeque::from(vec![("x", 1), ("y", 2), ("x", 3)]);
+        let latest = key_by_sorted(&queue, |(name, _)| *name);
+        assert_eq!(latest.into_iter().collect::<Vec<_>>(), vec![("x", ("x", 3)), ("y", ("y", 2))]);
+    }
+
+    #[test]
+    fn test_float_sorts() {
+        let rows = vec![("a", 2.0), ("nan1", f64::NAN), ("b", -1.0), ("nan2", f64::NAN), ("c", 2.0)];
+        let names = |sorted: Vec<(&'static str, f64)>| -> Vec<&str> {
+            sorted.into_iter().map(|(name, _)| name).collect()
+        };
+        assert_eq!(
+            names(sort_by_partial(&rows, |(_, x)| *x, NanPolicy::Last)),
+            ["b", "a", "c", "nan1", "nan2"]
+        );
+        assert_eq!(
+            names(sort_by_partial(&rows, |(_, x)| *x, NanPolicy::First)),
+            ["nan1", "nan2", "b", "a", "c"]
+        );
+        assert_eq!(
+            names(sort_by_partial(&rows, |(_, x)| -x, NanPolicy::default())),
+            ["a", "c", "b", "nan1", "nan2"]
+        );
+        assert_eq!(
+            names(sort_by_float(&rows, |(_, x)| *x)),
+            ["b", "a", "c", "nan1", "nan2"]
+        );
+        assert_eq!(
+            names(sort_by_float(&rows, |(_, x)| -x)),
+            ["a", "c", "b", "nan1", "nan2"]
+        );
+        assert!(sort_by_float(&Vec::<f64>::new(), |x| *x).is_empty());
+
+        assert_eq!(
+            names(order_by_partial(&rows, |(_, x)| *x, false, NanPolicy::Last)),
+            ["a", "c", "b", "nan1", "nan2"]
+        );
+        assert_eq!(
+            names(order_by_partial(&rows, |(_, x)| *x, false, NanPolicy::First)),
+            ["nan1", "nan2", "a", "c", "b"]
+        );
+        assert_eq!(
+            names(order_by_partial(&rows, |(_, x)| *x, true, NanPolicy::Last)),
+            names(sort_by_partial(&rows, |(_, x)| *x, NanPolicy::Last))
+        );
+        let signed = vec![("neg_nan", -f64::NAN), ("zero", 0.0), ("neg_zero", -0.0), ("one", 1.0)];
+        assert_eq!(
+            names(sort_by_float(&signed, |(_, x)| *x)),
+            ["neg_zero", "zero", "one", "neg_nan"]
+        );
+        assert_eq!(
+            names(sort_by_float(&signed, |(_, x)| *x)),
+            names(order_by_float(&signed, |(_, x)| *x, true))
+        );
+        assert_eq!(
+            names(order_by_float(&signed, |(_, x)| *x, false)),
+            ["one", "zero", "neg_zero", "neg_nan"]
+        );
+        assert_eq!(
+            names(order_by_float(&signed, |(_, x)| *x, true)),
+            ["neg_zero", "zero", "one", "neg_nan"]
+        );
+        assert_eq!(
+            names(order_by_float(&rows, |(_, x)| *x, false)),
+            ["a", "c", "b", "nan1", "nan2"]
+        );
+    }
+
+    #[test]
+    fn test_sort_by_cached_key_calls_iteratee_once_per_element() {
+        use core::cell::Cell;
+
+        let calls = Cell::new(0);
+        let words = vec!["delta", "Alpha", "charlie", "Bravo"];
+        let sorted = sort_by_cached_key(&words, |word| {
+            calls.set(calls.get() + 1);
+            word.to_lowercase()
+        });
+        assert_eq!(sorted, vec!["Alpha", "Bravo", "charlie", "delta"]);
+        assert_eq!(calls.get(), words.len());
+        assert_eq!(sort_by_cached_key(&[2, 1, 2], |x| *x), sort_by(&[2, 1, 2], |x| *x));
+    }
+
+    #[test]
+    fn test_uniq_keeps_first_occurrence() {
+        let words = vec!["b", "a", "b", "c", "a"];
+        assert_eq!(uniq(&words), vec!["b", "a", "c"]);
+        assert_eq!(uniq_by(&words, |w| *w < "b"), vec!["b", "a"]);
+        assert!(uniq(&Vec::<i32>::new()).is_empty());
+    }
+
     #[test]
     fn test_key_by() {
         let users = vec![
@@ -409,7 +1165,7 @@ mod tests {
         let grouped = group_by(&empty, |x| x % 2);
         assert!(grouped.is_empty());
 
-        let keyed = key_by(&empty, std::string::ToString::to_string);
+        let keyed = key_by(&empty, ToString::to_string);
         assert!(keyed.is_empty());
 
         let invoked = invoke(&empty, |x| x * 2);
@@ -418,4 +1174,42 @@ mod tests {
         let sorted = sort_by(&empty, |x| *x);
         assert!(sorted.is_empty());
     }
+
+    #[test]
+    fn test_ordered_and_sorted_variants() {
+        let words = vec!["pear", "fig", "plum", "apple", "kiwi"];
+        let grouped = group_by_ordered(&words, |w| w.len());
+        assert_eq!(
+            grouped.as_slice(),
+            &[(4, vec!["pear", "plum", "kiwi"]), (3, vec!["fig"]), (5, vec!["apple"])]
+        );
+        let sorted = group_by_sorted(&words, |w| w.len());
+        assert_eq!(sorted.keys().copied().collect::<Vec<_>>(), vec![3, 4, 5]);
+
+        let keyed = key_by_ordered(&words, |w| w.len());
+        assert_eq!(keyed.values().copied().collect::<Vec<_>>(), vec!["kiwi", "fig", "apple"]);
+        let keyed: BTreeMap<usize, &str> = key_by_into(&words, |w| w.len());
+        assert_eq!(keyed, key_by_sorted(&words, |w| w.len()));
+        assert_eq!(keyed.into_iter().collect::<HashMap<_, _>>(), key_by(&words, |w| w.len()));
+    }
+
+    #[test]
+    fn test_sorted_variants_need_only_ord_keys() {
+        use crate::collection::query::count_by_sorted;
+
+        // Ordered but not hashable.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+        enum Size {
+            Small,
+            Large,
+        }
+
+        let size = |w: &&str| if w.len() > 3 { Size::Large } else { Size::Small };
+        let words = vec!["pear", "fig", "plum", "kiwi", "yam"];
+        let grouped = group_by_sorted(&words, size);
+        assert_eq!(grouped[&Size::Small], vec!["fig", "yam"]);
+        assert_eq!(grouped.keys().copied().collect::<Vec<_>>(), vec![Size::Small, Size::Large]);
+        assert_eq!(key_by_sorted(&words, size)[&Size::Large], "kiwi");
+        assert_eq!(count_by_sorted(&words, size)[&Size::Large], 3);
+    }
 }
@@ -0,0 +1,577 @@
+/*!
+Window methods for Lodash-RS.
+
+This module provides sequence operations over neighbouring elements:
+`windows`, `sliding`, `chunk`, `chunk_by`, `scan`, `reduce_scan`, `pairwise`,
+`rolling_sum` and `rolling_mean`.
+*/
+
+use crate::collection::source::{Indexed, Source};
+use crate::collection::Collection;
+use crate::math::Numeric;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Create every contiguous window of `size` elements. Returns an empty
+/// vector if `size` is zero or larger than the collection.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::window::windows;
+///
+/// let numbers = vec![1, 2, 3, 4];
+/// assert_eq!(windows(&numbers, 3), vec![vec![1, 2, 3], vec![2, 3, 4]]);
+/// assert!(windows(&numbers, 0).is_empty());
+/// ```
+pub fn windows<'a, T, S>(collection: S, size: usize) -> Vec<Vec<T>>
+where
+    T: Clone + 'a,
+    S: Source<'a, Item = T>,
+{
+    sliding(collection, size, 1)
+}
+
+/// Create windows of `size` elements starting every `step` elements. Only
+/// full windows are returned, so trailing elements that do not fill a window
+/// are dropped. Returns an empty vector if `size` or `step` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::window::sliding;
+///
+/// let numbers = vec![1, 2, 3, 4, 5, 6];
+/// assert_eq!(sliding(&numbers, 2, 3), vec![vec![1, 2], vec![4, 5]]);
+/// assert_eq!(sliding(&numbers, 3, 2), vec![vec![1, 2, 3], vec![3, 4, 5]]);
+/// ```
+pub fn sliding<'a, T, S>(collection: S, size: usize, step: usize) -> Vec<Vec<T>>
+where
+    T: Clone + 'a,
+    S: Source<'a, Item = T>,
+{
+    let items = Indexed::new(collection);
+    if size == 0 || step == 0 || size > items.len() {
+        return Vec::new();
+    }
+    (0..=items.len() - size)
+        .step_by(step)
+        .map(|start| {
+            (start..start + size)
+                .filter_map(|index| items.get(index))
+                .cloned()
+                .collect()
+        })
+        .collect()
+}
+
+/// Split the collection into chunks of `size` elements. The last chunk holds
+/// the remaining elements and may be shorter. Returns an empty vector if
+/// `size` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::window::chunk;
+///
+/// let letters = vec!['a', 'b', 'c', 'd', 'e'];
+/// assert_eq!(chunk(&letters, 2), vec![vec!['a', 'b'], vec!['c', 'd'], vec!['e']]);
+/// assert!(chunk(&letters, 0).is_empty());
+/// ```
+pub fn chunk<'a, T, S>(collection: S, size: usize) -> Vec<Vec<T>>
+where
+    T: Clone + 'a,
+    S: Source<'a, Item = T>,
+{
+    if size == 0 {
+        return Vec::new();
+    }
+    let mut chunks: Vec<Vec<T>> = Vec::new();
+    for item in collection.source_iter() {
+        match chunks.last_mut() {
+            Some(chunk) if chunk.len() < size => chunk.push(item.clone()),
+            _ => {
+                let mut chunk = Vec::with_capacity(size);
+                chunk.push(item.clone());
+                chunks.push(chunk);
+            }
+        }
+    }
+    chunks
+}
+
+/// Split the collection into runs of consecutive elements that produce the
+/// same key. Unlike `group_by`, equal keys that are not adjacent start a new
+/// run.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::window::chunk_by;
+///
+/// let numbers = vec![1, 3, 2, 4, 5, 7, 6];
+/// assert_eq!(
+///     chunk_by(&numbers, |x| x % 2),
+///     vec![vec![1, 3], vec![2, 4], vec![5, 7], vec![6]]
+/// );
+/// ```
+pub fn chunk_by<'a, T, S, K, F>(collection: S, key: F) -> Vec<Vec<T>>
+where
+    T: Clone + 'a,
+    S: Source<'a, Item = T>,
+    K: PartialEq,
+    F: Fn(&T) -> K,
+{
+    let mut runs: Vec<Vec<T>> = Vec::new();
+    let mut current: Option<K> = None;
+    for item in collection.source_iter() {
+        let item_key = key(item);
+        match runs.last_mut() {
+            Some(run) if current.as_ref() == Some(&item_key) => run.push(item.clone()),
+            _ => {
+                runs.push(vec![item.clone()]);
+                current = Some(item_key);
+            }
+        }
+    }
+    runs
+}
+
+/// Like `reduce`, but return every intermediate accumulator: the state
+/// after each element, starting from `initial`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::window::scan;
+///
+/// let numbers = vec![1, 2, 3, 4];
+/// assert_eq!(scan(&numbers, 0, |acc, x| acc + x), vec![1, 3, 6, 10]);
+/// assert_eq!(
+///     scan(&["a", "b"], String::new(), |acc, s| format!("{acc}{s}")),
+///     vec!["a", "ab"]
+/// );
+/// ```
+pub fn scan<'a, T, S, U, F>(collection: S, initial: U, iteratee: F) -> Vec<U>
+where
+    T: 'a,
+    S: Source<'a, Item = T>,
+    U: Clone,
+    F: Fn(&U, &T) -> U,
+{
+    let items = collection.source_iter();
+    let mut states = Vec::with_capacity(items.size_hint().0);
+    let mut state = initial;
+    for item in items {
+        state = iteratee(&state, item);
+        states.push(state.clone());
+    }
+    states
+}
+
+/// Like `scan`, but use the first element as the initial accumulator, so the
+/// result starts with the first element.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::window::reduce_scan;
+///
+/// let numbers = vec![3, 1, 4, 1, 5];
+/// assert_eq!(reduce_scan(&numbers, |max, x| *max.max(x)), vec![3, 3, 4, 4, 5]);
+/// assert!(reduce_scan(&Vec::<i32>::new(), |a, b| a + b).is_empty());
+/// ```
+pub fn reduce_scan<'a, T, S, F>(collection: S, iteratee: F) -> Vec<T>
+where
+    T: Clone + 'a,
+    S: Source<'a, Item = T>,
+    F: Fn(&T, &T) -> T,
+{
+    let mut items = collection.source_iter();
+    let Some(first) = items.next() else {
+        return Vec::new();
+    };
+    let mut states = Vec::with_capacity(items.size_hint().0 + 1);
+    states.push(first.clone());
+    for item in items {
+        let next = iteratee(&states[states.len() - 1], item);
+        states.push(next);
+    }
+    states
+}
+
+/// Pair every element with the one after it.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::window::pairwise;
+///
+/// assert_eq!(pairwise(&[1, 2, 3]), vec![(1, 2), (2, 3)]);
+/// assert!(pairwise(&[1]).is_empty());
+/// ```
+pub fn pairwise<'a, T, S>(collection: S) -> Vec<(T, T)>
+where
+    T: Clone + 'a,
+    S: Source<'a, Item = T>,
+{
+    let mut items = collection.source_iter();
+    let Some(mut previous) = items.next() else {
+        return Vec::new();
+    };
+    items
+        .map(|item| {
+            let pair = (previous.clone(), item.clone());
+            previous = item;
+            pair
+        })
+        .collect()
+}
+
+/// Sum every contiguous window of `window` values, in a single pass. Float
+/// sums are compensated as in [`crate::math::sum`]; integer sums are exact
+/// whenever the window's sum fits in `T`. Returns an empty vector if
+/// `window` is zero or larger than the collection.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::window::rolling_sum;
+///
+/// assert_eq!(rolling_sum(&[1, 2, 3, 4, 5], 3), vec![6, 9, 12]);
+/// assert_eq!(rolling_sum(&[0.1, 0.2, 0.3], 2), vec![0.30000000000000004, 0.5]);
+/// ```
+pub fn rolling_sum<'a, T, S>(collection: S, window: usize) -> Vec<T>
+where
+    T: Numeric + 'a,
+    S: Source<'a, Item = T>,
+{
+    let items = Indexed::new(collection);
+    if window == 0 || window > items.len() {
+        return Vec::new();
+    }
+    let mut sums = Vec::with_capacity(items.len() - window + 1);
+    let mut state = (T::ZERO, T::ZERO);
+    for end in 0..items.len() {
+        let value = items[end];
+        if end >= window {
+            let removed = items[end - window];
+            if T::IS_FLOAT {
+                T::sum_step(&mut state, T::ZERO - removed);
+            } else {
+                // Integer windows are exact. The running total may leave the
+                // range of the type between steps, but wrapping arithmetic
+                // brings it back whenever the window sum itself fits.
+                state.0 = state.0.wrapping_sub(removed);
+            }
+        }
+        if T::IS_FLOAT {
+            T::sum_step(&mut state, value);
+        } else {
+            state.0 = state.0.wrapping_add(value);
+        }
+        if end + 1 >= window {
+            let mut sum = T::sum_finish(state);
+            if T::IS_FLOAT && !sum.to_f64().is_finite() {
+                // An infinity or NaN that left the window still poisons the
+                // running state, so recompute and restart from this window.
+                let values = (end + 1 - window..=end).map(|index| items[index]);
+                sum = T::sum_values(values.clone());
+                state = (T::ZERO, T::ZERO);
+                for value in values {
+                    T::sum_step(&mut state, value);
+                }
+            }
+            sums.push(sum);
+        }
+    }
+    sums
+}
+
+/// Average every contiguous window of `window` values. Returns an empty
+/// vector if `window` is zero or larger than the collection.
+///
+/// Values are summed as `f64`, like [`crate::math::mean`], so integer
+/// windows whose sum does not fit in `T` still average correctly.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::window::rolling_mean;
+///
+/// assert_eq!(rolling_mean(&[1, 2, 3, 4], 2), vec![1.5, 2.5, 3.5]);
+/// assert_eq!(rolling_mean(&[200_u8, 200], 2), vec![200.0]);
+/// ```
+pub fn rolling_mean<'a, T, S>(collection: S, window: usize) -> Vec<f64>
+where
+    T: Numeric + 'a,
+    S: Source<'a, Item = T>,
+{
+    #[allow(clippy::cast_precision_loss)]
+    let size = window as f64;
+    let values: Vec<f64> = collection
+        .source_iter()
+        .map(|value| value.to_f64())
+        .collect();
+    rolling_sum(&values, window)
+        .into_iter()
+        .map(|sum| sum / size)
+        .collect()
+}
+
+/// Collection methods that work on the `Collection` type.
+impl<T> Collection<T> {
+    /// Create every contiguous window of `size` elements. See [`windows`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::collection::Collection;
+    ///
+    /// let collection = Collection::new(vec![1, 2, 3]);
+    /// assert_eq!(collection.windows(2), vec![vec![1, 2], vec![2, 3]]);
+    /// ```
+    #[must_use]
+    pub fn windows(&self, size: usize) -> Vec<Vec<T>>
+    where
+        T: Clone,
+    {
+        windows(self.data(), size)
+    }
+
+    /// Create windows of `size` elements starting every `step` elements.
+    /// See [`sliding`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::collection::Collection;
+    ///
+    /// let collection = Collection::new(vec![1, 2, 3, 4, 5]);
+    /// assert_eq!(collection.sliding(2, 2), vec![vec![1, 2], vec![3, 4]]);
+    /// ```
+    #[must_use]
+    pub fn sliding(&self, size: usize, step: usize) -> Vec<Vec<T>>
+    where
+        T: Clone,
+    {
+        sliding(self.data(), size, step)
+    }
+
+    /// Split the collection into chunks of `size` elements. See [`chunk`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::collection::Collection;
+    ///
+    /// let collection = Collection::new(vec![1, 2, 3]);
+    /// assert_eq!(collection.chunk(2), vec![vec![1, 2], vec![3]]);
+    /// ```
+    #[must_use]
+    pub fn chunk(&self, size: usize) -> Vec<Vec<T>>
+    where
+        T: Clone,
+    {
+        chunk(self.data(), size)
+    }
+
+    /// Split the collection into runs of consecutive elements with the same
+    /// key. See [`chunk_by`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::collection::Collection;
+    ///
+    /// let collection = Collection::new(vec!["a", "b", "cc", "d"]);
+    /// assert_eq!(collection.chunk_by(|s| s.len()), vec![vec!["a", "b"], vec!["cc"], vec!["d"]]);
+    /// ```
+    pub fn chunk_by<K, F>(&self, key: F) -> Vec<Vec<T>>
+    where
+        T: Clone,
+        K: PartialEq,
+        F: Fn(&T) -> K,
+    {
+        chunk_by(self.data(), key)
+    }
+
+    /// Return every intermediate accumulator of a `reduce`. See [`scan`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::collection::Collection;
+    ///
+    /// let collection = Collection::new(vec![1, 2, 3]);
+    /// assert_eq!(collection.scan(10, |acc, x| acc - x), vec![9, 7, 4]);
+    /// ```
+    pub fn scan<U, F>(&self, initial: U, iteratee: F) -> Vec<U>
+    where
+        U: Clone,
+        F: Fn(&U, &T) -> U,
+    {
+        scan(self.data(), initial, iteratee)
+    }
+
+    /// Like `scan`, starting from the first element. See [`reduce_scan`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::collection::Collection;
+    ///
+    /// let collection = Collection::new(vec![1, 2, 3]);
+    /// assert_eq!(collection.reduce_scan(|acc, x| acc * x), vec![1, 2, 6]);
+    /// ```
+    pub fn reduce_scan<F>(&self, iteratee: F) -> Vec<T>
+    where
+        T: Clone,
+        F: Fn(&T, &T) -> T,
+    {
+        reduce_scan(self.data(), iteratee)
+    }
+
+    /// Pair every element with the one after it. See [`pairwise`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::collection::Collection;
+    ///
+    /// let collection = Collection::new(vec!['a', 'b', 'c']);
+    /// assert_eq!(collection.pairwise(), vec![('a', 'b'), ('b', 'c')]);
+    /// ```
+    #[must_use]
+    pub fn pairwise(&self) -> Vec<(T, T)>
+    where
+        T: Clone,
+    {
+        pairwise(self.data())
+    }
+
+    /// Sum every contiguous window of `window` values. See [`rolling_sum`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::collection::Collection;
+    ///
+    /// let collection = Collection::new(vec![1, 2, 3, 4]);
+    /// assert_eq!(collection.rolling_sum(2), vec![3, 5, 7]);
+    /// ```
+    #[must_use]
+    pub fn rolling_sum(&self, window: usize) -> Vec<T>
+    where
+        T: Numeric,
+    {
+        rolling_sum(self.data(), window)
+    }
+
+    /// Average every contiguous window of `window` values. See
+    /// [`rolling_mean`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::collection::Collection;
+    ///
+    /// let collection = Collection::new(vec![2, 4, 6]);
+    /// assert_eq!(collection.rolling_mean(3), vec![4.0]);
+    /// ```
+    #[must_use]
+    pub fn rolling_mean(&self, window: usize) -> Vec<f64>
+    where
+        T: Numeric,
+    {
+        rolling_mean(self.data(), window)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_window_edges() {
+        let numbers = vec![1, 2, 3];
+        assert!(windows(&numbers, 4).is_empty());
+        assert_eq!(windows(&numbers, 3), vec![vec![1, 2, 3]]);
+        assert!(sliding(&numbers, 1, 0).is_empty());
+        assert_eq!(sliding(&numbers, 1, 2), vec![vec![1], vec![3]]);
+        assert_eq!(chunk(&numbers, 3), vec![vec![1, 2, 3]]);
+        assert_eq!(chunk(&numbers, 5), vec![vec![1, 2, 3]]);
+        assert!(chunk_by(&Vec::<i32>::new(), |x| *x).is_empty());
+        assert!(scan(&Vec::<i32>::new(), 0, |a, b| a + b).is_empty());
+    }
+
+    #[test]
+    fn test_rolling_sum_matches_naive() {
+        let values: Vec<f64> = (0..200).map(|i| f64::from(i % 17) * 0.1 - 0.7).collect();
+        for window in [1, 2, 5, 50] {
+            let naive: Vec<f64> = values
+                .windows(window)
+                .map(|w| f64::sum_values(w.iter().copied()))
+                .collect();
+            let rolling = rolling_sum(&values, window);
+            assert_eq!(rolling.len(), naive.len());
+            for (a, b) in rolling.iter().zip(&naive) {
+                assert!((a - b).abs() < 1e-12, "window {window}: {a} vs {b}");
+            }
+        }
+        assert_eq!(rolling_sum(&[5_u8, 250, 1, 2], 2), vec![255, 251, 3]);
+        assert_eq!(rolling_sum(&[-100_i8, 100, 100, -100], 3), vec![100, 100]);
+        assert_eq!(
+            rolling_sum(&[200_u8, 55, 0, 255, 0], 2),
+            vec![255, 55, 255, 255]
+        );
+    }
+
+    #[test]
+    fn test_rolling_sum_recovers_after_infinity() {
+        let values = [1.0, f64::INFINITY, 2.0, 3.0, 4.0];
+        let sums = rolling_sum(&values, 2);
+        assert_eq!(sums[..2], [f64::INFINITY, f64::INFINITY]);
+        assert_eq!(sums[2..], [5.0, 7.0]);
+        let nan = rolling_mean(&[f64::NAN, 1.0, 1.0], 2);
+        assert!(nan[0].is_nan());
+        assert_eq!(nan[1], 1.0);
+    }
+
+    #[test]
+    fn test_rolling_mean_does_not_overflow() {
+        assert_eq!(rolling_mean(&[200_u8, 200], 2), vec![200.0]);
+        assert_eq!(
+            rolling_mean(&[i32::MAX, i32::MAX, i32::MIN], 2),
+            vec![f64::from(i32::MAX), -0.5]
+        );
+        assert_eq!(rolling_mean(&[-100_i8, -100, -100], 3), vec![-100.0]);
+        assert_eq!(
+            rolling_mean(&[u64::MAX, u64::MAX], 2),
+            vec![u64::MAX.to_f64()]
+        );
+    }
+
+    #[test]
+    fn test_windows_accept_any_source() {
+        use crate::collection::source::refs;
+        use alloc::collections::{BTreeSet, VecDeque};
+
+        let queue: VecDeque<i32> = (1..=5).collect();
+        let set: BTreeSet<i32> = queue.iter().copied().collect();
+        assert_eq!(windows(&queue, 4), vec![vec![1, 2, 3, 4], vec![2, 3, 4, 5]]);
+        assert_eq!(sliding(&set, 2, 2), vec![vec![1, 2], vec![3, 4]]);
+        assert_eq!(chunk(set.iter(), 2), vec![vec![1, 2], vec![3, 4], vec![5]]);
+        assert_eq!(chunk_by(&queue, |x| x / 3), vec![vec![1, 2], vec![3, 4, 5]]);
+        assert_eq!(scan(&set, 0, |acc, x| acc + x), vec![1, 3, 6, 10, 15]);
+        assert_eq!(reduce_scan(&queue, |a, b| a * b), vec![1, 2, 6, 24, 120]);
+        assert_eq!(
+            pairwise(refs(queue.iter().rev().take(3))),
+            vec![(5, 4), (4, 3)]
+        );
+        assert_eq!(rolling_sum(&queue, 2), vec![3, 5, 7, 9]);
+        assert_eq!(rolling_sum(&set, 6), Vec::<i32>::new());
+        assert_eq!(rolling_mean(set.iter(), 5), vec![3.0]);
+    }
+}
@@ -6,9 +6,12 @@ and other extensions that enhance the core functionality.
 */
 
 pub mod parallel;
+pub mod traits;
 pub mod wasm;
 
 // Re-export commonly used items
+pub use traits::{LodashIterExt, LodashMapExt, LodashSliceExt};
+
 #[cfg(feature = "parallel")]
 pub use parallel::*;
 
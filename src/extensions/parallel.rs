@@ -11,6 +11,25 @@ use rayon::prelude::*;
 use crate::collection::Collection;
 #[cfg(feature = "parallel")]
 use crate::utils::{LodashError, Result};
+#[cfg(feature = "parallel")]
+use crate::math::statistics::{
+    check_percentile, histogram_edges, modal_key, percentile_of_sorted, Bins, Histogram,
+    PercentileMethod,
+};
+#[cfg(feature = "parallel")]
+use crate::math::{Numeric, RunningStats};
+#[cfg(feature = "parallel")]
+use crate::collection::join::{filter_rows, hash_index, inner_rows, left_rows, outer_rows};
+#[cfg(feature = "parallel")]
+use crate::collection::source::Indexed;
+#[cfg(feature = "parallel")]
+use std::collections::HashMap;
+#[cfg(feature = "parallel")]
+use std::hash::Hash;
+#[cfg(feature = "parallel")]
+use std::cmp::Ordering;
+#[cfg(feature = "parallel")]
+use crate::collection::transform::{compare_float, compare_partial, NanPolicy};
 
 #[cfg(feature = "parallel")]
 /// Parallel version of `map`.
@@ -30,7 +49,7 @@ where
     U: Send,
     F: Fn(&T) -> U + Sync,
 {
-    collection.par_iter().map(iteratee).collect()
+    collection.par_iter().map(&iteratee).collect()
 }
 
 #[cfg(feature = "parallel")]
@@ -58,6 +77,11 @@ where
 
 #[cfg(feature = "parallel")]
 /// Parallel version of `reduce`.
+///
+/// Each thread folds a part of the collection with `iteratee`, starting from
+/// a clone of `initial`, and the partial accumulators are merged in order
+/// with `combine`. `initial` must therefore be an identity for `combine`,
+/// such as `0` for addition.
 /// 
 /// # Examples
 /// 
@@ -65,16 +89,20 @@ where
 /// use rust_lodash::extensions::parallel::reduce_parallel;
 /// 
 /// let numbers = vec![1, 2, 3, 4, 5];
-/// let sum = reduce_parallel(&numbers, |acc, x| acc + x, 0);
+/// let sum = reduce_parallel(&numbers, |acc, x| acc + x, |a, b| a + b, 0);
 /// assert_eq!(sum, 15);
 /// ```
-pub fn reduce_parallel<T, U, F>(collection: &[T], iteratee: F, initial: U) -> U
+pub fn reduce_parallel<T, U, F, C>(collection: &[T], iteratee: F, combine: C, initial: U) -> U
 where
     T: Send + Sync,
-    U: Send + Sync,
+    U: Clone + Send + Sync,
     F: Fn(U, &T) -> U + Sync,
+    C: Fn(U, U) -> U + Sync,
 {
-    collection.par_iter().fold(|| initial, |acc, item| iteratee(acc, item)).reduce(|| initial, |a, b| a)
+    collection
+        .par_iter()
+        .fold(|| initial.clone(), &iteratee)
+        .reduce(|| initial.clone(), &combine)
 }
 
 #[cfg(feature = "parallel")]
@@ -84,18 +112,21 @@ where
 /// 
 /// ```
 /// use rust_lodash::extensions::parallel::for_each_parallel;
+/// use std::sync::atomic::{AtomicI32, Ordering};
 /// 
 /// let numbers = vec![1, 2, 3, 4, 5];
-/// let mut sum = 0;
-/// for_each_parallel(&numbers, |x| sum += x);
-/// assert_eq!(sum, 15);
+/// let sum = AtomicI32::new(0);
+/// for_each_parallel(&numbers, |x| {
+///     sum.fetch_add(*x, Ordering::Relaxed);
+/// });
+/// assert_eq!(sum.into_inner(), 15);
 /// ```
 pub fn for_each_parallel<T, F>(collection: &[T], iteratee: F)
 where
     T: Send + Sync,
     F: Fn(&T) + Sync,
 {
-    collection.par_iter().for_each(iteratee);
+    collection.par_iter().for_each(&iteratee);
 }
 
 #[cfg(feature = "parallel")]
@@ -135,7 +166,7 @@ where
     T: Send + Sync,
     F: Fn(&T) -> bool + Sync,
 {
-    collection.par_iter().all(predicate)
+    collection.par_iter().all(&predicate)
 }
 
 #[cfg(feature = "parallel")]
@@ -155,7 +186,529 @@ where
     T: Send + Sync,
     F: Fn(&T) -> bool + Sync,
 {
-    collection.par_iter().any(predicate)
+    collection.par_iter().any(&predicate)
+}
+
+#[cfg(feature = "parallel")]
+/// Map `collection` through `iteratee` in parallel, dropping `NaN` values,
+/// and sort the result.
+fn sorted_values_parallel<T, N, F>(collection: &[T], iteratee: F) -> Result<Vec<f64>>
+where
+    T: Sync,
+    N: Numeric,
+    F: Fn(&T) -> N + Sync,
+{
+    let mut values: Vec<f64> = collection
+        .par_iter()
+        .map(|item| iteratee(item).to_f64())
+        .filter(|value| !value.is_nan())
+        .collect();
+    if values.is_empty() {
+        return Err(LodashError::EmptyCollection);
+    }
+    values.par_sort_unstable_by(f64::total_cmp);
+    Ok(values)
+}
+
+#[cfg(feature = "parallel")]
+/// Parallel version of `median_by`.
+///
+/// # Errors
+///
+/// Returns `LodashError::EmptyCollection` if there are no non-`NaN` values.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::extensions::parallel::median_by_parallel;
+///
+/// let numbers = vec![5, 1, 4, 2, 3];
+/// assert_eq!(median_by_parallel(&numbers, |x| *x).unwrap(), 3.0);
+/// ```
+pub fn median_by_parallel<T, N, F>(collection: &[T], iteratee: F) -> Result<f64>
+where
+    T: Sync,
+    N: Numeric,
+    F: Fn(&T) -> N + Sync,
+{
+    percentile_by_parallel(collection, 50.0, PercentileMethod::Linear, iteratee)
+}
+
+#[cfg(feature = "parallel")]
+/// Parallel version of `percentile_by`.
+///
+/// # Errors
+///
+/// Returns `LodashError::InvalidInput` if `percentile` is not between 0 and
+/// 100, and `LodashError::EmptyCollection` if there are no non-`NaN` values.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::extensions::parallel::percentile_by_parallel;
+/// use rust_lodash::math::PercentileMethod;
+///
+/// let numbers: Vec<u32> = (1..=100).collect();
+/// let p95 = percentile_by_parallel(&numbers, 95.0, PercentileMethod::NearestRank, |x| *x);
+/// assert_eq!(p95.unwrap(), 95.0);
+/// ```
+pub fn percentile_by_parallel<T, N, F>(
+    collection: &[T],
+    percentile: f64,
+    method: PercentileMethod,
+    iteratee: F,
+) -> Result<f64>
+where
+    T: Sync,
+    N: Numeric,
+    F: Fn(&T) -> N + Sync,
+{
+    let fraction = check_percentile(percentile)?;
+    let values = sorted_values_parallel(collection, iteratee)?;
+    Ok(percentile_of_sorted(&values, fraction, method))
+}
+
+#[cfg(feature = "parallel")]
+/// Parallel version of `variance_by`. Partial results are merged with
+/// Chan's update, so the result matches the sequential version.
+///
+/// # Errors
+///
+/// Returns `LodashError::EmptyCollection` if there are no non-`NaN` values.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::extensions::parallel::variance_by_parallel;
+///
+/// let numbers = vec![2, 4, 4, 4, 5, 5, 7, 9];
+/// assert_eq!(variance_by_parallel(&numbers, |x| *x).unwrap(), 4.0);
+/// ```
+pub fn variance_by_parallel<T, N, F>(collection: &[T], iteratee: F) -> Result<f64>
+where
+    T: Sync,
+    N: Numeric,
+    F: Fn(&T) -> N + Sync,
+{
+    collection
+        .par_iter()
+        .fold(RunningStats::new, |mut stats, item| {
+            stats.push(iteratee(item).to_f64());
+            stats
+        })
+        .reduce(RunningStats::new, |mut left, right| {
+            left.merge(&right);
+            left
+        })
+        .variance()
+        .ok_or(LodashError::EmptyCollection)
+}
+
+#[cfg(feature = "parallel")]
+/// Parallel version of `std_dev_by`.
+///
+/// # Errors
+///
+/// Returns `LodashError::EmptyCollection` if there are no non-`NaN` values.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::extensions::parallel::std_dev_by_parallel;
+///
+/// let numbers = vec![2, 4, 4, 4, 5, 5, 7, 9];
+/// assert_eq!(std_dev_by_parallel(&numbers, |x| *x).unwrap(), 2.0);
+/// ```
+pub fn std_dev_by_parallel<T, N, F>(collection: &[T], iteratee: F) -> Result<f64>
+where
+    T: Sync,
+    N: Numeric,
+    F: Fn(&T) -> N + Sync,
+{
+    variance_by_parallel(collection, iteratee).map(f64::sqrt)
+}
+
+#[cfg(feature = "parallel")]
+/// Parallel version of `mode_by`. On ties the key seen first wins, as in
+/// the sequential version.
+///
+/// # Errors
+///
+/// Returns `LodashError::EmptyCollection` if `collection` is empty.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::extensions::parallel::mode_by_parallel;
+///
+/// let words = vec!["one", "two", "three", "four", "five"];
+/// assert_eq!(mode_by_parallel(&words, |word| word.len()).unwrap(), 3);
+/// ```
+pub fn mode_by_parallel<T, K, F>(collection: &[T], iteratee: F) -> Result<K>
+where
+    T: Sync,
+    K: Eq + Hash + Send,
+    F: Fn(&T) -> K + Sync,
+{
+    let counts = collection
+        .par_iter()
+        .enumerate()
+        .fold(HashMap::new, |mut counts: HashMap<K, (usize, usize)>, (index, item)| {
+            counts.entry(iteratee(item)).or_insert((0, index)).0 += 1;
+            counts
+        })
+        .reduce(HashMap::new, |mut left, right| {
+            for (key, (count, first)) in right {
+                let entry = left.entry(key).or_insert((0, first));
+                entry.0 += count;
+                entry.1 = entry.1.min(first);
+            }
+            left
+        });
+    modal_key(counts)
+}
+
+#[cfg(feature = "parallel")]
+/// Parallel version of `histogram_by`.
+///
+/// # Errors
+///
+/// Returns `LodashError::InvalidInput` for invalid `bins`, and
+/// `LodashError::EmptyCollection` if there are no non-`NaN` values.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::extensions::parallel::histogram_by_parallel;
+/// use rust_lodash::math::Bins;
+///
+/// let numbers = vec![1.0, 2.0, 2.5, 4.0, 9.0];
+/// let histogram = histogram_by_parallel(&numbers, Bins::Count(4), |x| *x).unwrap();
+/// assert_eq!(histogram.counts, vec![3, 1, 0, 1]);
+/// ```
+pub fn histogram_by_parallel<T, N, F>(collection: &[T], bins: Bins, iteratee: F) -> Result<Histogram>
+where
+    T: Sync,
+    N: Numeric,
+    F: Fn(&T) -> N + Sync,
+{
+    let values = sorted_values_parallel(collection, iteratee)?;
+    let edges = histogram_edges(&values, bins)?;
+    let bin_count = edges.len() - 1;
+    let counts = values
+        .par_iter()
+        .fold(
+            || vec![0; bin_count],
+            |mut counts, &value| {
+                if let Some(index) = Histogram::bin_index(&edges, value) {
+                    counts[index] += 1;
+                }
+                counts
+            },
+        )
+        .reduce(
+            || vec![0; bin_count],
+            |mut left, right| {
+                for (total, count) in left.iter_mut().zip(right) {
+                    *total += count;
+                }
+                left
+            },
+        );
+    Ok(Histogram { edges, counts })
+}
+
+#[cfg(feature = "parallel")]
+/// Matching `(left, right)` index pairs of a hash join, probing the left
+/// side in parallel. Pairs keep the sequential order.
+fn hash_matches_parallel<A, B, K, FA, FB>(
+    left: &[A],
+    right: &[B],
+    left_key: FA,
+    right_key: FB,
+) -> Vec<(usize, usize)>
+where
+    A: Sync,
+    K: Hash + Eq + Sync,
+    FA: Fn(&A) -> K + Sync,
+    FB: Fn(&B) -> K,
+{
+    let index = hash_index(right, right_key);
+    left.par_iter()
+        .enumerate()
+        .flat_map_iter(|(i, item)| {
+            index
+                .get(&left_key(item))
+                .into_iter()
+                .flatten()
+                .map(move |&j| (i, j))
+        })
+        .collect()
+}
+
+#[cfg(feature = "parallel")]
+/// Parallel version of `join_by`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::extensions::parallel::join_by_parallel;
+///
+/// let joined = join_by_parallel(&[1, 2, 3], &[(2, "b"), (3, "c")], |x| *x, |r| r.0);
+/// assert_eq!(joined, vec![(2, (2, "b")), (3, (3, "c"))]);
+/// ```
+pub fn join_by_parallel<A, B, K, FA, FB>(
+    left: &[A],
+    right: &[B],
+    left_key: FA,
+    right_key: FB,
+) -> Vec<(A, B)>
+where
+    A: Clone + Sync,
+    B: Clone,
+    K: Hash + Eq + Sync,
+    FA: Fn(&A) -> K + Sync,
+    FB: Fn(&B) -> K,
+{
+    let pairs = hash_matches_parallel(left, right, left_key, right_key);
+    inner_rows(&Indexed::new(left), &Indexed::new(right), &pairs)
+}
+
+#[cfg(feature = "parallel")]
+/// Parallel version of `left_join_by`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::extensions::parallel::left_join_by_parallel;
+///
+/// let joined = left_join_by_parallel(&[1, 2], &[2], |x| *x, |x| *x);
+/// assert_eq!(joined, vec![(1, None), (2, Some(2))]);
+/// ```
+pub fn left_join_by_parallel<A, B, K, FA, FB>(
+    left: &[A],
+    right: &[B],
+    left_key: FA,
+    right_key: FB,
+) -> Vec<(A, Option<B>)>
+where
+    A: Clone + Sync,
+    B: Clone,
+    K: Hash + Eq + Sync,
+    FA: Fn(&A) -> K + Sync,
+    FB: Fn(&B) -> K,
+{
+    let pairs = hash_matches_parallel(left, right, left_key, right_key);
+    left_rows(&Indexed::new(left), &Indexed::new(right), &pairs)
+}
+
+#[cfg(feature = "parallel")]
+/// Parallel version of `outer_join_by`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::extensions::parallel::outer_join_by_parallel;
+///
+/// let joined = outer_join_by_parallel(&[1, 2], &[2, 3], |x| *x, |x| *x);
+/// assert_eq!(joined, vec![(Some(1), None), (Some(2), Some(2)), (None, Some(3))]);
+/// ```
+pub fn outer_join_by_parallel<A, B, K, FA, FB>(
+    left: &[A],
+    right: &[B],
+    left_key: FA,
+    right_key: FB,
+) -> Vec<(Option<A>, Option<B>)>
+where
+    A: Clone + Sync,
+    B: Clone,
+    K: Hash + Eq + Sync,
+    FA: Fn(&A) -> K + Sync,
+    FB: Fn(&B) -> K,
+{
+    let pairs = hash_matches_parallel(left, right, left_key, right_key);
+    outer_rows(&Indexed::new(left), &Indexed::new(right), &pairs)
+}
+
+#[cfg(feature = "parallel")]
+/// Parallel version of `semi_join_by`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::extensions::parallel::semi_join_by_parallel;
+///
+/// assert_eq!(semi_join_by_parallel(&[1, 2, 3], &[3, 1], |x| *x, |x| *x), vec![1, 3]);
+/// ```
+pub fn semi_join_by_parallel<A, B, K, FA, FB>(
+    left: &[A],
+    right: &[B],
+    left_key: FA,
+    right_key: FB,
+) -> Vec<A>
+where
+    A: Clone + Sync,
+    K: Hash + Eq + Sync,
+    FA: Fn(&A) -> K + Sync,
+    FB: Fn(&B) -> K,
+{
+    let pairs = hash_matches_parallel(left, right, left_key, right_key);
+    filter_rows(&Indexed::new(left), &pairs, true)
+}
+
+#[cfg(feature = "parallel")]
+/// Parallel version of `anti_join_by`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::extensions::parallel::anti_join_by_parallel;
+///
+/// assert_eq!(anti_join_by_parallel(&[1, 2, 3], &[3, 1], |x| *x, |x| *x), vec![2]);
+/// ```
+pub fn anti_join_by_parallel<A, B, K, FA, FB>(
+    left: &[A],
+    right: &[B],
+    left_key: FA,
+    right_key: FB,
+) -> Vec<A>
+where
+    A: Clone + Sync,
+    K: Hash + Eq + Sync,
+    FA: Fn(&A) -> K + Sync,
+    FB: Fn(&B) -> K,
+{
+    let pairs = hash_matches_parallel(left, right, left_key, right_key);
+    filter_rows(&Indexed::new(left), &pairs, false)
+}
+
+#[cfg(feature = "parallel")]
+/// Compute every key in parallel, once per element, and stably sort by key
+/// with `compare`, so the result matches the sequential version.
+fn sort_by_keys_parallel<T, K, F, C>(collection: &[T], iteratee: F, compare: C) -> Vec<T>
+where
+    T: Clone + Sync,
+    K: Send,
+    F: Fn(&T) -> K + Sync,
+    C: Fn(&K, &K) -> Ordering + Sync,
+{
+    let mut keyed: Vec<(K, usize)> = collection
+        .par_iter()
+        .enumerate()
+        .map(|(index, item)| (iteratee(item), index))
+        .collect();
+    keyed.par_sort_by(|(a, _), (b, _)| compare(a, b));
+    keyed
+        .into_iter()
+        .map(|(_, index)| collection[index].clone())
+        .collect()
+}
+
+#[cfg(feature = "parallel")]
+/// Parallel version of `sort_by_partial`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::transform::NanPolicy;
+/// use rust_lodash::extensions::parallel::sort_by_partial_parallel;
+///
+/// let sorted = sort_by_partial_parallel(&[1.5, f64::NAN, 0.5], |x| *x, NanPolicy::First);
+/// assert!(sorted[0].is_nan());
+/// assert_eq!(sorted[1..], [0.5, 1.5]);
+/// ```
+pub fn sort_by_partial_parallel<T, K, F>(collection: &[T], iteratee: F, nan: NanPolicy) -> Vec<T>
+where
+    T: Clone + Sync,
+    K: PartialOrd + Send,
+    F: Fn(&T) -> K + Sync,
+{
+    sort_by_keys_parallel(collection, iteratee, |a, b| compare_partial(a, b, nan, true))
+}
+
+#[cfg(feature = "parallel")]
+/// Parallel version of `sort_by_float`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::extensions::parallel::sort_by_float_parallel;
+///
+/// let sorted = sort_by_float_parallel(&[("b", 2.0), ("a", -1.0)], |(_, x)| *x);
+/// assert_eq!(sorted, vec![("a", -1.0), ("b", 2.0)]);
+/// ```
+pub fn sort_by_float_parallel<T, F>(collection: &[T], iteratee: F) -> Vec<T>
+where
+    T: Clone + Sync,
+    F: Fn(&T) -> f64 + Sync,
+{
+    sort_by_keys_parallel(collection, iteratee, |a, b| compare_float(*a, *b, true))
+}
+
+#[cfg(feature = "parallel")]
+/// Parallel version of `order_by_partial`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::transform::NanPolicy;
+/// use rust_lodash::extensions::parallel::order_by_partial_parallel;
+///
+/// let sorted = order_by_partial_parallel(&[1.5, f64::NAN, 0.5], |x| *x, false, NanPolicy::Last);
+/// assert_eq!(sorted[..2], [1.5, 0.5]);
+/// assert!(sorted[2].is_nan());
+/// ```
+pub fn order_by_partial_parallel<T, K, F>(
+    collection: &[T],
+    iteratee: F,
+    ascending: bool,
+    nan: NanPolicy,
+) -> Vec<T>
+where
+    T: Clone + Sync,
+    K: PartialOrd + Send,
+    F: Fn(&T) -> K + Sync,
+{
+    sort_by_keys_parallel(collection, iteratee, |a, b| compare_partial(a, b, nan, ascending))
+}
+
+#[cfg(feature = "parallel")]
+/// Parallel version of `order_by_float`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::extensions::parallel::order_by_float_parallel;
+///
+/// let sorted = order_by_float_parallel(&[("b", 2.0), ("a", -1.0)], |(_, x)| *x, false);
+/// assert_eq!(sorted, vec![("b", 2.0), ("a", -1.0)]);
+/// ```
+pub fn order_by_float_parallel<T, F>(collection: &[T], iteratee: F, ascending: bool) -> Vec<T>
+where
+    T: Clone + Sync,
+    F: Fn(&T) -> f64 + Sync,
+{
+    sort_by_keys_parallel(collection, iteratee, |a, b| compare_float(*a, *b, ascending))
+}
+
+#[cfg(feature = "parallel")]
+/// Parallel version of `sort_by_cached_key`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::extensions::parallel::sort_by_cached_key_parallel;
+///
+/// let sorted = sort_by_cached_key_parallel(&["b", "C", "a"], |s| s.to_lowercase());
+/// assert_eq!(sorted, vec!["a", "b", "C"]);
+/// ```
+pub fn sort_by_cached_key_parallel<T, K, F>(collection: &[T], iteratee: F) -> Vec<T>
+where
+    T: Clone + Sync,
+    K: Ord + Send,
+    F: Fn(&T) -> K + Sync,
+{
+    sort_by_keys_parallel(collection, iteratee, Ord::cmp)
 }
 
 #[cfg(feature = "parallel")]
@@ -178,7 +731,7 @@ impl<T> Collection<T> {
         U: Send,
         F: Fn(&T) -> U + Sync,
     {
-        map_parallel(&self.data, iteratee)
+        map_parallel(self.data(), iteratee)
     }
 
     /// Parallel version of `filter`.
@@ -197,7 +750,7 @@ impl<T> Collection<T> {
         T: Clone + Send + Sync,
         F: Fn(&T) -> bool + Sync,
     {
-        filter_parallel(&self.data, predicate)
+        filter_parallel(self.data(), predicate)
     }
 
     /// Parallel version of `reduce`.
@@ -208,16 +761,17 @@ impl<T> Collection<T> {
     /// use rust_lodash::collection::Collection;
     /// 
     /// let collection = Collection::new(vec![1, 2, 3, 4, 5]);
-    /// let sum = collection.reduce_parallel(|acc, x| acc + x, 0);
+    /// let sum = collection.reduce_parallel(|acc, x| acc + x, |a, b| a + b, 0);
     /// assert_eq!(sum, 15);
     /// ```
-    pub fn reduce_parallel<U, F>(&self, iteratee: F, initial: U) -> U
+    pub fn reduce_parallel<U, F, C>(&self, iteratee: F, combine: C, initial: U) -> U
     where
         T: Send + Sync,
-        U: Send + Sync,
+        U: Clone + Send + Sync,
         F: Fn(U, &T) -> U + Sync,
+        C: Fn(U, U) -> U + Sync,
     {
-        reduce_parallel(&self.data, iteratee, initial)
+        reduce_parallel(self.data(), iteratee, combine, initial)
     }
 
     /// Parallel version of `for_each`.
@@ -226,18 +780,21 @@ impl<T> Collection<T> {
     /// 
     /// ```
     /// use rust_lodash::collection::Collection;
+    /// use std::sync::atomic::{AtomicI32, Ordering};
     /// 
     /// let collection = Collection::new(vec![1, 2, 3, 4, 5]);
-    /// let mut sum = 0;
-    /// collection.for_each_parallel(|x| sum += x);
-    /// assert_eq!(sum, 15);
+    /// let sum = AtomicI32::new(0);
+    /// collection.for_each_parallel(|x| {
+    ///     sum.fetch_add(*x, Ordering::Relaxed);
+    /// });
+    /// assert_eq!(sum.into_inner(), 15);
     /// ```
     pub fn for_each_parallel<F>(&self, iteratee: F)
     where
         T: Send + Sync,
         F: Fn(&T) + Sync,
     {
-        for_each_parallel(&self.data, iteratee)
+        for_each_parallel(self.data(), iteratee);
     }
 
     /// Parallel version of `find`.
@@ -256,7 +813,7 @@ impl<T> Collection<T> {
         T: Send + Sync,
         F: Fn(&T) -> bool + Sync,
     {
-        find_parallel(&self.data, predicate)
+        find_parallel(self.data(), predicate)
     }
 
     /// Parallel version of `every`.
@@ -275,7 +832,7 @@ impl<T> Collection<T> {
         T: Send + Sync,
         F: Fn(&T) -> bool + Sync,
     {
-        every_parallel(&self.data, predicate)
+        every_parallel(self.data(), predicate)
     }
 
     /// Parallel version of `some`.
@@ -294,7 +851,235 @@ impl<T> Collection<T> {
         T: Send + Sync,
         F: Fn(&T) -> bool + Sync,
     {
-        some_parallel(&self.data, predicate)
+        some_parallel(self.data(), predicate)
+    }
+
+    /// Parallel version of `median_by`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LodashError::EmptyCollection` if there are no non-`NaN` values.
+    pub fn median_by_parallel<N, F>(&self, iteratee: F) -> Result<f64>
+    where
+        T: Sync,
+        N: Numeric,
+        F: Fn(&T) -> N + Sync,
+    {
+        median_by_parallel(self.data(), iteratee)
+    }
+
+    /// Parallel version of `percentile_by`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LodashError::InvalidInput` if `percentile` is not between 0
+    /// and 100, and `LodashError::EmptyCollection` if there are no non-`NaN`
+    /// values.
+    pub fn percentile_by_parallel<N, F>(
+        &self,
+        percentile: f64,
+        method: PercentileMethod,
+        iteratee: F,
+    ) -> Result<f64>
+    where
+        T: Sync,
+        N: Numeric,
+        F: Fn(&T) -> N + Sync,
+    {
+        percentile_by_parallel(self.data(), percentile, method, iteratee)
+    }
+
+    /// Parallel version of `variance_by`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LodashError::EmptyCollection` if there are no non-`NaN` values.
+    pub fn variance_by_parallel<N, F>(&self, iteratee: F) -> Result<f64>
+    where
+        T: Sync,
+        N: Numeric,
+        F: Fn(&T) -> N + Sync,
+    {
+        variance_by_parallel(self.data(), iteratee)
+    }
+
+    /// Parallel version of `std_dev_by`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LodashError::EmptyCollection` if there are no non-`NaN` values.
+    pub fn std_dev_by_parallel<N, F>(&self, iteratee: F) -> Result<f64>
+    where
+        T: Sync,
+        N: Numeric,
+        F: Fn(&T) -> N + Sync,
+    {
+        std_dev_by_parallel(self.data(), iteratee)
+    }
+
+    /// Parallel version of `mode_by`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LodashError::EmptyCollection` if the collection is empty.
+    pub fn mode_by_parallel<K, F>(&self, iteratee: F) -> Result<K>
+    where
+        T: Sync,
+        K: Eq + Hash + Send,
+        F: Fn(&T) -> K + Sync,
+    {
+        mode_by_parallel(self.data(), iteratee)
+    }
+
+    /// Parallel version of `histogram_by`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LodashError::InvalidInput` for invalid `bins`, and
+    /// `LodashError::EmptyCollection` if there are no non-`NaN` values.
+    pub fn histogram_by_parallel<N, F>(&self, bins: Bins, iteratee: F) -> Result<Histogram>
+    where
+        T: Sync,
+        N: Numeric,
+        F: Fn(&T) -> N + Sync,
+    {
+        histogram_by_parallel(self.data(), bins, iteratee)
+    }
+
+    /// Parallel version of `join_by`.
+    pub fn join_by_parallel<B, K, FA, FB>(
+        &self,
+        right: &[B],
+        left_key: FA,
+        right_key: FB,
+    ) -> Vec<(T, B)>
+    where
+        T: Clone + Sync,
+        B: Clone,
+        K: Hash + Eq + Sync,
+        FA: Fn(&T) -> K + Sync,
+        FB: Fn(&B) -> K,
+    {
+        join_by_parallel(self.data(), right, left_key, right_key)
+    }
+
+    /// Parallel version of `left_join_by`.
+    pub fn left_join_by_parallel<B, K, FA, FB>(
+        &self,
+        right: &[B],
+        left_key: FA,
+        right_key: FB,
+    ) -> Vec<(T, Option<B>)>
+    where
+        T: Clone + Sync,
+        B: Clone,
+        K: Hash + Eq + Sync,
+        FA: Fn(&T) -> K + Sync,
+        FB: Fn(&B) -> K,
+    {
+        left_join_by_parallel(self.data(), right, left_key, right_key)
+    }
+
+    /// Parallel version of `outer_join_by`.
+    pub fn outer_join_by_parallel<B, K, FA, FB>(
+        &self,
+        right: &[B],
+        left_key: FA,
+        right_key: FB,
+    ) -> Vec<(Option<T>, Option<B>)>
+    where
+        T: Clone + Sync,
+        B: Clone,
+        K: Hash + Eq + Sync,
+        FA: Fn(&T) -> K + Sync,
+        FB: Fn(&B) -> K,
+    {
+        outer_join_by_parallel(self.data(), right, left_key, right_key)
+    }
+
+    /// Parallel version of `semi_join_by`.
+    pub fn semi_join_by_parallel<B, K, FA, FB>(
+        &self,
+        right: &[B],
+        left_key: FA,
+        right_key: FB,
+    ) -> Vec<T>
+    where
+        T: Clone + Sync,
+        K: Hash + Eq + Sync,
+        FA: Fn(&T) -> K + Sync,
+        FB: Fn(&B) -> K,
+    {
+        semi_join_by_parallel(self.data(), right, left_key, right_key)
+    }
+
+    /// Parallel version of `anti_join_by`.
+    pub fn anti_join_by_parallel<B, K, FA, FB>(
+        &self,
+        right: &[B],
+        left_key: FA,
+        right_key: FB,
+    ) -> Vec<T>
+    where
+        T: Clone + Sync,
+        K: Hash + Eq + Sync,
+        FA: Fn(&T) -> K + Sync,
+        FB: Fn(&B) -> K,
+    {
+        anti_join_by_parallel(self.data(), right, left_key, right_key)
+    }
+
+    /// Parallel version of `sort_by_partial`.
+    pub fn sort_by_partial_parallel<K, F>(&self, iteratee: F, nan: NanPolicy) -> Vec<T>
+    where
+        T: Clone + Sync,
+        K: PartialOrd + Send,
+        F: Fn(&T) -> K + Sync,
+    {
+        sort_by_partial_parallel(self.data(), iteratee, nan)
+    }
+
+    /// Parallel version of `sort_by_float`.
+    pub fn sort_by_float_parallel<F>(&self, iteratee: F) -> Vec<T>
+    where
+        T: Clone + Sync,
+        F: Fn(&T) -> f64 + Sync,
+    {
+        sort_by_float_parallel(self.data(), iteratee)
+    }
+
+    /// Parallel version of `order_by_partial`.
+    pub fn order_by_partial_parallel<K, F>(
+        &self,
+        iteratee: F,
+        ascending: bool,
+        nan: NanPolicy,
+    ) -> Vec<T>
+    where
+        T: Clone + Sync,
+        K: PartialOrd + Send,
+        F: Fn(&T) -> K + Sync,
+    {
+        order_by_partial_parallel(self.data(), iteratee, ascending, nan)
+    }
+
+    /// Parallel version of `order_by_float`.
+    pub fn order_by_float_parallel<F>(&self, iteratee: F, ascending: bool) -> Vec<T>
+    where
+        T: Clone + Sync,
+        F: Fn(&T) -> f64 + Sync,
+    {
+        order_by_float_parallel(self.data(), iteratee, ascending)
+    }
+
+    /// Parallel version of `sort_by_cached_key`.
+    pub fn sort_by_cached_key_parallel<K, F>(&self, iteratee: F) -> Vec<T>
+    where
+        T: Clone + Sync,
+        K: Ord + Send,
+        F: Fn(&T) -> K + Sync,
+    {
+        sort_by_cached_key_parallel(self.data(), iteratee)
     }
 }
 
@@ -302,6 +1087,7 @@ impl<T> Collection<T> {
 #[cfg(feature = "parallel")]
 mod tests {
     use super::*;
+    use std::sync::atomic::{AtomicI32, Ordering};
 
     #[test]
     fn test_map_parallel() {
@@ -320,16 +1106,22 @@ mod tests {
     #[test]
     fn test_reduce_parallel() {
         let numbers = vec![1, 2, 3, 4, 5];
-        let sum = reduce_parallel(&numbers, |acc, x| acc + x, 0);
+        let sum = reduce_parallel(&numbers, |acc, x| acc + x, |a, b| a + b, 0);
         assert_eq!(sum, 15);
+
+        let words: Vec<String> = (0..1000).map(|i| i.to_string()).collect();
+        let joined = reduce_parallel(&words, |acc, word| acc + word, |a, b| a + &b, String::new());
+        assert_eq!(joined, words.concat());
     }
 
     #[test]
     fn test_for_each_parallel() {
         let numbers = vec![1, 2, 3, 4, 5];
-        let mut sum = 0;
-        for_each_parallel(&numbers, |x| sum += x);
-        assert_eq!(sum, 15);
+        let sum = AtomicI32::new(0);
+        for_each_parallel(&numbers, |x| {
+            sum.fetch_add(*x, Ordering::Relaxed);
+        });
+        assert_eq!(sum.into_inner(), 15);
     }
 
     #[test]
@@ -370,16 +1162,18 @@ mod tests {
     #[test]
     fn test_collection_reduce_parallel() {
         let collection = Collection::new(vec![1, 2, 3, 4, 5]);
-        let sum = collection.reduce_parallel(|acc, x| acc + x, 0);
+        let sum = collection.reduce_parallel(|acc, x| acc + x, |a, b| a + b, 0);
         assert_eq!(sum, 15);
     }
 
     #[test]
     fn test_collection_for_each_parallel() {
         let collection = Collection::new(vec![1, 2, 3, 4, 5]);
-        let mut sum = 0;
-        collection.for_each_parallel(|x| sum += x);
-        assert_eq!(sum, 15);
+        let sum = AtomicI32::new(0);
+        collection.for_each_parallel(|x| {
+            sum.fetch_add(*x, Ordering::Relaxed);
+        });
+        assert_eq!(sum.into_inner(), 15);
     }
 
     #[test]
@@ -403,6 +1197,106 @@ mod tests {
         assert!(!has_even);
     }
 
+    #[test]
+    fn test_statistics_parallel_match_sequential() {
+        use crate::math::{histogram_by, mode_by, percentile_by, variance_by};
+
+        let numbers: Vec<u32> = (0..10_000).map(|i| (i * 7_919) % 1_000).collect();
+        for method in [PercentileMethod::Linear, PercentileMethod::NearestRank] {
+            assert_eq!(
+                percentile_by_parallel(&numbers, 37.5, method, |x| *x),
+                percentile_by(&numbers, 37.5, method, |x| *x)
+            );
+        }
+        let parallel = variance_by_parallel(&numbers, |x| *x).unwrap();
+        let sequential = variance_by(&numbers, |x| *x).unwrap();
+        assert!((parallel - sequential).abs() < 1e-6);
+        assert_eq!(
+            mode_by_parallel(&numbers, |x| x % 7),
+            mode_by(&numbers, |x| x % 7)
+        );
+        assert_eq!(
+            histogram_by_parallel(&numbers, Bins::Auto, |x| *x),
+            histogram_by(&numbers, Bins::Auto, |x| *x)
+        );
+        let collection = Collection::new(numbers);
+        assert_eq!(collection.median_by_parallel(|x| *x), Ok(499.5));
+    }
+
+    #[test]
+    fn test_statistics_parallel_empty() {
+        let empty: Vec<f64> = vec![];
+        assert_eq!(median_by_parallel(&empty, |x| *x), Err(LodashError::EmptyCollection));
+        assert_eq!(std_dev_by_parallel(&empty, |x| *x), Err(LodashError::EmptyCollection));
+        assert_eq!(mode_by_parallel(&empty, |x| x.to_bits()), Err(LodashError::EmptyCollection));
+    }
+
+    #[test]
+    fn test_joins_parallel_match_sequential() {
+        use crate::collection::join;
+        let left: Vec<u32> = (0..500).map(|i| i * 7 % 113).collect();
+        let right: Vec<u32> = (0..300).map(|i| i * 11 % 97).collect();
+        let key = |x: &u32| x % 50;
+        assert_eq!(
+            join_by_parallel(&left, &right, key, key),
+            join::join_by(&left, &right, key, key)
+        );
+        assert_eq!(
+            left_join_by_parallel(&left, &right, key, key),
+            join::left_join_by(&left, &right, key, key)
+        );
+        assert_eq!(
+            outer_join_by_parallel(&left, &right, |x| *x, |x| *x),
+            join::outer_join_by(&left, &right, |x| *x, |x| *x)
+        );
+        assert_eq!(
+            semi_join_by_parallel(&left, &right, |x| *x, |x| *x),
+            join::semi_join_by(&left, &right, |x| *x, |x| *x)
+        );
+        let collection = Collection::new(left.clone());
+        assert_eq!(
+            collection.anti_join_by_parallel(&right, |x| *x, |x| *x),
+            join::anti_join_by(&left, &right, |x| *x, |x| *x)
+        );
+    }
+
+    #[test]
+    fn test_sorts_match_sequential() {
+        use crate::collection::transform;
+
+        let values: Vec<(u32, f64)> = (0..2000)
+            .map(|i| (i, if i % 97 == 0 { f64::NAN } else { f64::from((i * 37) % 101) / 4.0 }))
+            .collect();
+        let key = |(_, x): &(u32, f64)| *x;
+        let ids = |rows: Vec<(u32, f64)>| rows.into_iter().map(|(i, _)| i).collect::<Vec<_>>();
+        for nan in [NanPolicy::First, NanPolicy::Last] {
+            assert_eq!(
+                ids(sort_by_partial_parallel(&values, key, nan)),
+                ids(transform::sort_by_partial(&values, key, nan))
+            );
+            for ascending in [true, false] {
+                assert_eq!(
+                    ids(order_by_partial_parallel(&values, key, ascending, nan)),
+                    ids(transform::order_by_partial(&values, key, ascending, nan))
+                );
+            }
+        }
+        assert_eq!(
+            ids(sort_by_float_parallel(&values, key)),
+            ids(transform::sort_by_float(&values, key))
+        );
+        assert_eq!(
+            ids(order_by_float_parallel(&values, key, false)),
+            ids(transform::order_by_float(&values, key, false))
+        );
+        let collection = Collection::new(values.clone());
+        let bucket = |(i, _): &(u32, f64)| i % 7;
+        assert_eq!(
+            ids(collection.sort_by_cached_key_parallel(bucket)),
+            ids(transform::sort_by_cached_key(&values, bucket))
+        );
+    }
+
     #[test]
     fn test_empty_collection_parallel() {
         let empty: Vec<i32> = vec![];
@@ -412,7 +1306,7 @@ mod tests {
         let evens = filter_parallel(&empty, |x| x % 2 == 0);
         assert!(evens.is_empty());
 
-        let sum = reduce_parallel(&empty, |acc, x| acc + x, 0);
+        let sum = reduce_parallel(&empty, |acc, x| acc + x, |a, b| a + b, 0);
         assert_eq!(sum, 0);
 
         let first_even = find_parallel(&empty, |x| x % 2 == 0);
@@ -0,0 +1,850 @@
+/*!
+Extension traits for Lodash-RS.
+
+This module provides `LodashSliceExt`, `LodashIterExt` and `LodashMapExt`,
+which make the lodash methods available directly on slices and `Vec`s,
+iterators and `HashMap`s without wrapping them in a `Collection` or `chain`.
+All three are re-exported from the prelude, `LodashIterExt` as `_` so that
+only its methods come into scope. It is implemented for every iterator and
+shares method names such as `sorted_by` with `itertools::Itertools`; with
+both in scope, call those as `LodashIterExt::sorted_by(iter, key)`.
+
+Methods whose name would clash with a standard method use a different name:
+the sorting methods are `sorted_by`, because `[T]::sort_by` sorts in place,
+and iterators have no `partition`, because `Iterator::partition` already
+exists.
+*/
+
+use crate::collection::ordered_map::OrderedMap;
+use crate::collection::{operation, query, transform, window};
+use rand::seq::{IteratorRandom, SliceRandom};
+use rand::thread_rng;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::hash::{BuildHasher, Hash};
+
+/// Lodash methods on slices, and on `Vec` through deref.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::prelude::*;
+///
+/// let users = vec![("ann", 31), ("bob", 17), ("cy", 31)];
+/// assert_eq!(users.count_by_sorted(|u| u.1).into_iter().collect::<Vec<_>>(), vec![(17, 1), (31, 2)]);
+/// assert_eq!(users.sorted_by(|u| u.1)[0], ("bob", 17));
+/// assert_eq!(users.chunk(2).len(), 2);
+/// assert_eq!(users.uniq_by(|u| u.1).len(), 2);
+/// ```
+pub trait LodashSliceExt<T> {
+    /// Group elements by key. See [`transform::group_by`].
+    fn group_by<K, F>(&self, iteratee: F) -> HashMap<K, Vec<T>>
+    where
+        T: Clone,
+        K: Hash + Eq,
+        F: Fn(&T) -> K;
+
+    /// Group elements by key, in first-seen key order. See
+    /// [`transform::group_by_ordered`].
+    fn group_by_ordered<K, F>(&self, iteratee: F) -> OrderedMap<K, Vec<T>>
+    where
+        T: Clone,
+        K: Hash + Eq + Clone,
+        F: Fn(&T) -> K;
+
+    /// Group elements by key, sorted by key. See
+    /// [`transform::group_by_sorted`].
+    fn group_by_sorted<K, F>(&self, iteratee: F) -> BTreeMap<K, Vec<T>>
+    where
+        T: Clone,
+        K: Ord,
+        F: Fn(&T) -> K;
+
+    /// Count elements by key. See [`query::count_by`].
+    fn count_by<K, F>(&self, iteratee: F) -> HashMap<K, usize>
+    where
+        K: Hash + Eq,
+        F: Fn(&T) -> K;
+
+    /// Count elements by key, in first-seen key order. See
+    /// [`query::count_by_ordered`].
+    fn count_by_ordered<K, F>(&self, iteratee: F) -> OrderedMap<K, usize>
+    where
+        K: Hash + Eq + Clone,
+        F: Fn(&T) -> K;
+
+    /// Count elements by key, sorted by key. See [`query::count_by_sorted`].
+    fn count_by_sorted<K, F>(&self, iteratee: F) -> BTreeMap<K, usize>
+    where
+        K: Ord,
+        F: Fn(&T) -> K;
+
+    /// Key elements by `iteratee`, keeping the last element for each key.
+    /// See [`transform::key_by`].
+    fn key_by<K, F>(&self, iteratee: F) -> HashMap<K, T>
+    where
+        T: Clone,
+        K: Hash + Eq,
+        F: Fn(&T) -> K;
+
+    /// Key elements by `iteratee`, in first-seen key order. See
+    /// [`transform::key_by_ordered`].
+    fn key_by_ordered<K, F>(&self, iteratee: F) -> OrderedMap<K, T>
+    where
+        T: Clone,
+        K: Hash + Eq + Clone,
+        F: Fn(&T) -> K;
+
+    /// Key elements by `iteratee`, sorted by key. See
+    /// [`transform::key_by_sorted`].
+    fn key_by_sorted<K, F>(&self, iteratee: F) -> BTreeMap<K, T>
+    where
+        T: Clone,
+        K: Ord,
+        F: Fn(&T) -> K;
+
+    /// Split elements into those the predicate accepts and those it
+    /// rejects. See [`query::partition`].
+    fn partition<F>(&self, predicate: F) -> (Vec<T>, Vec<T>)
+    where
+        T: Clone,
+        F: Fn(&T) -> bool;
+
+    /// A sorted copy, ordered by the key produced by `iteratee`. See
+    /// [`transform::sort_by`].
+    fn sorted_by<K, F>(&self, iteratee: F) -> Vec<T>
+    where
+        T: Clone,
+        K: Ord,
+        F: Fn(&T) -> K;
+
+    /// A sorted copy in the given direction. See [`transform::order_by`].
+    fn order_by<K, F>(&self, iteratee: F, ascending: bool) -> Vec<T>
+    where
+        T: Clone,
+        K: Ord,
+        F: Fn(&T) -> K;
+
+    /// A random element. See [`operation::sample`].
+    fn sample(&self) -> Option<&T>;
+
+    /// Up to `n` random elements. See [`operation::sample_size`].
+    fn sample_size(&self, n: usize) -> Vec<T>
+    where
+        T: Clone;
+
+    /// A shuffled copy. See [`operation::shuffle`].
+    fn shuffled(&self) -> Vec<T>
+    where
+        T: Clone;
+
+    /// Chunks of `size` elements. See [`window::chunk`].
+    fn chunk(&self, size: usize) -> Vec<Vec<T>>
+    where
+        T: Clone;
+
+    /// The elements without duplicates. See [`transform::uniq`].
+    fn uniq(&self) -> Vec<T>
+    where
+        T: Hash + Eq + Clone;
+
+    /// The elements without duplicate keys. See [`transform::uniq_by`].
+    fn uniq_by<K, F>(&self, iteratee: F) -> Vec<T>
+    where
+        T: Clone,
+        K: Hash + Eq,
+        F: Fn(&T) -> K;
+}
+
+impl<T> LodashSliceExt<T> for [T] {
+    fn group_by<K, F>(&self, iteratee: F) -> HashMap<K, Vec<T>>
+    where
+        T: Clone,
+        K: Hash + Eq,
+        F: Fn(&T) -> K,
+    {
+        transform::group_by(self, iteratee)
+    }
+
+    fn group_by_ordered<K, F>(&self, iteratee: F) -> OrderedMap<K, Vec<T>>
+    where
+        T: Clone,
+        K: Hash + Eq + Clone,
+        F: Fn(&T) -> K,
+    {
+        transform::group_by_ordered(self, iteratee)
+    }
+
+    fn group_by_sorted<K, F>(&self, iteratee: F) -> BTreeMap<K, Vec<T>>
+    where
+        T: Clone,
+        K: Ord,
+        F: Fn(&T) -> K,
+    {
+        transform::group_by_sorted(self, iteratee)
+    }
+
+    fn count_by<K, F>(&self, iteratee: F) -> HashMap<K, usize>
+    where
+        K: Hash + Eq,
+        F: Fn(&T) -> K,
+    {
+        query::count_by(self, iteratee)
+    }
+
+    fn count_by_ordered<K, F>(&self, iteratee: F) -> OrderedMap<K, usize>
+    where
+        K: Hash + Eq + Clone,
+        F: Fn(&T) -> K,
+    {
+        query::count_by_ordered(self, iteratee)
+    }
+
+    fn count_by_sorted<K, F>(&self, iteratee: F) -> BTreeMap<K, usize>
+    where
+        K: Ord,
+        F: Fn(&T) -> K,
+    {
+        query::count_by_sorted(self, iteratee)
+    }
+
+    fn key_by<K, F>(&self, iteratee: F) -> HashMap<K, T>
+    where
+        T: Clone,
+        K: Hash + Eq,
+        F: Fn(&T) -> K,
+    {
+        transform::key_by(self, iteratee)
+    }
+
+    fn key_by_ordered<K, F>(&self, iteratee: F) -> OrderedMap<K, T>
+    where
+        T: Clone,
+        K: Hash + Eq + Clone,
+        F: Fn(&T) -> K,
+    {
+        transform::key_by_ordered(self, iteratee)
+    }
+
+    fn key_by_sorted<K, F>(&self, iteratee: F) -> BTreeMap<K, T>
+    where
+        T: Clone,
+        K: Ord,
+        F: Fn(&T) -> K,
+    {
+        transform::key_by_sorted(self, iteratee)
+    }
+
+    fn partition<F>(&self, predicate: F) -> (Vec<T>, Vec<T>)
+    where
+        T: Clone,
+        F: Fn(&T) -> bool,
+    {
+        query::partition(self, predicate)
+    }
+
+    fn sorted_by<K, F>(&self, iteratee: F) -> Vec<T>
+    where
+        T: Clone,
+        K: Ord,
+        F: Fn(&T) -> K,
+    {
+        transform::sort_by(self, iteratee)
+    }
+
+    fn order_by<K, F>(&self, iteratee: F, ascending: bool) -> Vec<T>
+    where
+        T: Clone,
+        K: Ord,
+        F: Fn(&T) -> K,
+    {
+        transform::order_by(self, iteratee, ascending)
+    }
+
+    fn sample(&self) -> Option<&T> {
+        operation::sample(self)
+    }
+
+    fn sample_size(&self, n: usize) -> Vec<T>
+    where
+        T: Clone,
+    {
+        operation::sample_size(self, n)
+    }
+
+    fn shuffled(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        operation::shuffle(self)
+    }
+
+    fn chunk(&self, size: usize) -> Vec<Vec<T>>
+    where
+        T: Clone,
+    {
+        window::chunk(self, size)
+    }
+
+    fn uniq(&self) -> Vec<T>
+    where
+        T: Hash + Eq + Clone,
+    {
+        transform::uniq(self)
+    }
+
+    fn uniq_by<K, F>(&self, iteratee: F) -> Vec<T>
+    where
+        T: Clone,
+        K: Hash + Eq,
+        F: Fn(&T) -> K,
+    {
+        transform::uniq_by(self, iteratee)
+    }
+}
+
+/// Feed each item and its key to `step`, threading `map` through.
+fn fold_keyed<I, K, F, M, G>(items: I, key: F, mut map: M, mut step: G) -> M
+where
+    I: Iterator,
+    F: Fn(&I::Item) -> K,
+    G: FnMut(&mut M, K, I::Item),
+{
+    for item in items {
+        let item_key = key(&item);
+        step(&mut map, item_key, item);
+    }
+    map
+}
+
+/// Lodash methods on any iterator. Items are moved into the result, so they
+/// need not be `Clone`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::prelude::*;
+///
+/// let words = "the quick brown fox jumps over the lazy dog".split(' ');
+/// let by_length = words.clone().group_by_sorted(|w| w.len());
+/// assert_eq!(by_length[&5], vec!["quick", "brown", "jumps"]);
+/// assert_eq!(words.clone().uniq().len(), 8);
+/// assert_eq!((1..=5).chunk(2), vec![vec![1, 2], vec![3, 4], vec![5]]);
+/// ```
+pub trait LodashIterExt: Iterator + Sized {
+    /// Group items by key.
+    fn group_by<K, F>(self, iteratee: F) -> HashMap<K, Vec<Self::Item>>
+    where
+        K: Hash + Eq,
+        F: Fn(&Self::Item) -> K,
+    {
+        fold_keyed(self, iteratee, HashMap::new(), |groups, key, item| {
+            groups.entry(key).or_insert_with(Vec::new).push(item);
+        })
+    }
+
+    /// Group items by key, in first-seen key order.
+    fn group_by_ordered<K, F>(self, iteratee: F) -> OrderedMap<K, Vec<Self::Item>>
+    where
+        K: Hash + Eq + Clone,
+        F: Fn(&Self::Item) -> K,
+    {
+        fold_keyed(
+            self,
+            iteratee,
+            OrderedMap::new(),
+            |groups, key, item| match groups.get_mut(&key) {
+                Some(group) => group.push(item),
+                None => {
+                    groups.insert(key, vec![item]);
+                }
+            },
+        )
+    }
+
+    /// Group items by key, sorted by key.
+    fn group_by_sorted<K, F>(self, iteratee: F) -> BTreeMap<K, Vec<Self::Item>>
+    where
+        K: Ord,
+        F: Fn(&Self::Item) -> K,
+    {
+        fold_keyed(self, iteratee, BTreeMap::new(), |groups, key, item| {
+            groups.entry(key).or_insert_with(Vec::new).push(item);
+        })
+    }
+
+    /// Count items by key.
+    fn count_by<K, F>(self, iteratee: F) -> HashMap<K, usize>
+    where
+        K: Hash + Eq,
+        F: Fn(&Self::Item) -> K,
+    {
+        fold_keyed(self, iteratee, HashMap::new(), |counts, key, _| {
+            *counts.entry(key).or_insert(0) += 1;
+        })
+    }
+
+    /// Count items by key, in first-seen key order.
+    fn count_by_ordered<K, F>(self, iteratee: F) -> OrderedMap<K, usize>
+    where
+        K: Hash + Eq + Clone,
+        F: Fn(&Self::Item) -> K,
+    {
+        fold_keyed(
+            self,
+            iteratee,
+            OrderedMap::new(),
+            |counts, key, _| match counts.get_mut(&key) {
+                Some(count) => *count += 1,
+                None => {
+                    counts.insert(key, 1);
+                }
+            },
+        )
+    }
+
+    /// Count items by key, sorted by key.
+    fn count_by_sorted<K, F>(self, iteratee: F) -> BTreeMap<K, usize>
+    where
+        K: Ord,
+        F: Fn(&Self::Item) -> K,
+    {
+        fold_keyed(self, iteratee, BTreeMap::new(), |counts, key, _| {
+            *counts.entry(key).or_insert(0) += 1;
+        })
+    }
+
+    /// Key items by `iteratee`, keeping the last item for each key.
+    fn key_by<K, F>(self, iteratee: F) -> HashMap<K, Self::Item>
+    where
+        K: Hash + Eq,
+        F: Fn(&Self::Item) -> K,
+    {
+        fold_keyed(self, iteratee, HashMap::new(), |keyed, key, item| {
+            keyed.insert(key, item);
+        })
+    }
+
+    /// Key items by `iteratee`, in first-seen key order.
+    fn key_by_ordered<K, F>(self, iteratee: F) -> OrderedMap<K, Self::Item>
+    where
+        K: Hash + Eq + Clone,
+        F: Fn(&Self::Item) -> K,
+    {
+        fold_keyed(self, iteratee, OrderedMap::new(), |keyed, key, item| {
+            keyed.insert(key, item);
+        })
+    }
+
+    /// Key items by `iteratee`, sorted by key.
+    fn key_by_sorted<K, F>(self, iteratee: F) -> BTreeMap<K, Self::Item>
+    where
+        K: Ord,
+        F: Fn(&Self::Item) -> K,
+    {
+        fold_keyed(self, iteratee, BTreeMap::new(), |keyed, key, item| {
+            keyed.insert(key, item);
+        })
+    }
+
+    /// Collect the items, stably sorted by the key produced by `iteratee`.
+    fn sorted_by<K, F>(self, iteratee: F) -> Vec<Self::Item>
+    where
+        K: Ord,
+        F: Fn(&Self::Item) -> K,
+    {
+        let mut sorted: Vec<Self::Item> = self.collect();
+        sorted.sort_by_key(iteratee);
+        sorted
+    }
+
+    /// Collect the items, stably sorted in the given direction.
+    fn order_by<K, F>(self, iteratee: F, ascending: bool) -> Vec<Self::Item>
+    where
+        K: Ord,
+        F: Fn(&Self::Item) -> K,
+    {
+        let mut sorted: Vec<Self::Item> = self.collect();
+        if ascending {
+            sorted.sort_by_key(iteratee);
+        } else {
+            sorted.sort_by_key(|item| std::cmp::Reverse(iteratee(item)));
+        }
+        sorted
+    }
+
+    /// A random item, chosen in a single pass.
+    fn sample(self) -> Option<Self::Item> {
+        IteratorRandom::choose(self, &mut thread_rng())
+    }
+
+    /// Up to `n` random items, in random order.
+    fn sample_size(self, n: usize) -> Vec<Self::Item> {
+        let mut samples = self.shuffled();
+        samples.truncate(n);
+        samples
+    }
+
+    /// Collect the items in random order.
+    fn shuffled(self) -> Vec<Self::Item> {
+        let mut shuffled: Vec<Self::Item> = self.collect();
+        shuffled.shuffle(&mut thread_rng());
+        shuffled
+    }
+
+    /// Collect the items into chunks of `size`. The last chunk may be
+    /// shorter. Returns an empty vector if `size` is zero.
+    fn chunk(self, size: usize) -> Vec<Vec<Self::Item>> {
+        if size == 0 {
+            return Vec::new();
+        }
+        let mut chunks = Vec::new();
+        let mut current = Vec::with_capacity(size);
+        for item in self {
+            current.push(item);
+            if current.len() == size {
+                chunks.push(std::mem::replace(&mut current, Vec::with_capacity(size)));
+            }
+        }
+        if !current.is_empty() {
+            chunks.push(current);
+        }
+        chunks
+    }
+
+    /// Collect the items without duplicates, keeping first occurrences.
+    fn uniq(self) -> Vec<Self::Item>
+    where
+        Self::Item: Hash + Eq,
+    {
+        let items: Vec<Self::Item> = self.collect();
+        let keep: Vec<bool> = {
+            let mut seen = HashSet::new();
+            items.iter().map(|item| seen.insert(item)).collect()
+        };
+        items
+            .into_iter()
+            .zip(keep)
+            .filter_map(|(item, first)| first.then_some(item))
+            .collect()
+    }
+
+    /// Collect the items without duplicate keys, keeping first occurrences.
+    fn uniq_by<K, F>(self, iteratee: F) -> Vec<Self::Item>
+    where
+        K: Hash + Eq,
+        F: Fn(&Self::Item) -> K,
+    {
+        let mut seen = HashSet::new();
+        self.filter(|item| seen.insert(iteratee(item))).collect()
+    }
+}
+
+impl<I: Iterator> LodashIterExt for I {}
+
+/// Lodash methods on `HashMap`. As in Lodash, the collection methods
+/// iterate over the values; `HashMap` iteration order is unspecified, so
+/// use the `*_sorted` methods or `sorted_by` when order matters.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::prelude::*;
+/// use std::collections::HashMap;
+///
+/// let ages = HashMap::from([("ann", 31), ("bob", 17), ("cy", 45)]);
+/// assert_eq!(ages.sorted_by(|age| *age), vec![17, 31, 45]);
+/// assert_eq!(ages.count_by(|age| *age >= 18)[&true], 2);
+/// assert_eq!(ages.pick_by(|_, age| *age > 40), HashMap::from([("cy", 45)]));
+/// assert_eq!(ages.map_values(|age| age / 10)[&"ann"], 3);
+/// ```
+pub trait LodashMapExt<K, V> {
+    /// Group the values by key.
+    fn group_by<G, F>(&self, iteratee: F) -> HashMap<G, Vec<V>>
+    where
+        V: Clone,
+        G: Hash + Eq,
+        F: Fn(&V) -> G;
+
+    /// Group the values by key, sorted by key.
+    fn group_by_sorted<G, F>(&self, iteratee: F) -> BTreeMap<G, Vec<V>>
+    where
+        V: Clone,
+        G: Ord,
+        F: Fn(&V) -> G;
+
+    /// Count the values by key.
+    fn count_by<G, F>(&self, iteratee: F) -> HashMap<G, usize>
+    where
+        G: Hash + Eq,
+        F: Fn(&V) -> G;
+
+    /// Count the values by key, sorted by key.
+    fn count_by_sorted<G, F>(&self, iteratee: F) -> BTreeMap<G, usize>
+    where
+        G: Ord,
+        F: Fn(&V) -> G;
+
+    /// Key the values by `iteratee`. Which value wins for a repeated key is
+    /// unspecified.
+    fn key_by<G, F>(&self, iteratee: F) -> HashMap<G, V>
+    where
+        V: Clone,
+        G: Hash + Eq,
+        F: Fn(&V) -> G;
+
+    /// Split the values into those the predicate accepts and those it
+    /// rejects.
+    fn partition<F>(&self, predicate: F) -> (Vec<V>, Vec<V>)
+    where
+        V: Clone,
+        F: Fn(&V) -> bool;
+
+    /// The values, stably sorted by the key produced by `iteratee`.
+    fn sorted_by<G, F>(&self, iteratee: F) -> Vec<V>
+    where
+        V: Clone,
+        G: Ord,
+        F: Fn(&V) -> G;
+
+    /// The values, sorted in the given direction.
+    fn order_by<G, F>(&self, iteratee: F, ascending: bool) -> Vec<V>
+    where
+        V: Clone,
+        G: Ord,
+        F: Fn(&V) -> G;
+
+    /// A random value.
+    fn sample(&self) -> Option<&V>;
+
+    /// The values without duplicates.
+    fn uniq(&self) -> Vec<V>
+    where
+        V: Hash + Eq + Clone;
+
+    /// A map with the same keys and the values produced by `iteratee`.
+    fn map_values<U, F>(&self, iteratee: F) -> HashMap<K, U>
+    where
+        K: Hash + Eq + Clone,
+        F: Fn(&V) -> U;
+
+    /// A map with the keys produced by `iteratee` and the same values.
+    fn map_keys<J, F>(&self, iteratee: F) -> HashMap<J, V>
+    where
+        V: Clone,
+        J: Hash + Eq,
+        F: Fn(&K, &V) -> J;
+
+    /// The entries the predicate returns truthy for.
+    fn pick_by<F>(&self, predicate: F) -> HashMap<K, V>
+    where
+        K: Hash + Eq + Clone,
+        V: Clone,
+        F: Fn(&K, &V) -> bool;
+
+    /// The entries the predicate returns falsy for.
+    fn omit_by<F>(&self, predicate: F) -> HashMap<K, V>
+    where
+        K: Hash + Eq + Clone,
+        V: Clone,
+        F: Fn(&K, &V) -> bool;
+
+    /// A map from values to keys. Which key wins for a repeated value is
+    /// unspecified.
+    fn invert(&self) -> HashMap<V, K>
+    where
+        K: Clone,
+        V: Hash + Eq + Clone;
+}
+
+impl<K, V, H> LodashMapExt<K, V> for HashMap<K, V, H>
+where
+    H: BuildHasher,
+{
+    fn group_by<G, F>(&self, iteratee: F) -> HashMap<G, Vec<V>>
+    where
+        V: Clone,
+        G: Hash + Eq,
+        F: Fn(&V) -> G,
+    {
+        transform::group_by(self.values(), iteratee)
+    }
+
+    fn group_by_sorted<G, F>(&self, iteratee: F) -> BTreeMap<G, Vec<V>>
+    where
+        V: Clone,
+        G: Ord,
+        F: Fn(&V) -> G,
+    {
+        transform::group_by_sorted(self.values(), iteratee)
+    }
+
+    fn count_by<G, F>(&self, iteratee: F) -> HashMap<G, usize>
+    where
+        G: Hash + Eq,
+        F: Fn(&V) -> G,
+    {
+        query::count_by(self.values(), iteratee)
+    }
+
+    fn count_by_sorted<G, F>(&self, iteratee: F) -> BTreeMap<G, usize>
+    where
+        G: Ord,
+        F: Fn(&V) -> G,
+    {
+        query::count_by_sorted(self.values(), iteratee)
+    }
+
+    fn key_by<G, F>(&self, iteratee: F) -> HashMap<G, V>
+    where
+        V: Clone,
+        G: Hash + Eq,
+        F: Fn(&V) -> G,
+    {
+        transform::key_by(self.values(), iteratee)
+    }
+
+    fn partition<F>(&self, predicate: F) -> (Vec<V>, Vec<V>)
+    where
+        V: Clone,
+        F: Fn(&V) -> bool,
+    {
+        query::partition(self.values(), predicate)
+    }
+
+    fn sorted_by<G, F>(&self, iteratee: F) -> Vec<V>
+    where
+        V: Clone,
+        G: Ord,
+        F: Fn(&V) -> G,
+    {
+        transform::sort_by(self.values(), iteratee)
+    }
+
+    fn order_by<G, F>(&self, iteratee: F, ascending: bool) -> Vec<V>
+    where
+        V: Clone,
+        G: Ord,
+        F: Fn(&V) -> G,
+    {
+        transform::order_by(self.values(), iteratee, ascending)
+    }
+
+    fn sample(&self) -> Option<&V> {
+        operation::sample(self.values())
+    }
+
+    fn uniq(&self) -> Vec<V>
+    where
+        V: Hash + Eq + Clone,
+    {
+        transform::uniq(self.values())
+    }
+
+    fn map_values<U, F>(&self, iteratee: F) -> HashMap<K, U>
+    where
+        K: Hash + Eq + Clone,
+        F: Fn(&V) -> U,
+    {
+        self.iter()
+            .map(|(key, value)| (key.clone(), iteratee(value)))
+            .collect()
+    }
+
+    fn map_keys<J, F>(&self, iteratee: F) -> HashMap<J, V>
+    where
+        V: Clone,
+        J: Hash + Eq,
+        F: Fn(&K, &V) -> J,
+    {
+        self.iter()
+            .map(|(key, value)| (iteratee(key, value), value.clone()))
+            .collect()
+    }
+
+    fn pick_by<F>(&self, predicate: F) -> HashMap<K, V>
+    where
+        K: Hash + Eq + Clone,
+        V: Clone,
+        F: Fn(&K, &V) -> bool,
+    {
+        self.iter()
+            .filter(|(key, value)| predicate(key, value))
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect()
+    }
+
+    fn omit_by<F>(&self, predicate: F) -> HashMap<K, V>
+    where
+        K: Hash + Eq + Clone,
+        V: Clone,
+        F: Fn(&K, &V) -> bool,
+    {
+        self.pick_by(|key, value| !predicate(key, value))
+    }
+
+    fn invert(&self) -> HashMap<V, K>
+    where
+        K: Clone,
+        V: Hash + Eq + Clone,
+    {
+        self.iter()
+            .map(|(key, value)| (value.clone(), key.clone()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slice_ext_matches_free_functions() {
+        let numbers = vec![5, 3, 8, 3, 1];
+        assert_eq!(
+            numbers.group_by(|n| n % 2),
+            transform::group_by(&numbers, |n| n % 2)
+        );
+        assert_eq!(
+            numbers.key_by_ordered(|n| n % 2).as_slice(),
+            &[(1, 1), (0, 8)]
+        );
+        assert_eq!(numbers.partition(|n| *n > 3), (vec![5, 8], vec![3, 3, 1]));
+        assert_eq!(numbers.sorted_by(|n| *n), vec![1, 3, 3, 5, 8]);
+        assert_eq!(numbers.order_by(|n| *n, false), vec![8, 5, 3, 3, 1]);
+        assert_eq!(numbers.uniq(), vec![5, 3, 8, 1]);
+        assert!(numbers.contains(numbers.sample().unwrap()));
+        assert_eq!(numbers[..2].shuffled().len(), 2);
+    }
+
+    #[test]
+    fn test_iter_ext_moves_items() {
+        // Deliberately not `Clone`.
+        #[derive(Debug, PartialEq, Eq, Hash)]
+        struct Token(u8);
+
+        let tokens = vec![Token(1), Token(2), Token(1), Token(3)];
+        let counts = tokens.iter().count_by_ordered(|t| t.0);
+        assert_eq!(counts.as_slice(), &[(1, 2), (2, 1), (3, 1)]);
+
+        let groups = tokens.into_iter().group_by_sorted(|t| t.0 % 2);
+        assert_eq!(groups[&1], vec![Token(1), Token(1), Token(3)]);
+
+        let unique = vec![Token(2), Token(2), Token(7)].into_iter().uniq();
+        assert_eq!(unique, vec![Token(2), Token(7)]);
+        assert_eq!((0..7).chunk(3).last(), Some(&vec![6]));
+        assert!((0..3).chunk(0).is_empty());
+        assert_eq!((0..10).sample_size(4).len(), 4);
+        assert_eq!((0..0).sample(), None);
+    }
+
+    #[test]
+    fn test_map_ext() {
+        let stock = HashMap::from([("fig", 3), ("kiwi", 0), ("pear", 3)]);
+        assert_eq!(stock.group_by_sorted(|n| *n > 0)[&true], vec![3, 3]);
+        assert_eq!(stock.uniq().len(), 2);
+        assert_eq!(stock.omit_by(|_, n| *n > 0), HashMap::from([("kiwi", 0)]));
+        assert_eq!(stock.map_keys(|k, _| k.len()).len(), 2);
+        assert_eq!(stock.invert()[&0], "kiwi");
+        assert_eq!(stock.order_by(|n| *n, false)[2], 0);
+    }
+}
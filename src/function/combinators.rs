@@ -0,0 +1,584 @@
+/*!
+Function combinators for Lodash-RS.
+
+This module provides Lodash's higher-order helpers such as `once`, `negate`,
+`flow`, `partial` and `curry`.
+
+The combinators that take iteratees produce closures of the same shape as the
+crate's [`Predicate`](crate::utils::Predicate), [`Mapper`](crate::utils::Mapper)
+and [`Reducer`](crate::utils::Reducer) traits, so their results can be passed
+straight to `filter`, `partition`, `map` or `reduce`.
+*/
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, OnceLock, PoisonError};
+
+/// Create a function that is restricted to invoking `func` once. Repeat calls
+/// return the value of the first invocation.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::function::once;
+///
+/// let init = once(|x: &i32| x * 10);
+/// assert_eq!(init(&1), 10);
+/// assert_eq!(init(&2), 10);
+/// ```
+pub fn once<T, U, F>(func: F) -> impl Fn(&T) -> U
+where
+    U: Clone,
+    F: Fn(&T) -> U,
+{
+    let result = OnceLock::new();
+    move |value| result.get_or_init(|| func(value)).clone()
+}
+
+/// Create a function that invokes `func` while it's called less than `n`
+/// times. Subsequent calls return the result of the last invocation.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::function::before;
+///
+/// let first_two = before(3, |x: &i32| x * 2);
+/// assert_eq!(first_two(&1), Some(2));
+/// assert_eq!(first_two(&2), Some(4));
+/// assert_eq!(first_two(&3), Some(4));
+/// ```
+pub fn before<T, U, F>(n: usize, func: F) -> impl Fn(&T) -> Option<U>
+where
+    U: Clone,
+    F: Fn(&T) -> U,
+{
+    let state = Mutex::new((0_usize, None));
+    move |value| {
+        let mut state = state.lock().unwrap_or_else(PoisonError::into_inner);
+        let (calls, last) = &mut *state;
+        *calls += 1;
+        if *calls < n {
+            *last = Some(func(value));
+        }
+        last.clone()
+    }
+}
+
+/// Create a function that invokes `func` once it's called `n` or more times.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::function::after;
+///
+/// let third_onwards = after(3, |x: &i32| x * 2);
+/// assert_eq!(third_onwards(&1), None);
+/// assert_eq!(third_onwards(&2), None);
+/// assert_eq!(third_onwards(&3), Some(6));
+/// ```
+pub fn after<T, U, F>(n: usize, func: F) -> impl Fn(&T) -> Option<U>
+where
+    F: Fn(&T) -> U,
+{
+    let calls = AtomicUsize::new(0);
+    move |value| {
+        if calls.fetch_add(1, Ordering::Relaxed) + 1 >= n {
+            Some(func(value))
+        } else {
+            None
+        }
+    }
+}
+
+/// Create a predicate that negates the result of `predicate`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::iteration::filter;
+/// use rust_lodash::function::negate;
+///
+/// let odds = filter(&[1, 2, 3, 4, 5], negate(|x: &i32| x % 2 == 0));
+/// assert_eq!(odds, vec![1, 3, 5]);
+/// ```
+pub fn negate<T, F>(predicate: F) -> impl Fn(&T) -> bool
+where
+    F: Fn(&T) -> bool,
+{
+    move |value| !predicate(value)
+}
+
+/// Create a mapper that returns the result of invoking `first` and then
+/// `second` with the result of `first`.
+///
+/// Longer pipelines are built by nesting calls.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::iteration::map;
+/// use rust_lodash::function::flow;
+///
+/// let describe = flow(|x: &i32| x * 2, |x: &i32| format!("#{x}"));
+/// assert_eq!(map(&[1, 2], describe), vec!["#2", "#4"]);
+/// ```
+pub fn flow<T, U, V, F, G>(first: F, second: G) -> impl Fn(&T) -> V
+where
+    F: Fn(&T) -> U,
+    G: Fn(&U) -> V,
+{
+    move |value| second(&first(value))
+}
+
+/// This method is like `flow` except that it invokes the given functions
+/// from right to left.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::function::flow_right;
+///
+/// let describe = flow_right(|x: &i32| format!("#{x}"), |x: &i32| x * 2);
+/// assert_eq!(describe(&3), "#6");
+/// ```
+pub fn flow_right<T, U, V, F, G>(second: G, first: F) -> impl Fn(&T) -> V
+where
+    F: Fn(&T) -> U,
+    G: Fn(&U) -> V,
+{
+    flow(first, second)
+}
+
+/// Create a function that invokes `func` with `bound` prepended to the
+/// arguments it receives.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::iteration::filter;
+/// use rust_lodash::function::partial;
+///
+/// let at_least = |min: &i32, x: &i32| x >= min;
+/// assert_eq!(filter(&[1, 5, 3, 7], partial(at_least, 4)), vec![5, 7]);
+/// ```
+pub fn partial<A, T, U, F>(func: F, bound: A) -> impl Fn(&T) -> U
+where
+    F: Fn(&A, &T) -> U,
+{
+    move |value| func(&bound, value)
+}
+
+/// This method is like `partial` except that `bound` is appended to the
+/// arguments it receives.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::function::partial_right;
+///
+/// let divide = |x: &f64, by: &f64| x / by;
+/// let halve = partial_right(divide, 2.0);
+/// assert_eq!(halve(&9.0), 4.5);
+/// ```
+pub fn partial_right<A, T, U, F>(func: F, bound: A) -> impl Fn(&T) -> U
+where
+    F: Fn(&T, &A) -> U,
+{
+    move |value| func(value, &bound)
+}
+
+/// Create a curried version of a function of two arguments.
+///
+/// The curried functions can be called repeatedly, so every argument must be
+/// `Clone`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::function::curry2;
+///
+/// let add = curry2(|a: i32, b: i32| a + b);
+/// let add_five = add(5);
+/// assert_eq!(add_five(1), 6);
+/// assert_eq!(add_five(2), 7);
+/// ```
+pub fn curry2<A, B, R, F>(func: F) -> impl Fn(A) -> Box<dyn Fn(B) -> R>
+where
+    A: Clone + 'static,
+    F: Fn(A, B) -> R + Clone + 'static,
+{
+    move |a| {
+        let func = func.clone();
+        Box::new(move |b| func(a.clone(), b))
+    }
+}
+
+/// Create a curried version of a function of three arguments.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::function::curry3;
+///
+/// let volume = curry3(|a: i32, b: i32, c: i32| a * b * c);
+/// assert_eq!(volume(2)(3)(4), 24);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn curry3<A, B, C, R, F>(func: F) -> impl Fn(A) -> Box<dyn Fn(B) -> Box<dyn Fn(C) -> R>>
+where
+    A: Clone + 'static,
+    B: Clone + 'static,
+    C: 'static,
+    R: 'static,
+    F: Fn(A, B, C) -> R + Clone + 'static,
+{
+    move |a| {
+        let func = func.clone();
+        Box::new(curry2(move |b, c| func(a.clone(), b, c)))
+    }
+}
+
+/// Create a curried version of a function of four arguments.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::function::curry4;
+///
+/// let sum = curry4(|a: i32, b: i32, c: i32, d: i32| a + b + c + d);
+/// assert_eq!(sum(1)(2)(3)(4), 10);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn curry4<A, B, C, D, R, F>(
+    func: F,
+) -> impl Fn(A) -> Box<dyn Fn(B) -> Box<dyn Fn(C) -> Box<dyn Fn(D) -> R>>>
+where
+    A: Clone + 'static,
+    B: Clone + 'static,
+    C: Clone + 'static,
+    D: 'static,
+    R: 'static,
+    F: Fn(A, B, C, D) -> R + Clone + 'static,
+{
+    move |a| {
+        let func = func.clone();
+        Box::new(curry3(move |b, c, d| func(a.clone(), b, c, d)))
+    }
+}
+
+/// Create a curried version of a function of five arguments.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::function::curry5;
+///
+/// let sum = curry5(|a: i32, b: i32, c: i32, d: i32, e: i32| a + b + c + d + e);
+/// assert_eq!(sum(1)(2)(3)(4)(5), 15);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn curry5<A, B, C, D, E, R, F>(
+    func: F,
+) -> impl Fn(A) -> Box<dyn Fn(B) -> Box<dyn Fn(C) -> Box<dyn Fn(D) -> Box<dyn Fn(E) -> R>>>>
+where
+    A: Clone + 'static,
+    B: Clone + 'static,
+    C: Clone + 'static,
+    D: Clone + 'static,
+    E: 'static,
+    R: 'static,
+    F: Fn(A, B, C, D, E) -> R + Clone + 'static,
+{
+    move |a| {
+        let func = func.clone();
+        Box::new(curry4(move |b, c, d, e| func(a.clone(), b, c, d, e)))
+    }
+}
+
+/// Create a curried version of a function of six arguments.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::function::curry6;
+///
+/// let sum = curry6(|a: i32, b: i32, c: i32, d: i32, e: i32, f: i32| a + b + c + d + e + f);
+/// assert_eq!(sum(1)(2)(3)(4)(5)(6), 21);
+/// ```
+#[allow(clippy::type_complexity, clippy::many_single_char_names)]
+pub fn curry6<A, B, C, D, E, G, R, F>(
+    func: F,
+) -> impl Fn(A) -> Box<dyn Fn(B) -> Box<dyn Fn(C) -> Box<dyn Fn(D) -> Box<dyn Fn(E) -> Box<dyn Fn(G) -> R>>>>>
+where
+    A: Clone + 'static,
+    B: Clone + 'static,
+    C: Clone + 'static,
+    D: Clone + 'static,
+    E: Clone + 'static,
+    G: 'static,
+    R: 'static,
+    F: Fn(A, B, C, D, E, G) -> R + Clone + 'static,
+{
+    move |a| {
+        let func = func.clone();
+        Box::new(curry5(move |b, c, d, e, g| func(a.clone(), b, c, d, e, g)))
+    }
+}
+
+/// Create a mapper that invokes each of `mappers` and returns their results.
+///
+/// Closures of different types can be combined by boxing them.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::function::over;
+///
+/// let bounds = over(vec![
+///     Box::new(|v: &Vec<i32>| *v.iter().min().unwrap()) as Box<dyn Fn(&Vec<i32>) -> i32>,
+///     Box::new(|v: &Vec<i32>| *v.iter().max().unwrap()),
+/// ]);
+/// assert_eq!(bounds(&vec![3, 1, 4]), vec![1, 4]);
+/// ```
+pub fn over<T, U, F>(mappers: impl IntoIterator<Item = F>) -> impl Fn(&T) -> Vec<U>
+where
+    F: Fn(&T) -> U,
+{
+    let mappers: Vec<F> = mappers.into_iter().collect();
+    move |value| mappers.iter().map(|mapper| mapper(value)).collect()
+}
+
+/// Create a predicate that checks if all of `predicates` return true.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::iteration::filter;
+/// use rust_lodash::function::over_every;
+///
+/// let checks: Vec<Box<dyn Fn(&i32) -> bool>> = vec![Box::new(|x| *x > 1), Box::new(|x| x % 2 == 1)];
+/// assert_eq!(filter(&[1, 2, 3, 5], over_every(checks)), vec![3, 5]);
+/// ```
+pub fn over_every<T, F>(predicates: impl IntoIterator<Item = F>) -> impl Fn(&T) -> bool
+where
+    F: Fn(&T) -> bool,
+{
+    let predicates: Vec<F> = predicates.into_iter().collect();
+    move |value| predicates.iter().all(|predicate| predicate(value))
+}
+
+/// Create a predicate that checks if any of `predicates` return true.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::query::partition;
+/// use rust_lodash::function::over_some;
+///
+/// let checks: Vec<Box<dyn Fn(&i32) -> bool>> = vec![Box::new(|x| *x < 2), Box::new(|x| *x > 4)];
+/// let (outside, inside) = partition(&[1, 2, 3, 5], over_some(checks));
+/// assert_eq!(outside, vec![1, 5]);
+/// assert_eq!(inside, vec![2, 3]);
+/// ```
+pub fn over_some<T, F>(predicates: impl IntoIterator<Item = F>) -> impl Fn(&T) -> bool
+where
+    F: Fn(&T) -> bool,
+{
+    let predicates: Vec<F> = predicates.into_iter().collect();
+    move |value| predicates.iter().any(|predicate| predicate(value))
+}
+
+/// Create a function that invokes `func` with its arguments rearranged
+/// according to `indexes`: the argument at `indexes[0]` is passed first, the
+/// argument at `indexes[1]` second, and so on.
+///
+/// As in Lodash, arguments past the end of `indexes` keep their positions.
+/// Since Rust has no `undefined`, an index outside the argument list leaves
+/// the argument at that position unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::function::rearg;
+///
+/// let join = rearg([2, 0, 1], |args: &[&str]| args.concat());
+/// assert_eq!(join(&["b", "c", "a"]), "abc");
+/// assert_eq!(join(&["b", "c", "a", "d"]), "abcd");
+/// ```
+pub fn rearg<T, U, F>(indexes: impl IntoIterator<Item = usize>, func: F) -> impl Fn(&[T]) -> U
+where
+    T: Clone,
+    F: Fn(&[T]) -> U,
+{
+    let indexes: Vec<usize> = indexes.into_iter().collect();
+    move |args| {
+        let mut reordered = args.to_vec();
+        for (position, &index) in indexes.iter().enumerate().take(args.len()) {
+            if let Some(arg) = args.get(index) {
+                reordered[position] = arg.clone();
+            }
+        }
+        func(&reordered)
+    }
+}
+
+/// Create a mapper that accepts one argument by reference and passes a clone
+/// of it to `func`, so functions taking their argument by value can be used
+/// as iteratees.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::iteration::map;
+/// use rust_lodash::function::unary;
+///
+/// assert_eq!(map(&[-1, 2, -3], unary(i32::abs)), vec![1, 2, 3]);
+/// ```
+pub fn unary<T, U, F>(func: F) -> impl Fn(&T) -> U
+where
+    T: Clone,
+    F: Fn(T) -> U,
+{
+    move |value| func(value.clone())
+}
+
+/// Create a reducer that invokes `func` with its arguments swapped, so a
+/// function written as `(element, accumulator)` can be passed to `reduce`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::iteration::reduce;
+/// use rust_lodash::function::flip_reducer;
+///
+/// let push = |x: &i32, mut acc: Vec<i32>| {
+///     acc.insert(0, *x);
+///     acc
+/// };
+/// assert_eq!(reduce(&[1, 2, 3], flip_reducer(push), Vec::new()), vec![3, 2, 1]);
+/// ```
+pub fn flip_reducer<T, U, F>(func: F) -> impl Fn(U, &T) -> U
+where
+    F: Fn(&T, U) -> U,
+{
+    move |acc, value| func(value, acc)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::type_conv::{Mapper, Predicate, Reducer};
+    use std::sync::atomic::AtomicUsize;
+
+    fn assert_predicate<T>(predicate: &impl Predicate<T>, value: &T) -> bool {
+        predicate.apply(value)
+    }
+
+    #[test]
+    fn test_once() {
+        let calls = AtomicUsize::new(0);
+        let init = once(|x: &i32| {
+            calls.fetch_add(1, Ordering::Relaxed);
+            *x
+        });
+        assert_eq!(init(&1), 1);
+        assert_eq!(init(&2), 1);
+        assert_eq!(calls.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn test_before_and_after() {
+        let never = before(1, |x: &i32| *x);
+        assert_eq!(never(&1), None);
+
+        let first_two = before(3, |x: &i32| *x);
+        let results: Vec<_> = (1..=4).map(|x| first_two(&x)).collect();
+        assert_eq!(results, vec![Some(1), Some(2), Some(2), Some(2)]);
+
+        let from_second = after(2, |x: &i32| *x);
+        let results: Vec<_> = (1..=3).map(|x| from_second(&x)).collect();
+        assert_eq!(results, vec![None, Some(2), Some(3)]);
+    }
+
+    #[test]
+    fn test_negate() {
+        let is_even = |x: &i32| x % 2 == 0;
+        let is_odd = negate(is_even);
+        assert!(assert_predicate(&is_odd, &3));
+        assert!(!assert_predicate(&is_odd, &4));
+    }
+
+    #[test]
+    fn test_flow() {
+        let add_then_square = flow(|x: &i32| x + 1, |x: &i32| x * x);
+        assert_eq!(Mapper::apply(&add_then_square, &2), 9);
+
+        let square_then_add = flow_right(|x: &i32| x + 1, |x: &i32| x * x);
+        assert_eq!(square_then_add(&2), 5);
+
+        let nested = flow(flow(|x: &i32| x + 1, |x: &i32| x * 2), |x: &i32| x.to_string());
+        assert_eq!(nested(&1), "4");
+    }
+
+    #[test]
+    fn test_partial() {
+        let greet = partial(|greeting: &String, name: &&str| format!("{greeting} {name}"), "hi".to_string());
+        assert_eq!(greet(&"fred"), "hi fred");
+
+        let subtract = partial_right(|x: &i32, y: &i32| x - y, 1);
+        assert_eq!(subtract(&5), 4);
+    }
+
+    #[test]
+    fn test_curry() {
+        let join = curry3(|a: &'static str, b: &'static str, c: &'static str| format!("{a}{b}{c}"));
+        let with_a = join("a");
+        assert_eq!(with_a("b")("c"), "abc");
+        assert_eq!(with_a("x")("y"), "axy");
+
+        let sum = curry6(|a: u8, b: u8, c: u8, d: u8, e: u8, f: u8| a + b + c + d + e + f);
+        assert_eq!(sum(1)(1)(1)(1)(1)(1), 6);
+    }
+
+    #[test]
+    fn test_over() {
+        let stats = over(vec![|x: &i32| x + 1, |x: &i32| x - 1]);
+        assert_eq!(stats(&5), vec![6, 4]);
+
+        let none: Vec<fn(&i32) -> bool> = Vec::new();
+        assert!(over_every(none.clone())(&1));
+        assert!(!over_some(none)(&1));
+    }
+
+    #[test]
+    fn test_rearg() {
+        let list = rearg(vec![2, 0, 1], <[i32]>::to_vec);
+        assert_eq!(list(&[1, 2, 3]), vec![3, 1, 2]);
+        assert_eq!(list(&[1, 2, 3, 4]), vec![3, 1, 2, 4]);
+
+        let swap = rearg([1, 0], |args: &[i32]| args[0] - args[1]);
+        assert_eq!(swap(&[3, 10]), 7);
+
+        let out_of_range = rearg([5, 0], <[i32]>::to_vec);
+        assert_eq!(out_of_range(&[1, 2]), vec![1, 1]);
+        assert_eq!(rearg(Vec::new(), <[i32]>::to_vec)(&[1, 2]), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_unary_and_flip_reducer() {
+        let mapper = unary(|s: String| s.len());
+        assert_eq!(Mapper::apply(&mapper, &"abc".to_string()), 3);
+
+        let reducer = flip_reducer(|x: &i32, acc: i32| acc - x);
+        assert_eq!(Reducer::apply(&reducer, 10, &3), 7);
+    }
+
+    #[test]
+    fn test_combinators_are_send_sync() {
+        fn assert_send_sync<F: Send + Sync>(_: &F) {}
+        assert_send_sync(&once(|x: &i32| *x));
+        assert_send_sync(&before(2, |x: &i32| *x));
+        assert_send_sync(&after(2, |x: &i32| *x));
+        assert_send_sync(&negate(|x: &i32| *x > 0));
+    }
+}
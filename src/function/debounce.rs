@@ -0,0 +1,869 @@
+/*!
+Debounce and throttle for Lodash-RS.
+
+This module provides `debounce` and `throttle` wrappers around closures with
+Lodash's full option set (`leading`, `trailing`, `max_wait`) and the `cancel`,
+`flush` and `pending` controls.
+
+Two flavours are available:
+
+- [`Debounced`] is driven by an injectable [`Clock`] and is meant for
+  synchronous event loops, which call [`Debounced::poll`] to fire trailing
+  invocations once [`Debounced::next_deadline`] has passed.
+- [`AsyncDebounced`] (requires the `async` feature) is driven by a tokio timer
+  and fires trailing invocations on its own.
+*/
+
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+/// Options for `debounce`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::function::DebounceOptions;
+/// use std::time::Duration;
+///
+/// let options = DebounceOptions::default()
+///     .leading(true)
+///     .max_wait(Duration::from_millis(500));
+/// assert!(options.leading);
+/// assert!(options.trailing);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DebounceOptions {
+    /// Invoke on the leading edge of the timeout.
+    pub leading: bool,
+    /// Invoke on the trailing edge of the timeout.
+    pub trailing: bool,
+    /// The maximum time the function is allowed to be delayed before it is invoked.
+    pub max_wait: Option<Duration>,
+}
+
+impl Default for DebounceOptions {
+    fn default() -> Self {
+        Self {
+            leading: false,
+            trailing: true,
+            max_wait: None,
+        }
+    }
+}
+
+impl DebounceOptions {
+    /// Set whether to invoke on the leading edge of the timeout.
+    #[must_use]
+    pub fn leading(mut self, leading: bool) -> Self {
+        self.leading = leading;
+        self
+    }
+
+    /// Set whether to invoke on the trailing edge of the timeout.
+    #[must_use]
+    pub fn trailing(mut self, trailing: bool) -> Self {
+        self.trailing = trailing;
+        self
+    }
+
+    /// Set the maximum time the function is allowed to be delayed.
+    #[must_use]
+    pub fn max_wait(mut self, max_wait: Duration) -> Self {
+        self.max_wait = Some(max_wait);
+        self
+    }
+}
+
+/// Options for `throttle`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::function::ThrottleOptions;
+///
+/// let options = ThrottleOptions::default().trailing(false);
+/// assert!(options.leading);
+/// assert!(!options.trailing);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ThrottleOptions {
+    /// Invoke on the leading edge of the timeout.
+    pub leading: bool,
+    /// Invoke on the trailing edge of the timeout.
+    pub trailing: bool,
+}
+
+impl Default for ThrottleOptions {
+    fn default() -> Self {
+        Self {
+            leading: true,
+            trailing: true,
+        }
+    }
+}
+
+impl ThrottleOptions {
+    /// Set whether to invoke on the leading edge of the timeout.
+    #[must_use]
+    pub fn leading(mut self, leading: bool) -> Self {
+        self.leading = leading;
+        self
+    }
+
+    /// Set whether to invoke on the trailing edge of the timeout.
+    #[must_use]
+    pub fn trailing(mut self, trailing: bool) -> Self {
+        self.trailing = trailing;
+        self
+    }
+
+    fn into_debounce(self, wait: Duration) -> DebounceOptions {
+        DebounceOptions {
+            leading: self.leading,
+            trailing: self.trailing,
+            max_wait: Some(wait),
+        }
+    }
+}
+
+/// Source of the current time for synchronous debouncing.
+pub trait Clock {
+    /// Get the current instant.
+    fn now(&self) -> Instant;
+}
+
+/// Clock backed by `std::time::Instant::now`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// Manually advanced clock, useful for simulations and tests.
+///
+/// Clones share the same time, so one handle can be given to a `Debounced`
+/// while another one is advanced by the caller.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::function::{Clock, ManualClock};
+/// use std::time::Duration;
+///
+/// let clock = ManualClock::new();
+/// let start = clock.now();
+/// clock.advance(Duration::from_millis(10));
+/// assert_eq!(clock.now() - start, Duration::from_millis(10));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ManualClock {
+    now: Arc<Mutex<Instant>>,
+}
+
+impl ManualClock {
+    /// Create a new manual clock starting at the current instant.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            now: Arc::new(Mutex::new(Instant::now())),
+        }
+    }
+
+    /// Move the clock forward by the given duration.
+    pub fn advance(&self, duration: Duration) {
+        let mut now = self.now.lock().unwrap_or_else(PoisonError::into_inner);
+        *now += duration;
+    }
+}
+
+impl Default for ManualClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Instant {
+        *self.now.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Timing state shared by the synchronous and async wrappers.
+///
+/// Each method returns the arguments the wrapped function must be invoked
+/// with, if any; the caller performs the invocation.
+struct Timing<A> {
+    wait: Duration,
+    leading: bool,
+    trailing: bool,
+    max_wait: Option<Duration>,
+    last_args: Option<A>,
+    last_call_time: Option<Instant>,
+    last_invoke_time: Option<Instant>,
+    deadline: Option<Instant>,
+}
+
+impl<A> Timing<A> {
+    fn new(wait: Duration, options: DebounceOptions) -> Self {
+        Self {
+            wait,
+            leading: options.leading,
+            trailing: options.trailing,
+            max_wait: options.max_wait.map(|max_wait| max_wait.max(wait)),
+            last_args: None,
+            last_call_time: None,
+            last_invoke_time: None,
+            deadline: None,
+        }
+    }
+
+    fn since_last_invoke(&self, now: Instant) -> Duration {
+        self.last_invoke_time
+            .map_or(Duration::MAX, |time| now.saturating_duration_since(time))
+    }
+
+    fn should_invoke(&self, now: Instant) -> bool {
+        let Some(last_call_time) = self.last_call_time else {
+            return true;
+        };
+        now.saturating_duration_since(last_call_time) >= self.wait
+            || self
+                .max_wait
+                .is_some_and(|max_wait| self.since_last_invoke(now) >= max_wait)
+    }
+
+    fn remaining_wait(&self, now: Instant) -> Duration {
+        let since_last_call = self
+            .last_call_time
+            .map_or(Duration::ZERO, |time| now.saturating_duration_since(time));
+        let waiting = self.wait.saturating_sub(since_last_call);
+        match self.max_wait {
+            Some(max_wait) => waiting.min(max_wait.saturating_sub(self.since_last_invoke(now))),
+            None => waiting,
+        }
+    }
+
+    fn invoke(&mut self, now: Instant) -> Option<A> {
+        self.last_invoke_time = Some(now);
+        self.last_args.take()
+    }
+
+    fn leading_edge(&mut self, now: Instant) -> Option<A> {
+        self.last_invoke_time = Some(now);
+        self.deadline = Some(now + self.wait);
+        if self.leading {
+            self.invoke(now)
+        } else {
+            None
+        }
+    }
+
+    fn trailing_edge(&mut self, now: Instant) -> Option<A> {
+        self.deadline = None;
+        if self.trailing && self.last_args.is_some() {
+            return self.invoke(now);
+        }
+        self.last_args = None;
+        None
+    }
+
+    fn call(&mut self, args: A, now: Instant) -> Option<A> {
+        let is_invoking = self.should_invoke(now);
+        self.last_args = Some(args);
+        self.last_call_time = Some(now);
+
+        if is_invoking {
+            if self.deadline.is_none() {
+                return self.leading_edge(now);
+            }
+            if self.max_wait.is_some() {
+                self.deadline = Some(now + self.wait);
+                return self.invoke(now);
+            }
+        }
+        if self.deadline.is_none() {
+            self.deadline = Some(now + self.wait);
+        }
+        None
+    }
+
+    fn poll(&mut self, now: Instant) -> Option<A> {
+        match self.deadline {
+            Some(deadline) if now >= deadline => {
+                if self.should_invoke(now) {
+                    self.trailing_edge(now)
+                } else {
+                    self.deadline = Some(now + self.remaining_wait(now));
+                    None
+                }
+            }
+            _ => None,
+        }
+    }
+
+    fn flush(&mut self, now: Instant) -> Option<A> {
+        if self.deadline.is_none() {
+            None
+        } else {
+            self.trailing_edge(now)
+        }
+    }
+
+    fn cancel(&mut self) {
+        self.last_args = None;
+        self.last_call_time = None;
+        self.last_invoke_time = None;
+        self.deadline = None;
+    }
+}
+
+/// A debounced or throttled closure driven by a [`Clock`].
+///
+/// The wrapped function takes a single argument; use a tuple to pass several.
+/// Trailing invocations only happen when the owner calls [`Debounced::poll`],
+/// so this type fits synchronous event loops that already wake up periodically
+/// or can sleep until [`Debounced::next_deadline`].
+pub struct Debounced<F, A, R, C = SystemClock> {
+    func: F,
+    timing: Timing<A>,
+    result: Option<R>,
+    clock: C,
+}
+
+impl<F, A, R, C> Debounced<F, A, R, C>
+where
+    F: FnMut(A) -> R,
+    R: Clone,
+    C: Clock,
+{
+    fn run(&mut self, args: Option<A>) -> Option<R> {
+        if let Some(args) = args {
+            self.result = Some((self.func)(args));
+        }
+        self.result.clone()
+    }
+
+    /// Call the debounced function, returning the result of the last invocation.
+    pub fn call(&mut self, args: A) -> Option<R> {
+        let now = self.clock.now();
+        let args = self.timing.call(args, now);
+        self.run(args)
+    }
+
+    /// Fire the pending trailing invocation if its deadline has passed,
+    /// returning the result of the last invocation.
+    pub fn poll(&mut self) -> Option<R> {
+        let now = self.clock.now();
+        let args = self.timing.poll(now);
+        self.run(args)
+    }
+
+    /// Immediately invoke the pending trailing call, if any, returning the
+    /// result of the last invocation.
+    pub fn flush(&mut self) -> Option<R> {
+        let now = self.clock.now();
+        let args = self.timing.flush(now);
+        self.run(args)
+    }
+
+    /// Cancel the pending trailing call.
+    pub fn cancel(&mut self) {
+        self.timing.cancel();
+    }
+
+    /// Check whether a timer is pending.
+    #[must_use]
+    pub fn pending(&self) -> bool {
+        self.timing.deadline.is_some()
+    }
+
+    /// Get the instant at which [`Debounced::poll`] should next be called.
+    #[must_use]
+    pub fn next_deadline(&self) -> Option<Instant> {
+        self.timing.deadline
+    }
+}
+
+/// Create a debounced function that delays invoking `func` until after `wait`
+/// has elapsed since the last time it was called.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::function::{debounce_with_clock, DebounceOptions, ManualClock};
+/// use std::time::Duration;
+///
+/// let clock = ManualClock::new();
+/// let mut calls = Vec::new();
+/// let mut save = debounce_with_clock(
+///     |x: i32| calls.push(x),
+///     Duration::from_millis(100),
+///     DebounceOptions::default(),
+///     clock.clone(),
+/// );
+///
+/// save.call(1);
+/// save.call(2);
+/// clock.advance(Duration::from_millis(100));
+/// save.poll();
+/// drop(save);
+/// assert_eq!(calls, vec![2]);
+/// ```
+pub fn debounce<F, A, R>(func: F, wait: Duration) -> Debounced<F, A, R>
+where
+    F: FnMut(A) -> R,
+{
+    debounce_with(func, wait, DebounceOptions::default())
+}
+
+/// This method is like `debounce` except that it accepts options.
+pub fn debounce_with<F, A, R>(func: F, wait: Duration, options: DebounceOptions) -> Debounced<F, A, R>
+where
+    F: FnMut(A) -> R,
+{
+    debounce_with_clock(func, wait, options, SystemClock)
+}
+
+/// This method is like `debounce_with` except that it reads time from `clock`.
+pub fn debounce_with_clock<F, A, R, C>(
+    func: F,
+    wait: Duration,
+    options: DebounceOptions,
+    clock: C,
+) -> Debounced<F, A, R, C>
+where
+    F: FnMut(A) -> R,
+    C: Clock,
+{
+    Debounced {
+        func,
+        timing: Timing::new(wait, options),
+        result: None,
+        clock,
+    }
+}
+
+/// Create a throttled function that only invokes `func` at most once per
+/// every `wait`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::function::{throttle_with_clock, ManualClock, ThrottleOptions};
+/// use std::time::Duration;
+///
+/// let clock = ManualClock::new();
+/// let mut calls = Vec::new();
+/// let mut update = throttle_with_clock(
+///     |x: i32| calls.push(x),
+///     Duration::from_millis(100),
+///     ThrottleOptions::default(),
+///     clock.clone(),
+/// );
+///
+/// update.call(1);
+/// update.call(2);
+/// update.call(3);
+/// clock.advance(Duration::from_millis(100));
+/// update.poll();
+/// drop(update);
+/// assert_eq!(calls, vec![1, 3]);
+/// ```
+pub fn throttle<F, A, R>(func: F, wait: Duration) -> Debounced<F, A, R>
+where
+    F: FnMut(A) -> R,
+{
+    throttle_with(func, wait, ThrottleOptions::default())
+}
+
+/// This method is like `throttle` except that it accepts options.
+pub fn throttle_with<F, A, R>(func: F, wait: Duration, options: ThrottleOptions) -> Debounced<F, A, R>
+where
+    F: FnMut(A) -> R,
+{
+    throttle_with_clock(func, wait, options, SystemClock)
+}
+
+/// This method is like `throttle_with` except that it reads time from `clock`.
+pub fn throttle_with_clock<F, A, R, C>(
+    func: F,
+    wait: Duration,
+    options: ThrottleOptions,
+    clock: C,
+) -> Debounced<F, A, R, C>
+where
+    F: FnMut(A) -> R,
+    C: Clock,
+{
+    debounce_with_clock(func, wait, options.into_debounce(wait), clock)
+}
+
+#[cfg(feature = "async")]
+struct AsyncState<A> {
+    timing: Timing<A>,
+    timer: Option<tokio::task::JoinHandle<()>>,
+}
+
+/// A debounced or throttled closure driven by a tokio timer.
+///
+/// Handles are cheap to clone and share the same state. Calls must be made
+/// from within a tokio runtime, which runs the trailing invocations.
+#[cfg(feature = "async")]
+pub struct AsyncDebounced<A> {
+    func: Arc<Mutex<dyn FnMut(A) + Send>>,
+    state: Arc<Mutex<AsyncState<A>>>,
+}
+
+#[cfg(feature = "async")]
+impl<A> Clone for AsyncDebounced<A> {
+    fn clone(&self) -> Self {
+        Self {
+            func: Arc::clone(&self.func),
+            state: Arc::clone(&self.state),
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl<A> AsyncDebounced<A>
+where
+    A: Send + 'static,
+{
+    fn now() -> Instant {
+        tokio::time::Instant::now().into_std()
+    }
+
+    fn lock_state(&self) -> std::sync::MutexGuard<'_, AsyncState<A>> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn run(&self, args: Option<A>) {
+        if let Some(args) = args {
+            let mut func = self.func.lock().unwrap_or_else(PoisonError::into_inner);
+            func(args);
+        }
+    }
+
+    fn ensure_timer(&self, state: &mut AsyncState<A>) {
+        if state.timing.deadline.is_none() || state.timer.is_some() {
+            return;
+        }
+        let handle = self.clone();
+        state.timer = Some(tokio::spawn(async move {
+            loop {
+                let deadline = {
+                    let mut state = handle.lock_state();
+                    let Some(deadline) = state.timing.deadline else {
+                        state.timer = None;
+                        return;
+                    };
+                    deadline
+                };
+                tokio::time::sleep_until(tokio::time::Instant::from_std(deadline)).await;
+                let args = handle.lock_state().timing.poll(Self::now());
+                handle.run(args);
+            }
+        }));
+    }
+
+    /// Call the debounced function.
+    pub fn call(&self, args: A) {
+        let args = {
+            let mut state = self.lock_state();
+            let args = state.timing.call(args, Self::now());
+            self.ensure_timer(&mut state);
+            args
+        };
+        self.run(args);
+    }
+
+    /// Immediately invoke the pending trailing call, if any.
+    pub fn flush(&self) {
+        let args = self.lock_state().timing.flush(Self::now());
+        self.run(args);
+    }
+
+    /// Cancel the pending trailing call.
+    pub fn cancel(&self) {
+        let mut state = self.lock_state();
+        state.timing.cancel();
+        if let Some(timer) = state.timer.take() {
+            timer.abort();
+        }
+    }
+
+    /// Check whether a timer is pending.
+    #[must_use]
+    pub fn pending(&self) -> bool {
+        self.lock_state().timing.deadline.is_some()
+    }
+}
+
+/// Async version of `debounce_with`, driven by a tokio timer.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::function::{debounce_async, DebounceOptions};
+/// use std::sync::{Arc, Mutex};
+/// use std::time::Duration;
+///
+/// # async fn example() {
+/// let calls = Arc::new(Mutex::new(Vec::new()));
+/// let sink = Arc::clone(&calls);
+/// let save = debounce_async(
+///     move |x: i32| sink.lock().unwrap().push(x),
+///     Duration::from_millis(10),
+///     DebounceOptions::default(),
+/// );
+///
+/// save.call(1);
+/// save.call(2);
+/// tokio::time::sleep(Duration::from_millis(50)).await;
+/// assert_eq!(*calls.lock().unwrap(), vec![2]);
+/// # }
+/// ```
+#[cfg(feature = "async")]
+pub fn debounce_async<F, A>(func: F, wait: Duration, options: DebounceOptions) -> AsyncDebounced<A>
+where
+    F: FnMut(A) + Send + 'static,
+    A: Send + 'static,
+{
+    AsyncDebounced {
+        func: Arc::new(Mutex::new(func)),
+        state: Arc::new(Mutex::new(AsyncState {
+            timing: Timing::new(wait, options),
+            timer: None,
+        })),
+    }
+}
+
+/// Async version of `throttle_with`, driven by a tokio timer.
+#[cfg(feature = "async")]
+pub fn throttle_async<F, A>(func: F, wait: Duration, options: ThrottleOptions) -> AsyncDebounced<A>
+where
+    F: FnMut(A) + Send + 'static,
+    A: Send + 'static,
+{
+    debounce_async(func, wait, options.into_debounce(wait))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    const WAIT: Duration = Duration::from_millis(100);
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn test_debounce_trailing() {
+        let clock = ManualClock::new();
+        let calls = RefCell::new(Vec::new());
+        let mut debounced = debounce_with_clock(
+            |x: i32| calls.borrow_mut().push(x),
+            WAIT,
+            DebounceOptions::default(),
+            clock.clone(),
+        );
+
+        debounced.call(1);
+        clock.advance(ms(50));
+        debounced.call(2);
+        clock.advance(ms(60));
+        debounced.poll();
+        assert!(calls.borrow().is_empty());
+        assert!(debounced.pending());
+
+        clock.advance(ms(40));
+        debounced.poll();
+        assert_eq!(*calls.borrow(), vec![2]);
+        assert!(!debounced.pending());
+    }
+
+    #[test]
+    fn test_debounce_leading() {
+        let clock = ManualClock::new();
+        let calls = RefCell::new(Vec::new());
+        let mut debounced = debounce_with_clock(
+            |x: i32| calls.borrow_mut().push(x),
+            WAIT,
+            DebounceOptions::default().leading(true).trailing(false),
+            clock.clone(),
+        );
+
+        debounced.call(1);
+        debounced.call(2);
+        clock.advance(WAIT);
+        debounced.poll();
+        assert_eq!(*calls.borrow(), vec![1]);
+
+        debounced.call(3);
+        assert_eq!(*calls.borrow(), vec![1, 3]);
+    }
+
+    #[test]
+    fn test_debounce_leading_and_trailing_single_call() {
+        let clock = ManualClock::new();
+        let calls = RefCell::new(Vec::new());
+        let mut debounced = debounce_with_clock(
+            |x: i32| calls.borrow_mut().push(x),
+            WAIT,
+            DebounceOptions::default().leading(true),
+            clock.clone(),
+        );
+
+        debounced.call(1);
+        clock.advance(WAIT);
+        debounced.poll();
+        assert_eq!(*calls.borrow(), vec![1]);
+    }
+
+    #[test]
+    fn test_debounce_max_wait() {
+        let clock = ManualClock::new();
+        let calls = RefCell::new(Vec::new());
+        let mut debounced = debounce_with_clock(
+            |x: i32| calls.borrow_mut().push(x),
+            WAIT,
+            DebounceOptions::default().max_wait(ms(250)),
+            clock.clone(),
+        );
+
+        for i in 0..10 {
+            debounced.call(i);
+            clock.advance(ms(50));
+            debounced.poll();
+        }
+        assert_eq!(*calls.borrow(), vec![4, 9]);
+    }
+
+    #[test]
+    fn test_debounce_returns_last_result() {
+        let clock = ManualClock::new();
+        let mut debounced = debounce_with_clock(
+            |x: i32| x * 2,
+            WAIT,
+            DebounceOptions::default().leading(true),
+            clock.clone(),
+        );
+
+        assert_eq!(debounced.call(1), Some(2));
+        assert_eq!(debounced.call(5), Some(2));
+        assert_eq!(debounced.flush(), Some(10));
+    }
+
+    #[test]
+    fn test_debounce_cancel_and_flush() {
+        let clock = ManualClock::new();
+        let calls = RefCell::new(Vec::new());
+        let mut debounced = debounce_with_clock(
+            |x: i32| calls.borrow_mut().push(x),
+            WAIT,
+            DebounceOptions::default(),
+            clock.clone(),
+        );
+
+        debounced.call(1);
+        debounced.cancel();
+        assert!(!debounced.pending());
+        clock.advance(WAIT);
+        debounced.poll();
+        assert!(calls.borrow().is_empty());
+
+        debounced.call(2);
+        debounced.flush();
+        assert_eq!(*calls.borrow(), vec![2]);
+        assert!(!debounced.pending());
+        assert_eq!(debounced.next_deadline(), None);
+    }
+
+    #[test]
+    fn test_throttle() {
+        let clock = ManualClock::new();
+        let calls = RefCell::new(Vec::new());
+        let mut throttled = throttle_with_clock(
+            |x: i32| calls.borrow_mut().push(x),
+            WAIT,
+            ThrottleOptions::default(),
+            clock.clone(),
+        );
+
+        for i in 0..7 {
+            throttled.call(i);
+            clock.advance(ms(40));
+            throttled.poll();
+        }
+        clock.advance(WAIT);
+        throttled.poll();
+        assert_eq!(*calls.borrow(), vec![0, 2, 5, 6]);
+    }
+
+    #[test]
+    fn test_throttle_no_trailing() {
+        let clock = ManualClock::new();
+        let calls = RefCell::new(Vec::new());
+        let mut throttled = throttle_with_clock(
+            |x: i32| calls.borrow_mut().push(x),
+            WAIT,
+            ThrottleOptions::default().trailing(false),
+            clock.clone(),
+        );
+
+        throttled.call(1);
+        throttled.call(2);
+        clock.advance(WAIT);
+        throttled.poll();
+        throttled.call(3);
+        assert_eq!(*calls.borrow(), vec![1, 3]);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test(start_paused = true)]
+    async fn test_debounce_async() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&calls);
+        let debounced = debounce_async(
+            move |x: i32| sink.lock().unwrap().push(x),
+            WAIT,
+            DebounceOptions::default(),
+        );
+
+        debounced.call(1);
+        tokio::time::sleep(ms(50)).await;
+        debounced.call(2);
+        assert!(debounced.pending());
+        tokio::time::sleep(ms(200)).await;
+        assert_eq!(*calls.lock().unwrap(), vec![2]);
+        assert!(!debounced.pending());
+
+        debounced.call(3);
+        debounced.cancel();
+        tokio::time::sleep(ms(200)).await;
+        assert_eq!(*calls.lock().unwrap(), vec![2]);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test(start_paused = true)]
+    async fn test_throttle_async() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&calls);
+        let throttled = throttle_async(
+            move |x: i32| sink.lock().unwrap().push(x),
+            WAIT,
+            ThrottleOptions::default(),
+        );
+
+        throttled.call(1);
+        throttled.call(2);
+        throttled.call(3);
+        tokio::time::sleep(ms(150)).await;
+        assert_eq!(*calls.lock().unwrap(), vec![1, 3]);
+    }
+}
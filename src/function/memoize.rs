@@ -0,0 +1,649 @@
+/*!
+Memoization for Lodash-RS.
+
+This module provides `memoize` and `memoize_with`, which cache the results of
+a function keyed by a resolver, together with the [`Cache`] trait and its
+unbounded, LRU, TTL and size-capped implementations.
+
+Memoized functions are `Sync` whenever their parts are, so they can wrap
+iteratees passed to parallel methods such as `map_parallel`.
+*/
+
+use crate::function::debounce::{Clock, SystemClock};
+use crate::utils::type_conv::{Mapper, ToHashKey, ToKey};
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+
+/// Storage used by memoized functions.
+pub trait Cache<K, V> {
+    /// Get the value stored for `key`, if any.
+    fn get(&mut self, key: &K) -> Option<&V>;
+
+    /// Store `value` for `key`, evicting other entries if needed.
+    fn insert(&mut self, key: K, value: V);
+
+    /// Remove the value stored for `key`.
+    fn remove(&mut self, key: &K) -> Option<V>;
+
+    /// Remove all entries.
+    fn clear(&mut self);
+
+    /// Get the number of entries.
+    fn len(&self) -> usize;
+
+    /// Check if the cache has no entries.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Cache that never evicts entries.
+#[derive(Debug, Clone)]
+pub struct UnboundedCache<K, V> {
+    entries: HashMap<K, V>,
+}
+
+impl<K, V> UnboundedCache<K, V> {
+    /// Create a new unbounded cache.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            entries: HashMap::new(),
+        }
+    }
+}
+
+impl<K, V> Default for UnboundedCache<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V> Cache<K, V> for UnboundedCache<K, V>
+where
+    K: Hash + Eq,
+{
+    fn get(&mut self, key: &K) -> Option<&V> {
+        self.entries.get(key)
+    }
+
+    fn insert(&mut self, key: K, value: V) {
+        self.entries.insert(key, value);
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        self.entries.remove(key)
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+}
+
+/// Cache that keeps at most `capacity` entries, evicting the least recently used.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::function::{Cache, LruCache};
+///
+/// let mut cache = LruCache::new(2);
+/// cache.insert("a", 1);
+/// cache.insert("b", 2);
+/// cache.get(&"a");
+/// cache.insert("c", 3);
+/// assert_eq!(cache.get(&"b"), None);
+/// assert_eq!(cache.get(&"a"), Some(&1));
+/// ```
+#[derive(Debug, Clone)]
+pub struct LruCache<K, V> {
+    capacity: usize,
+    tick: u64,
+    entries: HashMap<K, (V, u64)>,
+    recency: BTreeMap<u64, K>,
+}
+
+impl<K, V> LruCache<K, V> {
+    /// Create a new LRU cache holding at most `capacity` entries.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            tick: 0,
+            entries: HashMap::new(),
+            recency: BTreeMap::new(),
+        }
+    }
+
+    /// Get the maximum number of entries.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
+
+impl<K, V> Cache<K, V> for LruCache<K, V>
+where
+    K: Hash + Eq + Clone,
+{
+    fn get(&mut self, key: &K) -> Option<&V> {
+        self.tick += 1;
+        let tick = self.tick;
+        let (value, last_used) = self.entries.get_mut(key)?;
+        if let Some(key) = self.recency.remove(last_used) {
+            self.recency.insert(tick, key);
+        }
+        *last_used = tick;
+        Some(value)
+    }
+
+    fn insert(&mut self, key: K, value: V) {
+        if self.capacity == 0 {
+            return;
+        }
+        self.tick += 1;
+        if let Some((_, last_used)) = self.entries.remove(&key) {
+            self.recency.remove(&last_used);
+        }
+        while self.entries.len() >= self.capacity {
+            let Some((_, oldest)) = self.recency.pop_first() else {
+                break;
+            };
+            self.entries.remove(&oldest);
+        }
+        self.recency.insert(self.tick, key.clone());
+        self.entries.insert(key, (value, self.tick));
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        let (value, last_used) = self.entries.remove(key)?;
+        self.recency.remove(&last_used);
+        Some(value)
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.recency.clear();
+    }
+
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+}
+
+/// Cache whose entries expire `ttl` after they were inserted.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::function::{Cache, ManualClock, TtlCache};
+/// use std::time::Duration;
+///
+/// let clock = ManualClock::new();
+/// let mut cache = TtlCache::with_clock(Duration::from_secs(1), clock.clone());
+/// cache.insert("a", 1);
+/// assert_eq!(cache.get(&"a"), Some(&1));
+///
+/// clock.advance(Duration::from_secs(1));
+/// assert_eq!(cache.get(&"a"), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct TtlCache<K, V, C = SystemClock> {
+    ttl: Duration,
+    clock: C,
+    entries: HashMap<K, (V, Instant)>,
+}
+
+impl<K, V> TtlCache<K, V> {
+    /// Create a new TTL cache.
+    #[must_use]
+    pub fn new(ttl: Duration) -> Self {
+        Self::with_clock(ttl, SystemClock)
+    }
+}
+
+impl<K, V, C> TtlCache<K, V, C> {
+    /// Create a new TTL cache that reads time from `clock`.
+    #[must_use]
+    pub fn with_clock(ttl: Duration, clock: C) -> Self {
+        Self {
+            ttl,
+            clock,
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Get the time-to-live of entries.
+    #[must_use]
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+}
+
+impl<K, V, C> TtlCache<K, V, C>
+where
+    K: Hash + Eq,
+    C: Clock,
+{
+    /// Remove all expired entries.
+    pub fn purge_expired(&mut self) {
+        let now = self.clock.now();
+        self.entries.retain(|_, (_, expires_at)| *expires_at > now);
+    }
+}
+
+impl<K, V, C> Cache<K, V> for TtlCache<K, V, C>
+where
+    K: Hash + Eq,
+    C: Clock,
+{
+    fn get(&mut self, key: &K) -> Option<&V> {
+        let now = self.clock.now();
+        if self.entries.get(key)?.1 <= now {
+            self.entries.remove(key);
+            return None;
+        }
+        self.entries.get(key).map(|(value, _)| value)
+    }
+
+    fn insert(&mut self, key: K, value: V) {
+        let expires_at = self.clock.now() + self.ttl;
+        self.entries.insert(key, (value, expires_at));
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        self.entries.remove(key).map(|(value, _)| value)
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+}
+
+/// Cache that keeps the total size of its values under `max_size`, evicting
+/// the oldest entries first.
+///
+/// The size of each value is measured by the `weigher` function.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::function::{Cache, SizeCappedCache};
+///
+/// let mut cache = SizeCappedCache::new(8, |s: &String| s.len());
+/// cache.insert(1, "abcd".to_string());
+/// cache.insert(2, "efgh".to_string());
+/// cache.insert(3, "ij".to_string());
+/// assert_eq!(cache.get(&1), None);
+/// assert_eq!(cache.total_size(), 6);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SizeCappedCache<K, V> {
+    max_size: usize,
+    total_size: usize,
+    weigher: fn(&V) -> usize,
+    entries: HashMap<K, (V, usize)>,
+    order: VecDeque<K>,
+}
+
+impl<K, V> SizeCappedCache<K, V> {
+    /// Create a new size-capped cache.
+    #[must_use]
+    pub fn new(max_size: usize, weigher: fn(&V) -> usize) -> Self {
+        Self {
+            max_size,
+            total_size: 0,
+            weigher,
+            entries: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    /// Get the total size of the stored values.
+    #[must_use]
+    pub fn total_size(&self) -> usize {
+        self.total_size
+    }
+}
+
+impl<K, V> Cache<K, V> for SizeCappedCache<K, V>
+where
+    K: Hash + Eq + Clone,
+{
+    fn get(&mut self, key: &K) -> Option<&V> {
+        self.entries.get(key).map(|(value, _)| value)
+    }
+
+    fn insert(&mut self, key: K, value: V) {
+        let size = (self.weigher)(&value);
+        if size > self.max_size {
+            return;
+        }
+        self.remove(&key);
+        while self.total_size + size > self.max_size {
+            let Some(oldest) = self.order.pop_front() else {
+                break;
+            };
+            if let Some((_, oldest_size)) = self.entries.remove(&oldest) {
+                self.total_size -= oldest_size;
+            }
+        }
+        self.total_size += size;
+        self.order.push_back(key.clone());
+        self.entries.insert(key, (value, size));
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        let (value, size) = self.entries.remove(key)?;
+        self.total_size -= size;
+        self.order.retain(|k| k != key);
+        Some(value)
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+        self.total_size = 0;
+    }
+
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+}
+
+/// Hit and miss counters of a memoized function.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// Number of calls answered from the cache.
+    pub hits: u64,
+    /// Number of calls that invoked the wrapped function.
+    pub misses: u64,
+}
+
+impl CacheStats {
+    /// Get the fraction of calls answered from the cache.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn hit_rate(&self) -> f64 {
+        let total = self.hits + self.misses;
+        if total == 0 {
+            0.0
+        } else {
+            self.hits as f64 / total as f64
+        }
+    }
+}
+
+/// Marker for the argument, key and value types of a `Memoized`.
+type Signature<A, K, V> = PhantomData<fn(&A) -> (K, V)>;
+
+/// A function whose results are cached by key.
+///
+/// The cache lock is not held while the wrapped function runs, so concurrent
+/// misses on the same key may each invoke it; the last result is kept.
+pub struct Memoized<F, R, C, A, K, V> {
+    func: F,
+    resolver: R,
+    cache: Mutex<C>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+    _marker: Signature<A, K, V>,
+}
+
+impl<F, R, C, A, K, V> Memoized<F, R, C, A, K, V>
+where
+    F: Fn(&A) -> V,
+    R: Fn(&A) -> K,
+    C: Cache<K, V>,
+    V: Clone,
+{
+    fn lock_cache(&self) -> MutexGuard<'_, C> {
+        self.cache.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Call the memoized function, returning the cached result if present.
+    pub fn call(&self, args: &A) -> V {
+        let key = (self.resolver)(args);
+        if let Some(value) = self.lock_cache().get(&key) {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            return value.clone();
+        }
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        let value = (self.func)(args);
+        self.lock_cache().insert(key, value.clone());
+        value
+    }
+
+    /// Get exclusive access to the underlying cache.
+    pub fn cache(&self) -> MutexGuard<'_, C> {
+        self.lock_cache()
+    }
+
+    /// Remove all cached results.
+    pub fn clear(&self) {
+        self.lock_cache().clear();
+    }
+
+    /// Get the hit and miss counters.
+    #[must_use]
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Reset the hit and miss counters.
+    pub fn reset_stats(&self) {
+        self.hits.store(0, Ordering::Relaxed);
+        self.misses.store(0, Ordering::Relaxed);
+    }
+}
+
+impl<F, R, C, A, K, V> Mapper<A, V> for Memoized<F, R, C, A, K, V>
+where
+    F: Fn(&A) -> V,
+    R: Fn(&A) -> K,
+    C: Cache<K, V>,
+    V: Clone,
+{
+    fn apply(&self, value: &A) -> V {
+        self.call(value)
+    }
+}
+
+/// Memoized function returned by `memoize`.
+pub type KeyMemoized<F, A, V> = Memoized<F, fn(&A) -> String, UnboundedCache<String, V>, A, String, V>;
+
+/// Resolver that keys arguments by their `ToKey` representation.
+pub fn key_resolver<A: ToKey>(args: &A) -> String {
+    args.to_key()
+}
+
+/// Resolver that keys arguments by their `ToHashKey` representation.
+pub fn hash_key_resolver<A: ToHashKey>(args: &A) -> u64 {
+    args.to_hash_key()
+}
+
+/// Create a function that memoizes the result of `func` in an unbounded
+/// cache, keyed by the `ToKey` representation of its argument.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::function::memoize;
+///
+/// let square = memoize(|x: &i32| x * x);
+/// assert_eq!(square.call(&4), 16);
+/// assert_eq!(square.call(&4), 16);
+/// assert_eq!(square.stats().hits, 1);
+/// assert_eq!(square.stats().misses, 1);
+/// ```
+pub fn memoize<F, A, V>(func: F) -> KeyMemoized<F, A, V>
+where
+    F: Fn(&A) -> V,
+    A: ToKey,
+    V: Clone,
+{
+    memoize_with(func, key_resolver::<A>, UnboundedCache::new())
+}
+
+/// This method is like `memoize` except that it accepts a key resolver and
+/// a cache.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::function::{hash_key_resolver, memoize_with, Cache, LruCache};
+///
+/// let length = memoize_with(|s: &String| s.len(), hash_key_resolver, LruCache::new(100));
+/// assert_eq!(length.call(&"hello".to_string()), 5);
+/// assert_eq!(length.cache().len(), 1);
+/// ```
+pub fn memoize_with<F, R, C, A, K, V>(func: F, resolver: R, cache: C) -> Memoized<F, R, C, A, K, V>
+where
+    F: Fn(&A) -> V,
+    R: Fn(&A) -> K,
+    C: Cache<K, V>,
+    V: Clone,
+{
+    Memoized {
+        func,
+        resolver,
+        cache: Mutex::new(cache),
+        hits: AtomicU64::new(0),
+        misses: AtomicU64::new(0),
+        _marker: PhantomData,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::function::debounce::ManualClock;
+    use std::sync::atomic::AtomicUsize;
+
+    #[test]
+    fn test_memoize() {
+        let calls = AtomicUsize::new(0);
+        let double = memoize(|x: &i32| {
+            calls.fetch_add(1, Ordering::Relaxed);
+            x * 2
+        });
+
+        assert_eq!(double.call(&2), 4);
+        assert_eq!(double.call(&2), 4);
+        assert_eq!(double.call(&3), 6);
+        assert_eq!(calls.load(Ordering::Relaxed), 2);
+        assert_eq!(double.stats(), CacheStats { hits: 1, misses: 2 });
+        assert!((double.stats().hit_rate() - 1.0 / 3.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_memoize_clear_and_cache() {
+        let double = memoize(|x: &i32| x * 2);
+        double.call(&1);
+        double.cache().insert("2".to_string(), 5);
+        assert_eq!(double.call(&2), 5);
+
+        double.clear();
+        assert!(double.cache().is_empty());
+        assert_eq!(double.call(&2), 4);
+
+        double.reset_stats();
+        assert_eq!(double.stats(), CacheStats::default());
+    }
+
+    #[test]
+    fn test_memoize_with_custom_resolver() {
+        let memoized = memoize_with(
+            |pair: &(i32, i32)| pair.0 + pair.1,
+            |pair: &(i32, i32)| pair.0,
+            UnboundedCache::new(),
+        );
+        assert_eq!(memoized.call(&(1, 2)), 3);
+        assert_eq!(memoized.call(&(1, 5)), 3);
+    }
+
+    #[test]
+    fn test_memoize_is_sync() {
+        let square = memoize_with(|x: &i32| x * x, hash_key_resolver, UnboundedCache::new());
+        let shared = &square;
+        let results: Vec<i32> = std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..4)
+                .map(|i| scope.spawn(move || Mapper::apply(shared, &(i % 2))))
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+        assert_eq!(results, vec![0, 1, 0, 1]);
+        assert_eq!(square.cache().len(), 2);
+    }
+
+    #[test]
+    fn test_lru_cache() {
+        let mut cache = LruCache::new(2);
+        cache.insert(1, "a");
+        cache.insert(2, "b");
+        assert_eq!(cache.get(&1), Some(&"a"));
+        cache.insert(3, "c");
+        assert_eq!(cache.get(&2), None);
+        assert_eq!(cache.len(), 2);
+
+        cache.insert(1, "z");
+        assert_eq!(cache.get(&1), Some(&"z"));
+        assert_eq!(cache.remove(&3), Some("c"));
+        assert_eq!(cache.len(), 1);
+
+        let mut empty: LruCache<i32, i32> = LruCache::new(0);
+        empty.insert(1, 1);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_ttl_cache() {
+        let clock = ManualClock::new();
+        let mut cache = TtlCache::with_clock(Duration::from_secs(10), clock.clone());
+        cache.insert("a", 1);
+        clock.advance(Duration::from_secs(5));
+        cache.insert("b", 2);
+        assert_eq!(cache.get(&"a"), Some(&1));
+
+        clock.advance(Duration::from_secs(5));
+        assert_eq!(cache.get(&"a"), None);
+        assert_eq!(cache.get(&"b"), Some(&2));
+
+        clock.advance(Duration::from_secs(5));
+        cache.purge_expired();
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_size_capped_cache() {
+        let mut cache = SizeCappedCache::new(10, |v: &Vec<u8>| v.len());
+        cache.insert("a", vec![0; 4]);
+        cache.insert("b", vec![0; 4]);
+        cache.insert("c", vec![0; 4]);
+        assert_eq!(cache.get(&"a"), None);
+        assert_eq!(cache.total_size(), 8);
+
+        cache.insert("big", vec![0; 11]);
+        assert_eq!(cache.get(&"big"), None);
+
+        cache.insert("b", vec![0; 1]);
+        assert_eq!(cache.total_size(), 5);
+        cache.clear();
+        assert_eq!(cache.total_size(), 0);
+    }
+}
@@ -0,0 +1,28 @@
+/*!
+Function module for Lodash-RS.
+
+This module contains higher-order helpers that wrap closures, such as
+`debounce`, `throttle`, `memoize` and combinators such as `negate` and
+`flow`, organized by functionality.
+*/
+
+pub mod combinators;
+pub mod debounce;
+pub mod memoize;
+
+// Re-export commonly used items
+pub use combinators::{
+    after, before, curry2, curry3, curry4, curry5, curry6, flip_reducer, flow, flow_right, negate,
+    once, over, over_every, over_some, partial, partial_right, rearg, unary,
+};
+pub use debounce::{
+    debounce, debounce_with, debounce_with_clock, throttle, throttle_with, throttle_with_clock,
+    Clock, DebounceOptions, Debounced, ManualClock, SystemClock, ThrottleOptions,
+};
+pub use memoize::{
+    hash_key_resolver, key_resolver, memoize, memoize_with, Cache, CacheStats, KeyMemoized, LruCache,
+    Memoized, SizeCappedCache, TtlCache, UnboundedCache,
+};
+
+#[cfg(feature = "async")]
+pub use debounce::{debounce_async, throttle_async, AsyncDebounced};
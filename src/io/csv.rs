@@ -0,0 +1,782 @@
+//! CSV readers and writers.
+
+use super::{malformed, ReadReport, Records};
+use crate::collection::ordered_map::OrderedMap;
+use crate::utils::{LodashError, Result};
+use serde::de::value::{Error as DeError, MapDeserializer};
+use serde::de::{
+    DeserializeOwned, Deserializer, Error as _, IntoDeserializer, Unexpected, Visitor,
+};
+use serde::{forward_to_deserialize_any, Serialize};
+use serde_json::Value;
+use std::collections::VecDeque;
+use std::io::{BufRead, Write};
+use std::marker::PhantomData;
+
+/// The operation named by read errors.
+const READ: &str = "csv read";
+/// The operation named by write errors.
+const WRITE: &str = "csv write";
+
+/// A lazy reader of CSV records, keyed by the header row.
+///
+/// Fields follow RFC 4180: quoted fields may contain the delimiter, line
+/// breaks and doubled quotes. Blank lines are ignored. Each record is
+/// deserialized from a map of header to field, so structs read their fields
+/// by column name and parse numbers and booleans from the text; into a
+/// `serde_json::Value`, empty fields become `null` and fields that look like
+/// numbers or `true`/`false` become numbers and booleans.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::io::CsvReader;
+/// use serde::Deserialize;
+///
+/// #[derive(Debug, Deserialize, PartialEq)]
+/// struct Row {
+///     zip: String,
+///     count: u32,
+///     note: Option<String>,
+/// }
+///
+/// let input = "zip,count,note\n02134,3,\"multi\nline\"\n10001,x,\n";
+/// let mut reader = CsvReader::<_, Row>::new(input.as_bytes());
+/// let row = reader.next().unwrap().unwrap();
+/// assert_eq!(row, Row { zip: "02134".into(), count: 3, note: Some("multi\nline".into()) });
+/// let error = reader.next().unwrap().unwrap_err();
+/// assert_eq!(error.index(), Some(1));
+/// assert_eq!(
+///     format!("{error:#}"),
+///     "csv read failed at element 1: Invalid input: line 4: invalid value: string \"x\", expected u32"
+/// );
+/// ```
+#[derive(Debug)]
+pub struct CsvReader<R, T = Value> {
+    /// The input
+    reader: R,
+    /// The line buffer
+    buffer: Vec<u8>,
+    /// Lines to read again after an unterminated quoted field
+    pending: VecDeque<(usize, String)>,
+    /// The field delimiter
+    delimiter: char,
+    /// The column names, once read
+    header: Option<Vec<String>>,
+    /// The number of lines read
+    line: usize,
+    /// The number of rows read after the header
+    rows: usize,
+    /// The most lines one record may span
+    max_record_lines: usize,
+    /// Whether malformed rows are skipped
+    skip_malformed: bool,
+    /// Whether the input is exhausted or failed
+    done: bool,
+    /// Shared progress
+    report: ReadReport,
+    /// The record type
+    marker: PhantomData<fn() -> T>,
+}
+
+/// Why a row could not be read.
+enum RowError {
+    /// The row is malformed and may be skipped
+    Malformed(LodashError),
+    /// The input failed, ending the reader
+    Failed(LodashError),
+}
+
+impl<R: BufRead, T: DeserializeOwned> CsvReader<R, T> {
+    /// Create a reader over `reader`, whose first row is the header.
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            buffer: Vec::new(),
+            pending: VecDeque::new(),
+            delimiter: ',',
+            header: None,
+            line: 0,
+            rows: 0,
+            max_record_lines: 1024,
+            skip_malformed: false,
+            done: false,
+            report: ReadReport::default(),
+            marker: PhantomData,
+        }
+    }
+
+    /// Use `delimiter` instead of `,` between fields.
+    #[must_use]
+    pub fn delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Limit a quoted field to `lines` lines (1024 by default, at least 1).
+    ///
+    /// A stray quote opens a field that never closes; the limit keeps it from
+    /// swallowing the rest of the input. The row it starts is malformed and
+    /// the lines after it are read as rows again.
+    #[must_use]
+    pub fn max_record_lines(mut self, lines: usize) -> Self {
+        self.max_record_lines = lines.max(1);
+        self
+    }
+
+    /// Skip rows that are malformed, have the wrong number of fields or do
+    /// not deserialize to `T`, counting them in the [`ReadReport`], instead
+    /// of yielding an error. A malformed header is always an error.
+    #[must_use]
+    pub fn skip_malformed(mut self, skip: bool) -> Self {
+        self.skip_malformed = skip;
+        self
+    }
+
+    /// A handle on the reader's progress.
+    #[must_use]
+    pub fn report(&self) -> ReadReport {
+        self.report.clone()
+    }
+
+    /// The records without the `Result` wrapper. See [`Records`].
+    pub fn records(self) -> Records<Self> {
+        let report = self.report();
+        Records::new(self, report)
+    }
+
+    /// Read the next non-blank row and its starting line, or `None` at the
+    /// end of the input.
+    ///
+    /// A row whose quoted field is still open at the end of the input or
+    /// after `max_record_lines` lines is malformed, and its lines after the
+    /// first are read again as rows of their own.
+    fn read_row(&mut self) -> Option<std::result::Result<(usize, Vec<String>), RowError>> {
+        let mut lines: Vec<(usize, String)> = Vec::new();
+        let mut quoted = false;
+        loop {
+            let line = match self.read_line() {
+                Some(Ok(line)) => line,
+                Some(Err(error)) => return Some(Err(error)),
+                None if lines.is_empty() => return None,
+                None => return Some(Err(self.unterminated(lines, "unterminated quoted field"))),
+            };
+            quoted ^= line.1.matches('"').count() % 2 == 1;
+            lines.push(line);
+            if quoted {
+                if lines.len() >= self.max_record_lines {
+                    let message = format!(
+                        "quoted field spans more than {} lines",
+                        self.max_record_lines
+                    );
+                    return Some(Err(self.unterminated(lines, message)));
+                }
+                continue;
+            }
+            let start = lines[0].0;
+            let record: String = lines.iter().map(|(_, text)| text.as_str()).collect();
+            let row = record.trim_end_matches(['\n', '\r']);
+            if row.trim().is_empty() {
+                lines.clear();
+                continue;
+            }
+            return Some(
+                split_row(row, self.delimiter)
+                    .map(|fields| (start, fields))
+                    .map_err(|message| RowError::Malformed(malformed(start, message))),
+            );
+        }
+    }
+
+    /// Read the next line and its number, replaying pending lines first.
+    fn read_line(&mut self) -> Option<std::result::Result<(usize, String), RowError>> {
+        if let Some(line) = self.pending.pop_front() {
+            return Some(Ok(line));
+        }
+        if self.done {
+            return None;
+        }
+        self.buffer.clear();
+        match self.reader.read_until(b'\n', &mut self.buffer) {
+            Ok(0) => {
+                self.done = true;
+                return None;
+            }
+            Ok(_) => self.line += 1,
+            Err(error) => {
+                self.done = true;
+                return Some(Err(RowError::Failed(LodashError::from_error(error))));
+            }
+        }
+        Some(match std::str::from_utf8(&self.buffer) {
+            Ok(text) => Ok((self.line, text.to_string())),
+            Err(_) => Err(RowError::Malformed(malformed(self.line, "invalid UTF-8"))),
+        })
+    }
+
+    /// Reject the row made of `lines` and queue its lines after the first to
+    /// be read again, so one stray quote costs only its own row.
+    fn unterminated(
+        &mut self,
+        mut lines: Vec<(usize, String)>,
+        message: impl std::fmt::Display,
+    ) -> RowError {
+        let start = lines[0].0;
+        for line in lines.drain(1..).rev() {
+            self.pending.push_front(line);
+        }
+        RowError::Malformed(malformed(start, message))
+    }
+
+    fn deserialize(&self, line: usize, fields: &[String]) -> Result<T> {
+        let header = self.header.as_deref().unwrap_or_default();
+        if fields.len() != header.len() {
+            return Err(malformed(
+                line,
+                format_args!("expected {} fields, found {}", header.len(), fields.len()),
+            ));
+        }
+        let entries = header
+            .iter()
+            .map(String::as_str)
+            .zip(fields.iter().map(|field| Field(field)));
+        T::deserialize(MapDeserializer::new(entries))
+            .map_err(|error: DeError| malformed(line, error))
+    }
+}
+
+impl<R: BufRead, T: DeserializeOwned> Iterator for CsvReader<R, T> {
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Result<T>> {
+        if self.header.is_none() {
+            match self.read_row()? {
+                Ok((_, header)) => self.header = Some(header),
+                Err(RowError::Malformed(error) | RowError::Failed(error)) => {
+                    self.done = true;
+                    self.pending.clear();
+                    return Some(Err(error.with_operation(READ)));
+                }
+            }
+        }
+        loop {
+            let index = self.rows;
+            self.rows += 1;
+            let record = match self.read_row()? {
+                Ok((line, fields)) => self.deserialize(line, &fields),
+                Err(RowError::Malformed(error)) => Err(error),
+                Err(RowError::Failed(error)) => {
+                    return Some(Err(error.with_operation(READ).with_index(index)))
+                }
+            };
+            match record {
+                Ok(record) => {
+                    self.report.record_read();
+                    return Some(Ok(record));
+                }
+                Err(_) if self.skip_malformed => self.report.record_skipped(),
+                Err(error) => return Some(Err(error.with_operation(READ).with_index(index))),
+            }
+        }
+    }
+}
+
+/// Split a complete row into its fields, unquoting quoted fields.
+fn split_row(row: &str, delimiter: char) -> std::result::Result<Vec<String>, String> {
+    #[derive(PartialEq)]
+    enum State {
+        Start,
+        Unquoted,
+        Quoted,
+        AfterQuote,
+    }
+
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut state = State::Start;
+    let mut chars = row.chars().peekable();
+    while let Some(c) = chars.next() {
+        state = match (state, c) {
+            (State::Quoted, '"') if chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+                State::Quoted
+            }
+            (State::Quoted, '"') => State::AfterQuote,
+            (State::Quoted, c) => {
+                field.push(c);
+                State::Quoted
+            }
+            (State::Start, '"') => State::Quoted,
+            (_, c) if c == delimiter => {
+                fields.push(std::mem::take(&mut field));
+                State::Start
+            }
+            (State::AfterQuote, c) => {
+                return Err(format!("unexpected `{c}` after a quoted field"));
+            }
+            (_, '"') => return Err("unexpected quote inside an unquoted field".to_string()),
+            (_, c) => {
+                field.push(c);
+                State::Unquoted
+            }
+        };
+    }
+    fields.push(field);
+    Ok(fields)
+}
+
+/// A CSV field as a serde deserializer.
+struct Field<'a>(&'a str);
+
+/// Whether `text` is written like a JSON number, so that codes such as
+/// `02134` stay strings.
+fn is_number(text: &str) -> bool {
+    let digits = text.strip_prefix('-').unwrap_or(text);
+    let leading_zero =
+        digits.len() > 1 && digits.starts_with('0') && digits.as_bytes()[1].is_ascii_digit();
+    digits.starts_with(|c: char| c.is_ascii_digit())
+        && !digits.ends_with('.')
+        && !leading_zero
+        && digits
+            .bytes()
+            .all(|b| b.is_ascii_digit() || matches!(b, b'.' | b'e' | b'E' | b'-' | b'+'))
+}
+
+macro_rules! deserialize_parsed {
+    ($($method:ident => $visit:ident,)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> std::result::Result<V::Value, DeError> {
+                match self.0.trim().parse() {
+                    Ok(value) => visitor.$visit(value),
+                    Err(_) => Err(DeError::invalid_value(Unexpected::Str(self.0), &visitor)),
+                }
+            }
+        )*
+    };
+}
+
+impl IntoDeserializer<'_, DeError> for Field<'_> {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+impl<'de> Deserializer<'de> for Field<'_> {
+    type Error = DeError;
+
+    fn deserialize_any<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> std::result::Result<V::Value, DeError> {
+        let text = self.0;
+        match text {
+            "" => return visitor.visit_unit(),
+            "true" => return visitor.visit_bool(true),
+            "false" => return visitor.visit_bool(false),
+            _ => {}
+        }
+        if is_number(text) {
+            if let Ok(number) = text.parse::<i64>() {
+                return visitor.visit_i64(number);
+            }
+            if let Ok(number) = text.parse::<u64>() {
+                return visitor.visit_u64(number);
+            }
+            if let Ok(number) = text.parse::<f64>() {
+                return visitor.visit_f64(number);
+            }
+        }
+        visitor.visit_str(text)
+    }
+
+    deserialize_parsed! {
+        deserialize_bool => visit_bool,
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+    }
+
+    fn deserialize_char<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> std::result::Result<V::Value, DeError> {
+        visitor.visit_str(self.0)
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> std::result::Result<V::Value, DeError> {
+        visitor.visit_str(self.0)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> std::result::Result<V::Value, DeError> {
+        visitor.visit_str(self.0)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> std::result::Result<V::Value, DeError> {
+        if self.0.is_empty() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> std::result::Result<V::Value, DeError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> std::result::Result<V::Value, DeError> {
+        visitor.visit_enum(self.0.into_deserializer())
+    }
+
+    forward_to_deserialize_any! {
+        i128 u128 bytes byte_buf unit unit_struct seq tuple tuple_struct map struct identifier
+        ignored_any
+    }
+}
+
+/// A writer of CSV records.
+///
+/// Records must serialize to objects, such as structs or maps. The header is
+/// the keys of the first record, in order, unless given with
+/// [`CsvWriter::with_header`]; a record with a key outside the header is an
+/// error and missing keys are written as empty fields. Strings are written
+/// as they are, `null` as an empty field and other values as compact JSON,
+/// quoting fields that contain the delimiter, a quote or a line break.
+///
+/// Wrap unbuffered outputs such as files in a `std::io::BufWriter`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::io::CsvWriter;
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Row {
+///     name: &'static str,
+///     tags: Vec<&'static str>,
+///     age: Option<u32>,
+/// }
+///
+/// let mut writer = CsvWriter::new(Vec::new());
+/// writer.write_all([
+///     Row { name: "Ann, Jr.", tags: vec!["a"], age: Some(31) },
+///     Row { name: "Bob", tags: vec![], age: None },
+/// ]).unwrap();
+/// let output = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+/// assert_eq!(output, "name,tags,age\n\"Ann, Jr.\",\"[\"\"a\"\"]\",31\nBob,[],\n");
+/// ```
+#[derive(Debug)]
+pub struct CsvWriter<W: Write> {
+    /// The output
+    writer: W,
+    /// The column names, once known
+    header: Option<Vec<String>>,
+    /// Whether the header row has been written
+    header_written: bool,
+    /// The field delimiter
+    delimiter: char,
+    /// The number of records written
+    records: usize,
+}
+
+impl<W: Write> CsvWriter<W> {
+    /// Create a writer over `writer` that takes its header from the first
+    /// record.
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            header: None,
+            header_written: false,
+            delimiter: ',',
+            records: 0,
+        }
+    }
+
+    /// Create a writer over `writer` with the columns `header`.
+    pub fn with_header(writer: W, header: Vec<String>) -> Self {
+        Self {
+            header: Some(header),
+            ..Self::new(writer)
+        }
+    }
+
+    /// Use `delimiter` instead of `,` between fields.
+    #[must_use]
+    pub fn delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Write one record, and the header row before the first.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `Context` around `LodashError::InvalidInput` if the record
+    /// does not serialize to an object or has a key outside the header, and
+    /// around `LodashError::Custom` if it fails to serialize or the output
+    /// fails.
+    pub fn write<T: Serialize + ?Sized>(&mut self, record: &T) -> Result<()> {
+        let index = self.records;
+        self.records += 1;
+        let context = |error: LodashError| error.with_operation(WRITE).with_index(index);
+        let json = serde_json::to_string(record)
+            .map_err(|error| context(LodashError::from_error(error)))?;
+        let row: OrderedMap<String, Value> = serde_json::from_str(&json).map_err(|_| {
+            context(LodashError::invalid_input(format!(
+                "CSV records must serialize to objects, found `{json}`"
+            )))
+        })?;
+        let header = self
+            .header
+            .get_or_insert_with(|| row.keys().cloned().collect());
+        if let Some(key) = row.keys().find(|key| !header.contains(key)) {
+            return Err(context(LodashError::invalid_input(format!(
+                "unexpected column `{key}`"
+            ))));
+        }
+        let cells: Vec<String> = header
+            .iter()
+            .map(|column| match row.get(column) {
+                None | Some(Value::Null) => String::new(),
+                Some(Value::String(string)) => quote(string, self.delimiter),
+                Some(other) => quote(&other.to_string(), self.delimiter),
+            })
+            .collect();
+        self.write_header()?;
+        self.write_line(&cells)
+            .map_err(|error| context(LodashError::from_error(error)))
+    }
+
+    /// Write every record of `records`, returning how many were written.
+    ///
+    /// # Errors
+    ///
+    /// Stops at the first record that fails, see [`CsvWriter::write`].
+    pub fn write_all<I>(&mut self, records: I) -> Result<usize>
+    where
+        I: IntoIterator,
+        I::Item: Serialize,
+    {
+        let mut count = 0;
+        for record in records {
+            self.write(&record)?;
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    /// Write the header row if it is known and not yet written, and flush
+    /// the output.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `Context` around `LodashError::Custom` if the output fails.
+    pub fn flush(&mut self) -> Result<()> {
+        self.write_header()?;
+        self.writer
+            .flush()
+            .map_err(|error| LodashError::from_error(error).with_operation(WRITE))
+    }
+
+    /// Flush the output and return it.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `Context` around `LodashError::Custom` if the output fails.
+    pub fn into_inner(mut self) -> Result<W> {
+        self.flush()?;
+        Ok(self.writer)
+    }
+
+    fn write_header(&mut self) -> Result<()> {
+        if self.header_written {
+            return Ok(());
+        }
+        let Some(header) = &self.header else {
+            return Ok(());
+        };
+        let cells: Vec<String> = header
+            .iter()
+            .map(|column| quote(column, self.delimiter))
+            .collect();
+        self.write_line(&cells)
+            .map_err(|error| LodashError::from_error(error).with_operation(WRITE))?;
+        self.header_written = true;
+        Ok(())
+    }
+
+    fn write_line(&mut self, cells: &[String]) -> std::io::Result<()> {
+        let mut separator = [0; 4];
+        let separator = self.delimiter.encode_utf8(&mut separator);
+        writeln!(self.writer, "{}", cells.join(separator))
+    }
+}
+
+/// Quote `field` if it contains the delimiter, a quote or a line break.
+fn quote(field: &str, delimiter: char) -> String {
+    if field.contains([delimiter, '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_split_row() {
+        assert_eq!(
+            split_row(r#"a,"b,c","d""e",,"#, ',').unwrap(),
+            vec!["a", "b,c", "d\"e", "", ""]
+        );
+        assert_eq!(split_row("a;b", ';').unwrap(), vec!["a", "b"]);
+        assert!(split_row(r#""a"b,c"#, ',').is_err());
+        assert!(split_row(r#"a"b,c"#, ',').is_err());
+    }
+
+    #[test]
+    fn test_reader_infers_json_values() {
+        let input = "id;code;score;ok;note\r\n1;007;2.5;true;\r\n\r\n-3;1e3;x;false;\"a;b\"\r\n";
+        let rows: Vec<Value> = CsvReader::new(input.as_bytes())
+            .delimiter(';')
+            .collect::<Result<_>>()
+            .unwrap();
+        assert_eq!(
+            rows,
+            vec![
+                json!({"id": 1, "code": "007", "score": 2.5, "ok": true, "note": null}),
+                json!({"id": -3, "code": 1000.0, "score": "x", "ok": false, "note": "a;b"}),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_reader_errors_and_skipping() {
+        let input = "a,b\n1,2\n3\n\"4,5\n";
+        let results: Vec<Result<Value>> = CsvReader::new(input.as_bytes()).collect();
+        assert_eq!(results[0], Ok(json!({"a": 1, "b": 2})));
+        assert_eq!(
+            format!("{:#}", results[1].as_ref().unwrap_err()),
+            "csv read failed at element 1: Invalid input: line 3: expected 2 fields, found 1"
+        );
+        let error = results[2].as_ref().unwrap_err();
+        assert_eq!((error.operation(), error.index()), (Some("csv read"), Some(2)));
+        assert_eq!(
+            error.root(),
+            &LodashError::invalid_input("line 4: unterminated quoted field")
+        );
+        assert_eq!(results.len(), 3);
+
+        let reader = CsvReader::<_, Value>::new(input.as_bytes()).skip_malformed(true);
+        let report = reader.report();
+        assert_eq!(reader.records().count(), 1);
+        assert_eq!((report.records(), report.skipped()), (1, 2));
+        assert!(report.check().is_ok());
+
+        let reader = CsvReader::<_, Value>::new(input.as_bytes());
+        let report = reader.report();
+        assert_eq!(reader.records().count(), 1);
+        assert_eq!(report.check().unwrap_err().index(), Some(1));
+    }
+
+    #[test]
+    fn test_reader_recovers_from_stray_quote() {
+        let input = "a,b\n1,\"2\n3,4\n5,6\n7,8\n";
+        let results: Vec<Result<Value>> = CsvReader::new(input.as_bytes())
+            .max_record_lines(2)
+            .collect();
+        assert_eq!(
+            format!("{:#}", results[0].as_ref().unwrap_err()),
+            "csv read failed at element 0: Invalid input: line 2: quoted field spans more than 2 lines"
+        );
+        assert_eq!(
+            results[1..],
+            [
+                Ok(json!({"a": 3, "b": 4})),
+                Ok(json!({"a": 5, "b": 6})),
+                Ok(json!({"a": 7, "b": 8})),
+            ]
+        );
+
+        let reader = CsvReader::<_, Value>::new(input.as_bytes()).skip_malformed(true);
+        let report = reader.report();
+        assert_eq!(reader.records().count(), 3);
+        assert_eq!((report.records(), report.skipped()), (3, 1));
+
+        let input = "a,b\n1,\"x\ny\"\n\"3,4\n";
+        let results: Vec<Result<Value>> = CsvReader::new(input.as_bytes())
+            .max_record_lines(2)
+            .collect();
+        assert_eq!(results[0], Ok(json!({"a": 1, "b": "x\ny"})));
+        assert_eq!(
+            results[1].as_ref().unwrap_err().root(),
+            &LodashError::invalid_input("line 4: unterminated quoted field")
+        );
+        assert_eq!(results.len(), 2);
+    }
+
+    #[test]
+    fn test_malformed_header_ends_reader() {
+        let input = "\"a,b\nx,y\n1,2\n";
+        let mut reader = CsvReader::<_, Value>::new(input.as_bytes()).skip_malformed(true);
+        let error = reader.next().unwrap().unwrap_err();
+        assert_eq!(
+            error.root(),
+            &LodashError::invalid_input("line 1: unterminated quoted field")
+        );
+        assert!(reader.next().is_none());
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn test_writer_header_and_columns() {
+        let mut writer =
+            CsvWriter::with_header(Vec::new(), vec!["b".into(), "a".into()]).delimiter('\t');
+        writer.write(&json!({"a": "x\ty", "b": 1.5})).unwrap();
+        writer.write(&json!({"a": true})).unwrap();
+        assert_eq!(
+            format!("{:#}", writer.write(&json!({"c": 1})).unwrap_err()),
+            "csv write failed at element 2: Invalid input: unexpected column `c`"
+        );
+        assert_eq!(
+            format!("{:#}", writer.write(&[1, 2]).unwrap_err()),
+            "csv write failed at element 3: Invalid input: CSV records must serialize to objects, found `[1,2]`"
+        );
+        let output = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+        assert_eq!(output, "b\ta\n1.5\t\"x\ty\"\n\ttrue\n");
+
+        let empty = CsvWriter::with_header(Vec::new(), vec!["only".into()]);
+        assert_eq!(empty.into_inner().unwrap(), b"only\n");
+    }
+}
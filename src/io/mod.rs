@@ -0,0 +1,167 @@
+/*!
+Streaming record IO for Lodash-RS.
+
+This module turns any `std::io::BufRead` into a lazy iterator of records and
+writes records back out, for batch jobs over NDJSON and CSV files that are
+too large to load at once.
+
+- [`NdjsonReader`] and [`CsvReader`] yield `Result<T>` for each record,
+  deserialized with serde (`serde_json::Value` by default). Malformed rows
+  produce `LodashError::InvalidInput` errors, or are skipped and counted with
+  `skip_malformed(true)`.
+- [`Records`] adapts a reader into an iterator of plain records for
+  [`chain_iter`](crate::chain::chain_iter) or the
+  [`LodashIterExt`](crate::extensions::LodashIterExt) methods, stopping at
+  the first error and recording it in a [`ReadReport`].
+- [`NdjsonWriter`] and [`CsvWriter`] serialize records to any
+  `std::io::Write`.
+
+Errors carry their context: [`LodashError::operation`] is `"ndjson read"`,
+`"csv read"`, `"ndjson write"` or `"csv write"`, [`LodashError::index`] is
+the 0-based index of the record, and messages about malformed input start
+with their line number.
+
+Memory stays bounded by what the steps keep: a reader holds one row at a
+time, `chain_iter` streams its leading `filter`, `map`, `take` and `skip`
+steps, and `count_by` only keeps the counts.
+
+```
+use rust_lodash::chain::chain_iter;
+use rust_lodash::extensions::LodashIterExt;
+use rust_lodash::io::NdjsonReader;
+use serde_json::{json, Value};
+
+let input = "{\"id\": 1, \"ok\": true}\nnot json\n{\"id\": 2, \"ok\": false}\n";
+
+let reader = NdjsonReader::<_, Value>::new(input.as_bytes()).skip_malformed(true);
+let report = reader.report();
+let ok = chain_iter(reader.records()).filter(|row| row["ok"] == true).value();
+assert_eq!(ok, vec![json!({"id": 1, "ok": true})]);
+assert!(report.check().is_ok());
+assert_eq!((report.records(), report.skipped()), (2, 1));
+
+let counts = NdjsonReader::<_, Value>::new(input.as_bytes())
+    .skip_malformed(true)
+    .records()
+    .count_by(|row| row["ok"].to_string());
+assert_eq!(counts["true"], 1);
+```
+*/
+
+mod csv;
+mod ndjson;
+
+pub use self::csv::{CsvReader, CsvWriter};
+pub use self::ndjson::{NdjsonReader, NdjsonWriter};
+
+use crate::utils::{LodashError, Result};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+/// Shared progress of a reader: how many records were read and skipped, and
+/// the error that stopped its [`Records`], if any.
+///
+/// Get one with `report()` before handing the reader to a chain; it stays
+/// connected to the reader.
+#[derive(Debug, Clone, Default)]
+pub struct ReadReport {
+    /// The shared counters
+    state: Arc<Mutex<ReportState>>,
+}
+
+#[derive(Debug, Default)]
+struct ReportState {
+    records: usize,
+    skipped: usize,
+    error: Option<LodashError>,
+}
+
+impl ReadReport {
+    fn lock(&self) -> MutexGuard<'_, ReportState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// The number of records read successfully so far.
+    #[must_use]
+    pub fn records(&self) -> usize {
+        self.lock().records
+    }
+
+    /// The number of malformed rows skipped so far.
+    #[must_use]
+    pub fn skipped(&self) -> usize {
+        self.lock().skipped
+    }
+
+    /// Check whether the records were read without error.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error that stopped the reader's [`Records`].
+    pub fn check(&self) -> Result<()> {
+        match &self.lock().error {
+            Some(error) => Err(error.clone()),
+            None => Ok(()),
+        }
+    }
+
+    fn record_read(&self) {
+        self.lock().records += 1;
+    }
+
+    fn record_skipped(&self) {
+        self.lock().skipped += 1;
+    }
+}
+
+/// The records of a reader, without the `Result` wrapper.
+///
+/// Iteration stops at the first error, which is kept in the reader's
+/// [`ReadReport`]: an early end looks like the end of the input, so call
+/// [`ReadReport::check`] once the records are consumed. Created by
+/// `records()` on a reader.
+#[derive(Debug)]
+#[must_use = "iterators are lazy, and read errors are only reported through `ReadReport::check`"]
+pub struct Records<I> {
+    /// The reader
+    inner: I,
+    /// Where to record an error
+    report: ReadReport,
+    /// Whether an error has ended iteration
+    failed: bool,
+}
+
+impl<I> Records<I> {
+    fn new(inner: I, report: ReadReport) -> Self {
+        Self {
+            inner,
+            report,
+            failed: false,
+        }
+    }
+}
+
+impl<I, T> Iterator for Records<I>
+where
+    I: Iterator<Item = Result<T>>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.failed {
+            return None;
+        }
+        match self.inner.next()? {
+            Ok(record) => Some(record),
+            Err(error) => {
+                self.failed = true;
+                self.report.lock().error = Some(error);
+                None
+            }
+        }
+    }
+}
+
+/// An error for a malformed row at `line`, which may include a column.
+fn malformed(line: impl std::fmt::Display, message: impl std::fmt::Display) -> LodashError {
+    LodashError::invalid_input(format!("line {line}: {message}"))
+}
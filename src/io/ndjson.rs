@@ -0,0 +1,307 @@
+//! Newline-delimited JSON readers and writers.
+
+use super::{malformed, ReadReport, Records};
+use crate::utils::{LodashError, Result};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use std::io::{BufRead, Write};
+use std::marker::PhantomData;
+
+/// The operation named by read errors.
+const READ: &str = "ndjson read";
+/// The operation named by write errors.
+const WRITE: &str = "ndjson write";
+
+/// A lazy reader of one JSON record per line. Blank lines are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::io::NdjsonReader;
+/// use serde::Deserialize;
+///
+/// #[derive(Debug, Deserialize, PartialEq)]
+/// struct Order {
+///     id: u32,
+///     total: f64,
+/// }
+///
+/// let input = "{\"id\": 1, \"total\": 9.5}\n\n{\"id\": 2, \"total\": \"n/a\"}\n";
+/// let mut reader = NdjsonReader::<_, Order>::new(input.as_bytes());
+/// assert_eq!(reader.next().unwrap().unwrap(), Order { id: 1, total: 9.5 });
+/// let error = reader.next().unwrap().unwrap_err();
+/// assert_eq!(error.index(), Some(1));
+/// assert_eq!(
+///     format!("{error:#}"),
+///     "ndjson read failed at element 1: \
+///      Invalid input: line 3, column 24: invalid type: string \"n/a\", expected f64"
+/// );
+/// assert!(reader.next().is_none());
+/// ```
+#[derive(Debug)]
+pub struct NdjsonReader<R, T = Value> {
+    /// The input
+    reader: R,
+    /// The line buffer
+    buffer: Vec<u8>,
+    /// The number of lines read
+    line: usize,
+    /// The number of non-blank lines read
+    rows: usize,
+    /// Whether malformed lines are skipped
+    skip_malformed: bool,
+    /// Whether the input is exhausted or failed
+    done: bool,
+    /// Shared progress
+    report: ReadReport,
+    /// The record type
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<R: BufRead, T: DeserializeOwned> NdjsonReader<R, T> {
+    /// Create a reader over `reader`.
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            buffer: Vec::new(),
+            line: 0,
+            rows: 0,
+            skip_malformed: false,
+            done: false,
+            report: ReadReport::default(),
+            marker: PhantomData,
+        }
+    }
+
+    /// Skip lines that are not valid JSON or do not deserialize to `T`,
+    /// counting them in the [`ReadReport`], instead of yielding an error.
+    #[must_use]
+    pub fn skip_malformed(mut self, skip: bool) -> Self {
+        self.skip_malformed = skip;
+        self
+    }
+
+    /// A handle on the reader's progress.
+    #[must_use]
+    pub fn report(&self) -> ReadReport {
+        self.report.clone()
+    }
+
+    /// The records without the `Result` wrapper. See [`Records`].
+    pub fn records(self) -> Records<Self> {
+        let report = self.report();
+        Records::new(self, report)
+    }
+}
+
+impl<R: BufRead, T: DeserializeOwned> Iterator for NdjsonReader<R, T> {
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Result<T>> {
+        while !self.done {
+            self.buffer.clear();
+            match self.reader.read_until(b'\n', &mut self.buffer) {
+                Ok(0) => self.done = true,
+                Ok(_) => {
+                    self.line += 1;
+                    if self.buffer.iter().all(u8::is_ascii_whitespace) {
+                        continue;
+                    }
+                    let index = self.rows;
+                    self.rows += 1;
+                    match serde_json::from_slice(&self.buffer) {
+                        Ok(record) => {
+                            self.report.record_read();
+                            return Some(Ok(record));
+                        }
+                        Err(_) if self.skip_malformed => self.report.record_skipped(),
+                        Err(error) => {
+                            let error = json_error(self.line, &error);
+                            return Some(Err(error.with_operation(READ).with_index(index)));
+                        }
+                    }
+                }
+                Err(error) => {
+                    self.done = true;
+                    let error = LodashError::from_error(error);
+                    return Some(Err(error.with_operation(READ).with_index(self.rows)));
+                }
+            }
+        }
+        None
+    }
+}
+
+/// A malformed-line error, with serde's column but without its line, which
+/// is always 1.
+fn json_error(line: usize, error: &serde_json::Error) -> LodashError {
+    let message = error.to_string();
+    let message = message
+        .rsplit_once(" at line ")
+        .map_or(message.as_str(), |(message, _)| message);
+    if error.column() == 0 {
+        malformed(line, message)
+    } else {
+        malformed(format_args!("{line}, column {}", error.column()), message)
+    }
+}
+
+/// A writer of one JSON record per line.
+///
+/// Wrap unbuffered outputs such as files in a `std::io::BufWriter`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::io::NdjsonWriter;
+/// use serde_json::json;
+///
+/// let mut writer = NdjsonWriter::new(Vec::new());
+/// writer.write(&json!({"id": 1})).unwrap();
+/// writer.write_all([json!({"id": 2}), json!({"id": 3})]).unwrap();
+/// let output = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+/// assert_eq!(output, "{\"id\":1}\n{\"id\":2}\n{\"id\":3}\n");
+/// ```
+#[derive(Debug)]
+pub struct NdjsonWriter<W: Write> {
+    /// The output
+    writer: W,
+    /// The number of records written
+    records: usize,
+}
+
+impl<W: Write> NdjsonWriter<W> {
+    /// Create a writer over `writer`.
+    pub fn new(writer: W) -> Self {
+        Self { writer, records: 0 }
+    }
+
+    /// Write one record.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `Context` around `LodashError::Custom` if the record fails
+    /// to serialize or the output fails.
+    pub fn write<T: Serialize + ?Sized>(&mut self, record: &T) -> Result<()> {
+        let index = self.records;
+        self.records += 1;
+        let context = |error: LodashError| error.with_operation(WRITE).with_index(index);
+        serde_json::to_writer(&mut self.writer, record)
+            .map_err(|error| context(LodashError::from_error(error)))?;
+        self.writer
+            .write_all(b"\n")
+            .map_err(|error| context(LodashError::from_error(error)))
+    }
+
+    /// Write every record of `records`, returning how many were written.
+    ///
+    /// # Errors
+    ///
+    /// Stops at the first record that fails, see [`NdjsonWriter::write`].
+    pub fn write_all<I>(&mut self, records: I) -> Result<usize>
+    where
+        I: IntoIterator,
+        I::Item: Serialize,
+    {
+        let mut count = 0;
+        for record in records {
+            self.write(&record)?;
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    /// Flush the output.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `Context` around `LodashError::Custom` if the output fails.
+    pub fn flush(&mut self) -> Result<()> {
+        self.writer
+            .flush()
+            .map_err(|error| LodashError::from_error(error).with_operation(WRITE))
+    }
+
+    /// Flush the output and return it.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `Context` around `LodashError::Custom` if the output fails.
+    pub fn into_inner(mut self) -> Result<W> {
+        self.flush()?;
+        Ok(self.writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chain::chain_iter;
+    use crate::extensions::LodashIterExt;
+    use serde_json::json;
+
+    #[test]
+    fn test_reader_line_numbers_and_skipping() {
+        let input = "{\"a\": 1}\n\n{\"a\": }\n[1, 2]\n{\"a\":";
+        let results: Vec<Result<Value>> = NdjsonReader::new(input.as_bytes()).collect();
+        assert_eq!(results[0], Ok(json!({"a": 1})));
+        assert_eq!(
+            format!("{:#}", results[1].as_ref().unwrap_err()),
+            "ndjson read failed at element 1: Invalid input: line 3, column 7: expected value"
+        );
+        assert_eq!(results[2], Ok(json!([1, 2])));
+        let error = results[3].as_ref().unwrap_err();
+        assert_eq!((error.operation(), error.index()), (Some("ndjson read"), Some(3)));
+        assert_eq!(
+            error.root(),
+            &LodashError::invalid_input("line 5, column 5: EOF while parsing a value")
+        );
+
+        let reader = NdjsonReader::<_, Value>::new(input.as_bytes()).skip_malformed(true);
+        let report = reader.report();
+        assert_eq!(reader.records().count(), 2);
+        assert_eq!((report.records(), report.skipped()), (2, 2));
+    }
+
+    #[test]
+    fn test_streaming_stays_lazy() {
+        let mut writer = NdjsonWriter::new(Vec::new());
+        writer
+            .write_all((0..10_000).map(|i| json!({"id": i, "group": i % 3})))
+            .unwrap();
+        let input = writer.into_inner().unwrap();
+        let reader = NdjsonReader::<_, Value>::new(std::io::Cursor::new(input.clone()));
+        let report = reader.report();
+        let first = chain_iter(reader.records())
+            .filter(|row| row["group"] == 1)
+            .take(2)
+            .value();
+        assert_eq!(
+            first,
+            vec![json!({"id": 1, "group": 1}), json!({"id": 4, "group": 1})]
+        );
+        assert_eq!(report.records(), 5);
+
+        let counts = NdjsonReader::<_, Value>::new(input.as_slice())
+            .records()
+            .count_by(|row| row["group"].as_u64());
+        assert_eq!(counts[&Some(0)], 3334);
+        let unique = NdjsonReader::<_, Value>::new(input.as_slice())
+            .records()
+            .uniq_by(|row| row["group"].as_u64());
+        assert_eq!(unique.len(), 3);
+    }
+
+    #[test]
+    fn test_writer_roundtrip() {
+        let records = vec![json!({"b": 1, "a": "x\ny"}), json!(null)];
+        let mut writer = NdjsonWriter::new(Vec::new());
+        assert_eq!(writer.write_all(&records), Ok(2));
+        let output = writer.into_inner().unwrap();
+        let read: Vec<Value> = NdjsonReader::new(output.as_slice())
+            .collect::<Result<_>>()
+            .unwrap();
+        assert_eq!(read, records);
+    }
+}
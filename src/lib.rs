@@ -13,6 +13,7 @@ A type-safe, high-performance Rust implementation of Lodash collection methods w
 - **Parallel Processing**: Automatic parallelization for large collections
 - **Memory Safe**: Guaranteed memory safety with no data races
 - **WASM Compatible**: Full WebAssembly support for browser usage
+- **No Std**: Collection methods, chains and errors build on `no_std + alloc` without the default `std` feature
 
 ## Quick Start
 
@@ -39,9 +40,13 @@ assert_eq!(result, vec![6, 12]);
 
 The library is organized into several modules:
 
-- `collection`: Core collection methods (iteration, query, transform, operations)
+- `collection`: Core collection methods (iteration, query, transform, operations, grouped aggregation)
 - `chain`: Fluent method chaining system
+- `function`: Higher-order function wrappers (debounce, throttle, memoize, combinators)
+- `math`: Math and number methods (sum, mean, min/max, clamp, rounding, statistics)
+- `string`: String methods (word splitting, case conversion, padding, escaping, templates)
 - `utils`: Utility functions and type conversions
+- `io`: Streaming NDJSON and CSV readers and writers (requires `serialize`)
 - `extensions`: Advanced features (parallel processing, WASM support)
 
 ## Performance
@@ -61,15 +66,25 @@ Lodash-RS is designed for maximum performance:
 - **Error Handling**: Comprehensive error types with proper propagation
 */
 
-// #![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(not(feature = "std"), no_std)]
 #![warn(missing_docs, clippy::all, clippy::pedantic)]
 #![allow(clippy::module_name_repetitions)]
 
+extern crate alloc;
+
 // Core modules
 pub mod collection;
 pub mod chain;
+#[cfg(feature = "std")]
+pub mod function;
+pub mod math;
+#[cfg(feature = "std")]
+pub mod string;
 pub mod utils;
+#[cfg(feature = "std")]
 pub mod extensions;
+#[cfg(feature = "serialize")]
+pub mod io;
 
 // Re-exports for convenience
 pub mod prelude {
@@ -77,6 +92,9 @@ pub mod prelude {
 
     // Core types
     pub use crate::collection::Collection;
+    pub use crate::collection::grouped::{grouped_by, GroupedCollection};
+    pub use crate::collection::ordered_map::OrderedMap;
+    pub use crate::collection::source::{refs, Source};
     pub use crate::chain::Chain;
 
     // Iteration methods
@@ -87,20 +105,74 @@ pub mod prelude {
     // Query methods
     pub use crate::collection::query::{
         find, find_last, includes, every, some, count_by, partition,
+        count_by_into, count_by_ordered, count_by_sorted,
     };
 
     // Transform methods
     pub use crate::collection::transform::{
         group_by, key_by, invoke, sort_by, order_by,
+        group_by_into, group_by_ordered, group_by_sorted, key_by_into, key_by_ordered,
+        key_by_sorted, uniq, uniq_by, sort_by_partial, sort_by_float, sort_by_cached_key,
+        order_by_partial, order_by_float, NanPolicy,
+    };
+
+    // Join methods
+    pub use crate::collection::join::{
+        anti_join_by, join_by, left_join_by, outer_join_by, semi_join_by,
+    };
+
+    // Pivot methods
+    #[cfg(feature = "std")]
+    pub use crate::collection::pivot::{crosstab, melt, pivot, Table};
+
+    // Window methods
+    pub use crate::collection::window::{
+        chunk, chunk_by, pairwise, reduce_scan, rolling_mean, rolling_sum, scan, sliding, windows,
     };
 
     // Collection operations
     pub use crate::collection::operation::{
-        shuffle, sample, sample_size, size,
+        sample_size_with, sample_with, shuffle_with, size,
+    };
+    #[cfg(feature = "std")]
+    pub use crate::collection::operation::{shuffle, sample, sample_size};
+
+    // Extension traits. `LodashIterExt` is imported anonymously so that a
+    // glob import does not bind its name next to `itertools::Itertools`.
+    #[cfg(feature = "std")]
+    pub use crate::extensions::traits::{LodashMapExt, LodashSliceExt};
+    #[cfg(feature = "std")]
+    pub use crate::extensions::traits::LodashIterExt as _;
+
+    // Function wrappers
+    #[cfg(feature = "std")]
+    pub use crate::function::{
+        debounce, flow, memoize, negate, once, over_every, over_some, partial, throttle,
+    };
+
+    // Math methods
+    pub use crate::math::{max_by, mean, mean_by, min_by, sum, sum_by, Numeric};
+    #[cfg(feature = "std")]
+    pub use crate::math::{
+        histogram_by, median_by, mode_by, percentile_by, std_dev_by, variance_by, Bins,
+        PercentileMethod,
+    };
+
+    // String methods
+    #[cfg(feature = "std")]
+    pub use crate::string::{
+        camel_case, deburr, escape, kebab_case, pad, pad_end, pad_start, snake_case, start_case,
+        truncate, unescape, words, TruncateOptions,
     };
 
     // Chain operations
-    pub use crate::chain::chain;
+    pub use crate::chain::{chain, chain_iter, chain_owned, chain_ref, ChainRef};
+    #[cfg(feature = "serialize")]
+    pub use crate::chain::Pipeline;
+    #[cfg(feature = "serialize")]
+    pub use crate::utils::expr::{compile_mapper, compile_predicate, Expr};
+    #[cfg(feature = "serialize")]
+    pub use crate::io::{CsvReader, CsvWriter, NdjsonReader, NdjsonWriter};
     
     #[cfg(feature = "async")]
     pub use crate::chain::chain_async;
@@ -114,7 +186,9 @@ pub mod prelude {
     // Parallel versions
     #[cfg(feature = "parallel")]
     pub use crate::extensions::parallel::{
-        map_parallel, filter_parallel, reduce_parallel,
+        map_parallel, filter_parallel, reduce_parallel, for_each_parallel, find_parallel,
+        every_parallel, some_parallel, sort_by_partial_parallel, sort_by_float_parallel,
+        sort_by_cached_key_parallel, order_by_partial_parallel, order_by_float_parallel,
     };
 
     // Error types
@@ -127,6 +201,7 @@ pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 
 // Feature detection
 #[cfg(feature = "async")]
+/// Whether async features are enabled.
 pub const HAS_ASYNC: bool = true;
 
 #[cfg(not(feature = "async"))]
@@ -134,6 +209,7 @@ pub const HAS_ASYNC: bool = true;
 pub const HAS_ASYNC: bool = false;
 
 #[cfg(feature = "parallel")]
+/// Whether parallel processing features are enabled.
 pub const HAS_PARALLEL: bool = true;
 
 #[cfg(not(feature = "parallel"))]
@@ -141,6 +217,7 @@ pub const HAS_PARALLEL: bool = true;
 pub const HAS_PARALLEL: bool = false;
 
 #[cfg(feature = "wasm")]
+/// Whether WebAssembly features are enabled.
 pub const HAS_WASM: bool = true;
 
 #[cfg(not(feature = "wasm"))]
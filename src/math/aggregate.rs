@@ -0,0 +1,326 @@
+/*!
+Aggregate math methods for Lodash-RS.
+
+This module provides `sum`, `sum_by`, `mean`, `mean_by`, `min`, `min_by`,
+`max` and `max_by`. Methods that have no meaningful result for an empty
+collection return `LodashError::EmptyCollection`.
+*/
+
+use crate::collection::source::Source;
+use crate::math::Numeric;
+use crate::utils::{LodashError, Result};
+use core::cmp::Ordering;
+
+/// Compute the mean of `values` with Welford's running update, which avoids
+/// the overflow and precision loss of summing first.
+fn running_mean<I>(values: I) -> Result<f64>
+where
+    I: IntoIterator<Item = f64>,
+{
+    let mut count = 0.0;
+    let mut mean = 0.0;
+    for value in values {
+        count += 1.0;
+        mean += (value - mean) / count;
+    }
+    if count == 0.0 {
+        Err(LodashError::EmptyCollection)
+    } else {
+        Ok(mean)
+    }
+}
+
+/// Find the extremum of `items` by `key`, keeping the first item on ties and
+/// skipping keys that are not comparable with themselves (such as `NaN`).
+fn extremum_by<T, I, K, F>(items: I, key: F, wanted: Ordering) -> Result<T>
+where
+    I: IntoIterator<Item = T>,
+    K: PartialOrd,
+    F: Fn(&T) -> K,
+{
+    let mut best: Option<(T, K)> = None;
+    for item in items {
+        let computed = key(&item);
+        if computed.partial_cmp(&computed).is_none() {
+            continue;
+        }
+        let replace = match &best {
+            Some((_, current)) => computed.partial_cmp(current) == Some(wanted),
+            None => true,
+        };
+        if replace {
+            best = Some((item, computed));
+        }
+    }
+    best.map(|(item, _)| item)
+        .ok_or(LodashError::EmptyCollection)
+}
+
+/// Compute the sum of the values in `collection`. The sum of an empty
+/// collection is zero.
+///
+/// Float sums are compensated, so they are accurate even when values of very
+/// different magnitudes are mixed. Integer sums wrap around at the bounds of
+/// the type, in debug and release builds alike; use [`mean`] or sum values
+/// converted to a wider type when the total may not fit.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::math::sum;
+///
+/// assert_eq!(sum(&[4, 2, 8, 6]), 20);
+/// assert_eq!(sum(&[0.1; 10]), 1.0);
+/// assert_eq!(sum(&[] as &[i32]), 0);
+/// assert_eq!(sum(&[i32::MAX, 1]), i32::MIN);
+/// ```
+#[must_use]
+pub fn sum<'a, S>(collection: S) -> S::Item
+where
+    S: Source<'a>,
+    S::Item: Numeric,
+{
+    S::Item::sum_values(collection.source_iter().copied())
+}
+
+/// This method is like `sum` except that it accepts `iteratee` which is
+/// invoked for each element in `collection` to generate the value to be summed.
+/// Integer sums wrap around like `sum`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::math::sum_by;
+///
+/// let items = vec![("a", 4), ("b", 2), ("c", 8)];
+/// assert_eq!(sum_by(&items, |item| item.1), 14);
+/// ```
+pub fn sum_by<'a, S, N, F>(collection: S, iteratee: F) -> N
+where
+    S: Source<'a>,
+    N: Numeric,
+    F: Fn(&S::Item) -> N,
+{
+    N::sum_values(collection.source_iter().map(iteratee))
+}
+
+/// Compute the mean of the values in `collection`.
+///
+/// # Errors
+///
+/// Returns `LodashError::EmptyCollection` if `collection` is empty.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::math::mean;
+///
+/// assert_eq!(mean(&[4, 2, 8, 6]).unwrap(), 5.0);
+/// assert!(mean(&[] as &[f64]).is_err());
+/// ```
+pub fn mean<'a, S>(collection: S) -> Result<f64>
+where
+    S: Source<'a>,
+    S::Item: Numeric,
+{
+    running_mean(collection.source_iter().map(|value| value.to_f64()))
+}
+
+/// This method is like `mean` except that it accepts `iteratee` which is
+/// invoked for each element in `collection` to generate the value to be averaged.
+///
+/// # Errors
+///
+/// Returns `LodashError::EmptyCollection` if `collection` is empty.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::math::mean_by;
+///
+/// let items = vec![("a", 4), ("b", 2), ("c", 8), ("d", 6)];
+/// assert_eq!(mean_by(&items, |item| item.1).unwrap(), 5.0);
+/// ```
+pub fn mean_by<'a, S, N, F>(collection: S, iteratee: F) -> Result<f64>
+where
+    S: Source<'a>,
+    N: Numeric,
+    F: Fn(&S::Item) -> N,
+{
+    running_mean(collection.source_iter().map(|item| iteratee(item).to_f64()))
+}
+
+/// Compute the minimum value of `collection`. `NaN` values are ignored.
+///
+/// # Errors
+///
+/// Returns `LodashError::EmptyCollection` if `collection` has no comparable
+/// values.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::math::min;
+///
+/// assert_eq!(min(&[4, 2, 8, 6]).unwrap(), 2);
+/// assert_eq!(min(&[f64::NAN, 1.5]).unwrap(), 1.5);
+/// assert!(min(&[] as &[i32]).is_err());
+/// ```
+pub fn min<'a, S>(collection: S) -> Result<S::Item>
+where
+    S: Source<'a>,
+    S::Item: Numeric,
+{
+    extremum_by(collection.source_iter().copied(), |value| *value, Ordering::Less)
+}
+
+/// This method is like `min` except that it accepts `iteratee` which is
+/// invoked for each element in `collection` to generate the criterion by
+/// which the value is ranked. The first minimum is returned on ties.
+///
+/// # Errors
+///
+/// Returns `LodashError::EmptyCollection` if `collection` has no comparable
+/// values.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::math::min_by;
+///
+/// let items = vec![("a", 4), ("b", 2), ("c", 2)];
+/// assert_eq!(min_by(&items, |item| item.1).unwrap(), &("b", 2));
+/// ```
+pub fn min_by<'a, S, K, F>(collection: S, iteratee: F) -> Result<&'a S::Item>
+where
+    S: Source<'a>,
+    K: PartialOrd,
+    F: Fn(&S::Item) -> K,
+{
+    extremum_by(collection.source_iter(), |item| iteratee(item), Ordering::Less)
+}
+
+/// Compute the maximum value of `collection`. `NaN` values are ignored.
+///
+/// # Errors
+///
+/// Returns `LodashError::EmptyCollection` if `collection` has no comparable
+/// values.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::math::max;
+///
+/// assert_eq!(max(&[4, 2, 8, 6]).unwrap(), 8);
+/// assert!(max(&[] as &[f64]).is_err());
+/// ```
+pub fn max<'a, S>(collection: S) -> Result<S::Item>
+where
+    S: Source<'a>,
+    S::Item: Numeric,
+{
+    extremum_by(
+        collection.source_iter().copied(),
+        |value| *value,
+        Ordering::Greater,
+    )
+}
+
+/// This method is like `max` except that it accepts `iteratee` which is
+/// invoked for each element in `collection` to generate the criterion by
+/// which the value is ranked. The first maximum is returned on ties.
+///
+/// # Errors
+///
+/// Returns `LodashError::EmptyCollection` if `collection` has no comparable
+/// values.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::math::max_by;
+///
+/// let items = vec![("a", 4), ("b", 8), ("c", 8)];
+/// assert_eq!(max_by(&items, |item| item.1).unwrap(), &("b", 8));
+/// ```
+pub fn max_by<'a, S, K, F>(collection: S, iteratee: F) -> Result<&'a S::Item>
+where
+    S: Source<'a>,
+    K: PartialOrd,
+    F: Fn(&S::Item) -> K,
+{
+    extremum_by(collection.source_iter(), |item| iteratee(item), Ordering::Greater)
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_sum_integers_and_floats() {
+        assert_eq!(sum(&[1_u8, 2, 3]), 6);
+        assert_eq!(sum(&[-1_i64, 1, -1]), -1);
+        assert_eq!(sum(&[0.1_f32; 10]), 1.0);
+        assert_eq!(sum_by(&["a", "bb", "ccc"], |s| s.len()), 6);
+    }
+
+    #[test]
+    fn test_integer_sums_wrap() {
+        assert_eq!(sum(&[i32::MAX, 1]), i32::MIN);
+        assert_eq!(sum(&[200_u8, 100, 100]), 144);
+        assert_eq!(sum(&[i64::MIN, -1, 1]), i64::MIN);
+        assert_eq!(sum_by(&[u8::MAX, 1], |x| *x), 0);
+    }
+
+    #[test]
+    fn test_mean_is_stable() {
+        let values = vec![1e9 + 4.0, 1e9 + 7.0, 1e9 + 13.0, 1e9 + 16.0];
+        assert_eq!(mean(&values).unwrap(), 1e9 + 10.0);
+        assert_eq!(
+            mean(&[i64::MAX, i64::MAX]).unwrap(),
+            9.223_372_036_854_776e18
+        );
+        assert_eq!(mean_by(&[(1, 2.0), (2, 4.0)], |item| item.1).unwrap(), 3.0);
+    }
+
+    #[test]
+    fn test_empty_input_errors() {
+        assert_eq!(mean(&[] as &[i32]), Err(LodashError::EmptyCollection));
+        assert_eq!(min(&[] as &[f64]), Err(LodashError::EmptyCollection));
+        assert_eq!(max(&[f64::NAN]), Err(LodashError::EmptyCollection));
+        let empty: Vec<(i32, i32)> = Vec::new();
+        assert_eq!(
+            mean_by(&empty, |item| item.1),
+            Err(LodashError::EmptyCollection)
+        );
+        assert_eq!(
+            min_by(&empty, |item| item.1),
+            Err(LodashError::EmptyCollection)
+        );
+        assert_eq!(
+            max_by(&empty, |item| item.1),
+            Err(LodashError::EmptyCollection)
+        );
+    }
+
+    #[test]
+    fn test_min_max_skip_nan() {
+        let values = [3.0, f64::NAN, -1.0, 7.5];
+        assert_eq!(min(&values).unwrap(), -1.0);
+        assert_eq!(max(&values).unwrap(), 7.5);
+        let items = [("nan", f64::NAN), ("one", 1.0)];
+        assert_eq!(max_by(&items, |item| item.1).unwrap().0, "one");
+    }
+
+    #[test]
+    fn test_min_by_max_by_with_strings() {
+        let names = ["pebbles", "fred", "barney"];
+        assert_eq!(min_by(&names, |name| name.len()).unwrap(), &"fred");
+        assert_eq!(max_by(&names, ToString::to_string).unwrap(), &"pebbles");
+    }
+}
@@ -0,0 +1,31 @@
+/*!
+Math module for Lodash-RS.
+
+This module contains Lodash's math and number methods, such as `sum`, `mean`,
+`min_by`, `clamp` and `round`, built on the [`Numeric`] trait, along with
+statistical aggregations and streaming estimators.
+*/
+
+pub mod aggregate;
+pub mod number;
+pub mod numeric;
+#[cfg(feature = "std")]
+pub mod rounding;
+#[cfg(feature = "std")]
+pub mod statistics;
+#[cfg(feature = "std")]
+pub mod streaming;
+
+// Re-export commonly used items
+pub use aggregate::{max, max_by, mean, mean_by, min, min_by, sum, sum_by};
+pub use number::{clamp, in_range};
+pub use numeric::Numeric;
+#[cfg(feature = "std")]
+pub use rounding::{ceil, floor, round};
+#[cfg(feature = "std")]
+pub use statistics::{
+    histogram_by, median, median_by, mode_by, percentile_by, std_dev, std_dev_by, variance,
+    variance_by, Bins, Histogram, PercentileMethod, MAX_BINS,
+};
+#[cfg(feature = "std")]
+pub use streaming::{approx_percentile, P2Quantile, RunningStats};
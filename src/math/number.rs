@@ -0,0 +1,82 @@
+/*!
+Number methods for Lodash-RS.
+
+This module provides `clamp` and `in_range`.
+*/
+
+use crate::math::Numeric;
+
+/// Clamp `number` within the inclusive `lower` and `upper` bounds. `NaN` is
+/// returned unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::math::clamp;
+///
+/// assert_eq!(clamp(-10, -5, 5), -5);
+/// assert_eq!(clamp(10, -5, 5), 5);
+/// assert_eq!(clamp(2.5, 0.0, 1.0), 1.0);
+/// ```
+#[must_use]
+pub fn clamp<T>(number: T, lower: T, upper: T) -> T
+where
+    T: Numeric,
+{
+    if number.is_nan() {
+        return number;
+    }
+    let number = if number <= upper { number } else { upper };
+    if number >= lower {
+        number
+    } else {
+        lower
+    }
+}
+
+/// Check if `number` is between `start` and up to, but not including, `end`.
+/// If `start` is greater than `end` the bounds are swapped.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::math::in_range;
+///
+/// assert!(in_range(3, 2, 4));
+/// assert!(!in_range(4, 0, 4));
+/// assert!(in_range(-3, -2, -6));
+/// assert!(in_range(1.2, 0.0, 2.0));
+/// ```
+#[must_use]
+pub fn in_range<T>(number: T, start: T, end: T) -> bool
+where
+    T: Numeric,
+{
+    let (low, high) = if start > end {
+        (end, start)
+    } else {
+        (start, end)
+    };
+    number >= low && number < high
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clamp() {
+        assert_eq!(clamp(3_u8, 1, 5), 3);
+        assert_eq!(clamp(-0.5, -1.0, 1.0), -0.5);
+        assert!(clamp(f64::NAN, 0.0, 1.0).is_nan());
+    }
+
+    #[test]
+    fn test_in_range() {
+        assert!(in_range(0, 0, 1));
+        assert!(!in_range(1, 0, 1));
+        assert!(!in_range(5, 5, 5));
+        assert!(!in_range(f64::NAN, 0.0, 1.0));
+    }
+}
@@ -0,0 +1,195 @@
+/*!
+Numeric trait for Lodash-RS.
+
+This module provides `Numeric`, the abstraction over the primitive integer
+and float types used by the math methods.
+*/
+
+use core::fmt::Debug;
+use core::ops::{Add, Div, Mul, Sub};
+
+/// Trait for the primitive numeric types supported by the math methods.
+///
+/// Implemented for all primitive integer types and for `f32` and `f64`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::math::Numeric;
+///
+/// assert_eq!(<i32 as Numeric>::ZERO, 0);
+/// assert_eq!(3_u8.to_f64(), 3.0);
+/// assert_eq!(<i16 as Numeric>::from_f64(2.7), 2);
+/// assert!(f64::NAN.is_nan());
+/// ```
+pub trait Numeric:
+    Copy
+    + PartialOrd
+    + Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+{
+    /// The additive identity.
+    const ZERO: Self;
+
+    /// The multiplicative identity.
+    const ONE: Self;
+
+    /// Whether the type is a floating point type.
+    const IS_FLOAT: bool;
+
+    /// Convert the value to an `f64`, losing precision for large integers.
+    fn to_f64(self) -> f64;
+
+    /// Convert an `f64` to this type. Integer conversions truncate toward
+    /// zero and saturate at the bounds of the type; `NaN` converts to zero.
+    fn from_f64(value: f64) -> Self;
+
+    /// Whether the value is `NaN`. Always `false` for integers.
+    fn is_nan(self) -> bool;
+
+    /// Add `other`, wrapping around at the bounds of integer types. Floats
+    /// add normally.
+    #[must_use]
+    fn wrapping_add(self, other: Self) -> Self {
+        self + other
+    }
+
+    /// Subtract `other`, wrapping around at the bounds of integer types.
+    /// Floats subtract normally.
+    #[must_use]
+    fn wrapping_sub(self, other: Self) -> Self {
+        self - other
+    }
+
+    /// Add `value` to a running `(total, compensation)` sum. Floats use
+    /// Neumaier's compensated summation to limit rounding error; integers
+    /// wrap around at the bounds of the type instead of panicking.
+    fn sum_step(state: &mut (Self, Self), value: Self) {
+        state.0 = state.0.wrapping_add(value);
+    }
+
+    /// Finish a running sum started from `(ZERO, ZERO)`.
+    fn sum_finish(state: (Self, Self)) -> Self {
+        state.0
+    }
+
+    /// Sum `values` with `sum_step` and `sum_finish`.
+    fn sum_values<I>(values: I) -> Self
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        let mut state = (Self::ZERO, Self::ZERO);
+        for value in values {
+            Self::sum_step(&mut state, value);
+        }
+        Self::sum_finish(state)
+    }
+}
+
+macro_rules! impl_numeric_int {
+    ($($t:ty),*) => {
+        $(
+            #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss, clippy::cast_sign_loss, clippy::cast_lossless)]
+            impl Numeric for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+                const IS_FLOAT: bool = false;
+
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                fn from_f64(value: f64) -> Self {
+                    value as Self
+                }
+
+                fn is_nan(self) -> bool {
+                    false
+                }
+
+                fn wrapping_add(self, other: Self) -> Self {
+                    <$t>::wrapping_add(self, other)
+                }
+
+                fn wrapping_sub(self, other: Self) -> Self {
+                    <$t>::wrapping_sub(self, other)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_numeric_float {
+    ($($t:ty),*) => {
+        $(
+            #[allow(clippy::cast_possible_truncation, clippy::cast_lossless)]
+            impl Numeric for $t {
+                const ZERO: Self = 0.0;
+                const ONE: Self = 1.0;
+                const IS_FLOAT: bool = true;
+
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                fn from_f64(value: f64) -> Self {
+                    value as Self
+                }
+
+                fn is_nan(self) -> bool {
+                    <$t>::is_nan(self)
+                }
+
+                fn sum_step(state: &mut (Self, Self), value: Self) {
+                    // `abs` is only in `core` on newer toolchains.
+                    let magnitude = |x: Self| if x < 0.0 { -x } else { x };
+                    let (total, compensation) = state;
+                    let next = *total + value;
+                    if magnitude(*total) >= magnitude(value) {
+                        *compensation += (*total - next) + value;
+                    } else {
+                        *compensation += (value - next) + *total;
+                    }
+                    *total = next;
+                }
+
+                fn sum_finish((total, compensation): (Self, Self)) -> Self {
+                    // Infinities and NaN poison the compensation term.
+                    if total.is_finite() {
+                        total + compensation
+                    } else {
+                        total
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_numeric_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_numeric_float!(f32, f64);
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_f64_saturates() {
+        assert_eq!(<u8 as Numeric>::from_f64(300.0), 255);
+        assert_eq!(<u8 as Numeric>::from_f64(-1.0), 0);
+        assert_eq!(<i32 as Numeric>::from_f64(f64::NAN), 0);
+    }
+
+    #[test]
+    fn test_compensated_sum() {
+        let values = [1.0, 1e100, 1.0, -1e100];
+        assert_eq!(f64::sum_values(values), 2.0);
+        assert_eq!(values.iter().sum::<f64>(), 0.0);
+        assert_eq!(f64::sum_values([1.0, f64::INFINITY]), f64::INFINITY);
+        assert!(f64::sum_values([f64::INFINITY, f64::NEG_INFINITY]).is_nan());
+    }
+}
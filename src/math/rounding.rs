@@ -0,0 +1,135 @@
+/*!
+Rounding methods for Lodash-RS.
+
+This module provides `round`, `floor` and `ceil` with a decimal precision.
+Like Lodash, the number is shifted by the precision in its decimal string
+form rather than multiplied by a power of ten, so `round(1.005, 2)` is `1.01`.
+*/
+
+use crate::math::Numeric;
+
+/// Lodash's precision bound, which keeps shifted values within `f64` range.
+const MAX_PRECISION: i32 = 292;
+
+/// Shift the decimal exponent of `value` by `exponent`.
+fn shift(value: f64, exponent: i32) -> f64 {
+    format!("{value}e{exponent}").parse().unwrap_or(f64::NAN)
+}
+
+/// `Math.round`, which rounds halves toward positive infinity.
+fn round_half_up(value: f64) -> f64 {
+    let floor = value.floor();
+    if value - floor >= 0.5 {
+        floor + 1.0
+    } else {
+        floor
+    }
+}
+
+fn create_round<T>(number: T, precision: i32, func: fn(f64) -> f64) -> T
+where
+    T: Numeric,
+{
+    if !T::IS_FLOAT && precision >= 0 {
+        return number;
+    }
+    let value = number.to_f64();
+    if !value.is_finite() {
+        return number;
+    }
+    let precision = precision.clamp(-MAX_PRECISION, MAX_PRECISION);
+    if precision == 0 {
+        return T::from_f64(func(value));
+    }
+    T::from_f64(shift(func(shift(value, precision)), -precision))
+}
+
+/// Compute `number` rounded to `precision` decimal places. A negative
+/// precision rounds to the left of the decimal point. Halves are rounded
+/// toward positive infinity, as in JavaScript.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::math::round;
+///
+/// assert_eq!(round(4.006, 0), 4.0);
+/// assert_eq!(round(4.006, 2), 4.01);
+/// assert_eq!(round(1.005, 2), 1.01);
+/// assert_eq!(round(4060, -2), 4100);
+/// assert_eq!(round(-2.5, 0), -2.0);
+/// ```
+#[must_use]
+pub fn round<T>(number: T, precision: i32) -> T
+where
+    T: Numeric,
+{
+    create_round(number, precision, round_half_up)
+}
+
+/// Compute `number` rounded down to `precision` decimal places.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::math::floor;
+///
+/// assert_eq!(floor(4.006, 0), 4.0);
+/// assert_eq!(floor(0.046, 2), 0.04);
+/// assert_eq!(floor(4060, -2), 4000);
+/// ```
+#[must_use]
+pub fn floor<T>(number: T, precision: i32) -> T
+where
+    T: Numeric,
+{
+    create_round(number, precision, f64::floor)
+}
+
+/// Compute `number` rounded up to `precision` decimal places.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::math::ceil;
+///
+/// assert_eq!(ceil(4.006, 0), 5.0);
+/// assert_eq!(ceil(6.004, 2), 6.01);
+/// assert_eq!(ceil(6040, -2), 6100);
+/// ```
+#[must_use]
+pub fn ceil<T>(number: T, precision: i32) -> T
+where
+    T: Numeric,
+{
+    create_round(number, precision, f64::ceil)
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_precision() {
+        assert_eq!(round(4.016, 2), 4.02);
+        assert_eq!(round(4.1, -1), 0.0);
+        assert_eq!(round(5.0_f32, -1), 10.0);
+        assert_eq!(round(1e21, 2), 1e21);
+        assert_eq!(round(1.234_5e-7, 10), 1.235e-7);
+    }
+
+    #[test]
+    fn test_round_non_finite_and_integers() {
+        assert!(round(f64::NAN, 2).is_nan());
+        assert_eq!(ceil(f64::INFINITY, 2), f64::INFINITY);
+        assert_eq!(floor(-7_i32, 3), -7);
+        assert_eq!(round(u64::MAX, 0), u64::MAX);
+    }
+
+    #[test]
+    fn test_precision_is_bounded() {
+        assert_eq!(round(1.5, 400), 1.5);
+        assert_eq!(round(1.5, -400), 0.0);
+    }
+}
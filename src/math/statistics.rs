@@ -0,0 +1,697 @@
+/*!
+Statistical methods for Lodash-RS.
+
+This module provides `median`, `median_by`, `percentile_by`, `variance`,
+`variance_by`, `std_dev`, `std_dev_by`, `mode_by` and `histogram_by`.
+`NaN` values are ignored, and methods with no meaningful result for an empty
+collection return `LodashError::EmptyCollection`.
+*/
+
+use crate::collection::Collection;
+use crate::math::streaming::RunningStats;
+use crate::math::Numeric;
+use crate::utils::{LodashError, Result};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// How `percentile_by` picks a value when the percentile falls between two
+/// ranks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum PercentileMethod {
+    /// Interpolate linearly between the two closest ranks.
+    #[default]
+    Linear,
+    /// Use the smallest value such that at least the given percentage of the
+    /// values is less than or equal to it.
+    NearestRank,
+}
+
+/// How `histogram_by` chooses its bins.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum Bins {
+    /// A fixed number of equal-width bins spanning the data.
+    Count(usize),
+    /// Equal-width bins of the given width, starting at the minimum value.
+    Width(f64),
+    /// Bin width chosen by the Freedman-Diaconis rule, falling back to
+    /// Sturges' rule when the interquartile range is zero.
+    #[default]
+    Auto,
+}
+
+/// A histogram produced by `histogram_by`.
+///
+/// Bin `i` covers `edges[i]..edges[i + 1]`; the last bin also includes its
+/// upper edge.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct Histogram {
+    /// The bin edges, one more than the number of bins.
+    pub edges: Vec<f64>,
+    /// The number of values in each bin.
+    pub counts: Vec<usize>,
+}
+
+impl Histogram {
+    /// The total number of values counted.
+    #[must_use]
+    pub fn total(&self) -> usize {
+        self.counts.iter().sum()
+    }
+
+    /// Iterate over `(start, end, count)` for each bin.
+    pub fn bins(&self) -> impl Iterator<Item = (f64, f64, usize)> + '_ {
+        self.edges
+            .windows(2)
+            .zip(&self.counts)
+            .map(|(edge, &count)| (edge[0], edge[1], count))
+    }
+
+    /// The index of the bin `value` falls into, if any.
+    pub(crate) fn bin_index(edges: &[f64], value: f64) -> Option<usize> {
+        let bins = edges.len() - 1;
+        let (low, high) = (edges[0], edges[bins]);
+        if !(low..=high).contains(&value) {
+            return None;
+        }
+        let index = edges.partition_point(|&edge| edge <= value);
+        Some(index.saturating_sub(1).min(bins - 1))
+    }
+}
+
+/// Validate a percentile between 0 and 100 and convert it to a fraction.
+pub(crate) fn check_percentile(percentile: f64) -> Result<f64> {
+    if (0.0..=100.0).contains(&percentile) {
+        Ok(percentile / 100.0)
+    } else {
+        Err(LodashError::invalid_input(format!(
+            "percentile must be between 0 and 100, got {percentile}"
+        )))
+    }
+}
+
+/// Map `collection` through `iteratee`, dropping `NaN` values.
+pub(crate) fn finite_values<T, N, F>(collection: &[T], iteratee: F) -> Vec<f64>
+where
+    N: Numeric,
+    F: Fn(&T) -> N,
+{
+    collection
+        .iter()
+        .map(|item| iteratee(item).to_f64())
+        .filter(|value| !value.is_nan())
+        .collect()
+}
+
+/// Compute the percentile at `fraction` of non-empty `sorted` values.
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+pub(crate) fn percentile_of_sorted(sorted: &[f64], fraction: f64, method: PercentileMethod) -> f64 {
+    let last = sorted.len() - 1;
+    match method {
+        PercentileMethod::Linear => {
+            let rank = fraction * last as f64;
+            let lower = rank.floor() as usize;
+            let upper = rank.ceil() as usize;
+            sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+        }
+        PercentileMethod::NearestRank => {
+            let rank = (fraction * sorted.len() as f64).ceil() as usize;
+            sorted[rank.clamp(1, sorted.len()) - 1]
+        }
+    }
+}
+
+/// The largest number of bins `histogram_by` will create.
+pub const MAX_BINS: usize = 1 << 20;
+
+/// Compute the bin edges for non-empty `sorted` values.
+pub(crate) fn histogram_edges(sorted: &[f64], bins: Bins) -> Result<Vec<f64>> {
+    let (low, high) = (sorted[0], sorted[sorted.len() - 1]);
+    let range = high - low;
+    if !range.is_finite() {
+        return Err(LodashError::invalid_input(format!(
+            "histogram values must be finite, got a range of {low} to {high}"
+        )));
+    }
+    #[allow(clippy::cast_precision_loss)]
+    let count = match bins {
+        Bins::Count(0) => {
+            return Err(LodashError::invalid_input("bin count must be positive"));
+        }
+        Bins::Count(count) => count as f64,
+        Bins::Width(width) if !(width.is_finite() && width > 0.0) => {
+            return Err(LodashError::invalid_input(format!(
+                "bin width must be positive and finite, got {width}"
+            )));
+        }
+        Bins::Width(width) => (range / width).floor() + 1.0,
+        Bins::Auto => {
+            let n = sorted.len() as f64;
+            let iqr = percentile_of_sorted(sorted, 0.75, PercentileMethod::Linear)
+                - percentile_of_sorted(sorted, 0.25, PercentileMethod::Linear);
+            if iqr > 0.0 {
+                let width = 2.0 * iqr / n.cbrt();
+                (range / width).ceil().max(1.0)
+            } else {
+                n.log2().ceil() + 1.0
+            }
+        }
+    };
+    #[allow(clippy::cast_precision_loss)]
+    if count > MAX_BINS as f64 {
+        return Err(LodashError::invalid_input(format!(
+            "histogram needs {count} bins, more than the limit of {MAX_BINS}"
+        )));
+    }
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let count = count as usize;
+    #[allow(clippy::cast_precision_loss)]
+    let edges = match bins {
+        Bins::Width(width) => (0..=count).map(|i| low + width * i as f64).collect(),
+        _ if range == 0.0 => (0..=count).map(|i| low + i as f64).collect(),
+        _ => (0..=count)
+            .map(|i| low + range * i as f64 / count as f64)
+            .collect(),
+    };
+    Ok(edges)
+}
+
+/// Build a histogram of non-empty `sorted` values.
+pub(crate) fn histogram_of_sorted(sorted: &[f64], bins: Bins) -> Result<Histogram> {
+    let edges = histogram_edges(sorted, bins)?;
+    let mut counts = vec![0; edges.len() - 1];
+    for &value in sorted {
+        if let Some(index) = Histogram::bin_index(&edges, value) {
+            counts[index] += 1;
+        }
+    }
+    Ok(Histogram { edges, counts })
+}
+
+/// Pick the modal key from `(key, count, first_index)` entries, preferring
+/// the key seen first on ties.
+pub(crate) fn modal_key<K>(counts: impl IntoIterator<Item = (K, (usize, usize))>) -> Result<K> {
+    counts
+        .into_iter()
+        .max_by(|(_, (count_a, first_a)), (_, (count_b, first_b))| {
+            count_a.cmp(count_b).then(first_b.cmp(first_a))
+        })
+        .map(|(key, _)| key)
+        .ok_or(LodashError::EmptyCollection)
+}
+
+fn sorted_values<T, N, F>(collection: &[T], iteratee: F) -> Result<Vec<f64>>
+where
+    N: Numeric,
+    F: Fn(&T) -> N,
+{
+    let mut values = finite_values(collection, iteratee);
+    if values.is_empty() {
+        return Err(LodashError::EmptyCollection);
+    }
+    values.sort_unstable_by(f64::total_cmp);
+    Ok(values)
+}
+
+/// Compute the median of the values in `collection`.
+///
+/// # Errors
+///
+/// Returns `LodashError::EmptyCollection` if `collection` has no non-`NaN`
+/// values.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::math::median;
+///
+/// assert_eq!(median(&[3, 1, 2]).unwrap(), 2.0);
+/// assert_eq!(median(&[4, 1, 3, 2]).unwrap(), 2.5);
+/// ```
+pub fn median<T>(collection: &[T]) -> Result<f64>
+where
+    T: Numeric,
+{
+    median_by(collection, |value| *value)
+}
+
+/// This method is like `median` except that it accepts `iteratee` which is
+/// invoked for each element in `collection` to generate the value.
+///
+/// # Errors
+///
+/// Returns `LodashError::EmptyCollection` if `collection` has no non-`NaN`
+/// values.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::math::median_by;
+///
+/// let items = vec![("a", 10), ("b", 30), ("c", 20)];
+/// assert_eq!(median_by(&items, |item| item.1).unwrap(), 20.0);
+/// ```
+pub fn median_by<T, N, F>(collection: &[T], iteratee: F) -> Result<f64>
+where
+    N: Numeric,
+    F: Fn(&T) -> N,
+{
+    percentile_by(collection, 50.0, PercentileMethod::Linear, iteratee)
+}
+
+/// Compute the `percentile` (between 0 and 100) of the values generated by
+/// running each element of `collection` through `iteratee`.
+///
+/// # Errors
+///
+/// Returns `LodashError::InvalidInput` if `percentile` is not between 0 and
+/// 100, and `LodashError::EmptyCollection` if there are no non-`NaN` values.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::math::{percentile_by, PercentileMethod};
+///
+/// let values = vec![15, 20, 35, 40, 50];
+/// assert_eq!(percentile_by(&values, 40.0, PercentileMethod::Linear, |x| *x).unwrap(), 29.0);
+/// assert_eq!(percentile_by(&values, 40.0, PercentileMethod::NearestRank, |x| *x).unwrap(), 20.0);
+/// ```
+pub fn percentile_by<T, N, F>(
+    collection: &[T],
+    percentile: f64,
+    method: PercentileMethod,
+    iteratee: F,
+) -> Result<f64>
+where
+    N: Numeric,
+    F: Fn(&T) -> N,
+{
+    let fraction = check_percentile(percentile)?;
+    let values = sorted_values(collection, iteratee)?;
+    Ok(percentile_of_sorted(&values, fraction, method))
+}
+
+/// Compute the population variance of the values in `collection`.
+///
+/// Use [`RunningStats`](crate::math::RunningStats) for the sample variance.
+///
+/// # Errors
+///
+/// Returns `LodashError::EmptyCollection` if `collection` has no non-`NaN`
+/// values.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::math::variance;
+///
+/// assert_eq!(variance(&[2, 4, 4, 4, 5, 5, 7, 9]).unwrap(), 4.0);
+/// ```
+pub fn variance<T>(collection: &[T]) -> Result<f64>
+where
+    T: Numeric,
+{
+    variance_by(collection, |value| *value)
+}
+
+/// This method is like `variance` except that it accepts `iteratee` which is
+/// invoked for each element in `collection` to generate the value.
+///
+/// # Errors
+///
+/// Returns `LodashError::EmptyCollection` if `collection` has no non-`NaN`
+/// values.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::math::variance_by;
+///
+/// let items = vec![("a", 1.0), ("b", 3.0)];
+/// assert_eq!(variance_by(&items, |item| item.1).unwrap(), 1.0);
+/// ```
+pub fn variance_by<T, N, F>(collection: &[T], iteratee: F) -> Result<f64>
+where
+    N: Numeric,
+    F: Fn(&T) -> N,
+{
+    let stats: RunningStats = collection
+        .iter()
+        .map(|item| iteratee(item).to_f64())
+        .collect();
+    stats.variance().ok_or(LodashError::EmptyCollection)
+}
+
+/// Compute the population standard deviation of the values in `collection`.
+///
+/// # Errors
+///
+/// Returns `LodashError::EmptyCollection` if `collection` has no non-`NaN`
+/// values.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::math::std_dev;
+///
+/// assert_eq!(std_dev(&[2, 4, 4, 4, 5, 5, 7, 9]).unwrap(), 2.0);
+/// ```
+pub fn std_dev<T>(collection: &[T]) -> Result<f64>
+where
+    T: Numeric,
+{
+    std_dev_by(collection, |value| *value)
+}
+
+/// This method is like `std_dev` except that it accepts `iteratee` which is
+/// invoked for each element in `collection` to generate the value.
+///
+/// # Errors
+///
+/// Returns `LodashError::EmptyCollection` if `collection` has no non-`NaN`
+/// values.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::math::std_dev_by;
+///
+/// let items = vec![("a", 1.0), ("b", 3.0)];
+/// assert_eq!(std_dev_by(&items, |item| item.1).unwrap(), 1.0);
+/// ```
+pub fn std_dev_by<T, N, F>(collection: &[T], iteratee: F) -> Result<f64>
+where
+    N: Numeric,
+    F: Fn(&T) -> N,
+{
+    variance_by(collection, iteratee).map(f64::sqrt)
+}
+
+/// Find the most frequent key generated by running each element of
+/// `collection` through `iteratee`. On ties the key seen first wins.
+///
+/// # Errors
+///
+/// Returns `LodashError::EmptyCollection` if `collection` is empty.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::math::mode_by;
+///
+/// let words = vec!["one", "two", "three", "four", "five"];
+/// assert_eq!(mode_by(&words, |word| word.len()).unwrap(), 3);
+/// assert_eq!(mode_by(&words, |word| word.chars().next()).unwrap(), Some('t'));
+/// ```
+pub fn mode_by<T, K, F>(collection: &[T], iteratee: F) -> Result<K>
+where
+    K: Eq + Hash,
+    F: Fn(&T) -> K,
+{
+    let mut counts: HashMap<K, (usize, usize)> = HashMap::new();
+    for (index, item) in collection.iter().enumerate() {
+        counts.entry(iteratee(item)).or_insert((0, index)).0 += 1;
+    }
+    modal_key(counts)
+}
+
+/// Build a histogram of the values generated by running each element of
+/// `collection` through `iteratee`.
+///
+/// # Errors
+///
+/// Returns `LodashError::InvalidInput` for a zero bin count, a bin width
+/// that is not positive and finite, infinite values, or more than
+/// [`MAX_BINS`] bins, and `LodashError::EmptyCollection` if there are no
+/// non-`NaN` values.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::math::{histogram_by, Bins};
+///
+/// let values = vec![1.0, 2.0, 2.5, 4.0, 9.0];
+/// let histogram = histogram_by(&values, Bins::Count(4), |x| *x).unwrap();
+/// assert_eq!(histogram.edges, vec![1.0, 3.0, 5.0, 7.0, 9.0]);
+/// assert_eq!(histogram.counts, vec![3, 1, 0, 1]);
+///
+/// let histogram = histogram_by(&values, Bins::Width(5.0), |x| *x).unwrap();
+/// assert_eq!(histogram.counts, vec![4, 1]);
+/// ```
+pub fn histogram_by<T, N, F>(collection: &[T], bins: Bins, iteratee: F) -> Result<Histogram>
+where
+    N: Numeric,
+    F: Fn(&T) -> N,
+{
+    let values = sorted_values(collection, iteratee)?;
+    histogram_of_sorted(&values, bins)
+}
+
+/// Statistical methods that work on the `Collection` type.
+impl<T> Collection<T> {
+    /// Compute the median of the values generated by `iteratee`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LodashError::EmptyCollection` if there are no non-`NaN` values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::collection::Collection;
+    ///
+    /// let collection = Collection::new(vec![5, 1, 3]);
+    /// assert_eq!(collection.median_by(|x| *x).unwrap(), 3.0);
+    /// ```
+    pub fn median_by<N, F>(&self, iteratee: F) -> Result<f64>
+    where
+        N: Numeric,
+        F: Fn(&T) -> N,
+    {
+        median_by(self.data(), iteratee)
+    }
+
+    /// Compute the `percentile` of the values generated by `iteratee`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LodashError::InvalidInput` if `percentile` is not between 0
+    /// and 100, and `LodashError::EmptyCollection` if there are no non-`NaN`
+    /// values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::collection::Collection;
+    /// use rust_lodash::math::PercentileMethod;
+    ///
+    /// let collection = Collection::new(vec![1, 2, 3, 4, 5]);
+    /// assert_eq!(collection.percentile_by(75.0, PercentileMethod::Linear, |x| *x).unwrap(), 4.0);
+    /// ```
+    pub fn percentile_by<N, F>(
+        &self,
+        percentile: f64,
+        method: PercentileMethod,
+        iteratee: F,
+    ) -> Result<f64>
+    where
+        N: Numeric,
+        F: Fn(&T) -> N,
+    {
+        percentile_by(self.data(), percentile, method, iteratee)
+    }
+
+    /// Compute the population variance of the values generated by `iteratee`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LodashError::EmptyCollection` if there are no non-`NaN` values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::collection::Collection;
+    ///
+    /// let collection = Collection::new(vec![1, 3]);
+    /// assert_eq!(collection.variance_by(|x| *x).unwrap(), 1.0);
+    /// ```
+    pub fn variance_by<N, F>(&self, iteratee: F) -> Result<f64>
+    where
+        N: Numeric,
+        F: Fn(&T) -> N,
+    {
+        variance_by(self.data(), iteratee)
+    }
+
+    /// Compute the population standard deviation of the values generated by
+    /// `iteratee`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LodashError::EmptyCollection` if there are no non-`NaN` values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::collection::Collection;
+    ///
+    /// let collection = Collection::new(vec![1, 3]);
+    /// assert_eq!(collection.std_dev_by(|x| *x).unwrap(), 1.0);
+    /// ```
+    pub fn std_dev_by<N, F>(&self, iteratee: F) -> Result<f64>
+    where
+        N: Numeric,
+        F: Fn(&T) -> N,
+    {
+        std_dev_by(self.data(), iteratee)
+    }
+
+    /// Find the most frequent key generated by `iteratee`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LodashError::EmptyCollection` if the collection is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::collection::Collection;
+    ///
+    /// let collection = Collection::new(vec![1, 2, 2, 3]);
+    /// assert_eq!(collection.mode_by(|x| *x).unwrap(), 2);
+    /// ```
+    pub fn mode_by<K, F>(&self, iteratee: F) -> Result<K>
+    where
+        K: Eq + Hash,
+        F: Fn(&T) -> K,
+    {
+        mode_by(self.data(), iteratee)
+    }
+
+    /// Build a histogram of the values generated by `iteratee`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LodashError::InvalidInput` for invalid `bins`, and
+    /// `LodashError::EmptyCollection` if there are no non-`NaN` values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::collection::Collection;
+    /// use rust_lodash::math::Bins;
+    ///
+    /// let collection = Collection::new(vec![1, 2, 3, 4]);
+    /// let histogram = collection.histogram_by(Bins::Count(2), |x| *x).unwrap();
+    /// assert_eq!(histogram.counts, vec![2, 2]);
+    /// ```
+    pub fn histogram_by<N, F>(&self, bins: Bins, iteratee: F) -> Result<Histogram>
+    where
+        N: Numeric,
+        F: Fn(&T) -> N,
+    {
+        histogram_by(self.data(), bins, iteratee)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_percentile_methods() {
+        let values = [3, 6, 7, 8, 8, 10, 13, 15, 16, 20];
+        let linear = |p| percentile_by(&values, p, PercentileMethod::Linear, |x| *x).unwrap();
+        let nearest = |p| percentile_by(&values, p, PercentileMethod::NearestRank, |x| *x).unwrap();
+        assert_eq!(linear(0.0), 3.0);
+        assert_eq!(linear(25.0), 7.25);
+        assert_eq!(linear(100.0), 20.0);
+        assert_eq!(nearest(0.0), 3.0);
+        assert_eq!(nearest(25.0), 7.0);
+        assert_eq!(nearest(50.0), 8.0);
+        assert_eq!(nearest(100.0), 20.0);
+    }
+
+    #[test]
+    fn test_percentile_errors_and_nan() {
+        assert!(matches!(
+            percentile_by(&[1], -1.0, PercentileMethod::Linear, |x| *x),
+            Err(LodashError::InvalidInput { .. })
+        ));
+        assert!(matches!(
+            percentile_by(&[1], f64::NAN, PercentileMethod::Linear, |x| *x),
+            Err(LodashError::InvalidInput { .. })
+        ));
+        assert_eq!(median(&[f64::NAN, 1.0, 3.0]), Ok(2.0));
+        assert_eq!(median(&[f64::NAN]), Err(LodashError::EmptyCollection));
+        assert_eq!(median::<i32>(&[]), Err(LodashError::EmptyCollection));
+    }
+
+    #[test]
+    fn test_variance_and_std_dev() {
+        assert_eq!(variance(&[5]).unwrap(), 0.0);
+        assert_eq!(std_dev(&[1.0, f64::NAN, 3.0]).unwrap(), 1.0);
+        assert_eq!(variance::<f32>(&[]), Err(LodashError::EmptyCollection));
+    }
+
+    #[test]
+    fn test_mode_by_prefers_first_on_ties() {
+        assert_eq!(mode_by(&[3, 1, 1, 3], |x| *x).unwrap(), 3);
+        assert_eq!(
+            mode_by(&[1.5_f64, 2.5, 2.5], |x| x.to_bits()).unwrap(),
+            2.5_f64.to_bits()
+        );
+        assert_eq!(
+            mode_by::<i32, i32, _>(&[], |x| *x),
+            Err(LodashError::EmptyCollection)
+        );
+    }
+
+    #[test]
+    fn test_histogram_bins() {
+        let same = histogram_by(&[2, 2, 2], Bins::Auto, |x| *x).unwrap();
+        assert_eq!(same.total(), 3);
+        assert_eq!(same.counts[0], 3);
+
+        let values: Vec<u32> = (0..100).collect();
+        let auto = histogram_by(&values, Bins::Auto, |x| *x).unwrap();
+        assert_eq!(auto.total(), 100);
+        assert_eq!(auto.edges.first(), Some(&0.0));
+        assert_eq!(auto.edges.last(), Some(&99.0));
+
+        let width = histogram_by(&[0.0, 0.5, 1.0], Bins::Width(0.5), |x| *x).unwrap();
+        assert_eq!(width.edges, vec![0.0, 0.5, 1.0, 1.5]);
+        assert_eq!(width.counts, vec![1, 1, 1]);
+        let bins: Vec<_> = width.bins().collect();
+        assert_eq!(bins[0], (0.0, 0.5, 1));
+
+        assert!(histogram_by(&[1], Bins::Count(0), |x| *x).is_err());
+        assert!(histogram_by(&[1], Bins::Width(-1.0), |x| *x).is_err());
+    }
+
+    #[test]
+    fn test_histogram_limits() {
+        let invalid = |values: &[f64], bins| {
+            matches!(
+                histogram_by(values, bins, |x| *x),
+                Err(LodashError::InvalidInput { .. })
+            )
+        };
+        assert!(invalid(&[0.0, f64::INFINITY], Bins::Width(1.0)));
+        assert!(invalid(&[f64::NEG_INFINITY, 0.0], Bins::Auto));
+        assert!(invalid(&[-f64::MAX, f64::MAX], Bins::Count(2)));
+        assert!(invalid(&[0.0, 1e15], Bins::Width(1.0)));
+        assert!(invalid(&[0.0, 1.0], Bins::Count(MAX_BINS + 1)));
+        assert!(invalid(&[0.0, 1.0, 2.0, 3.0, 1e300], Bins::Auto));
+
+        let widest = histogram_by(&[0.0, 1.0], Bins::Count(MAX_BINS), |x| *x).unwrap();
+        assert_eq!(widest.counts.len(), MAX_BINS);
+    }
+}
@@ -0,0 +1,384 @@
+/*!
+Streaming statistics for Lodash-RS.
+
+This module provides single-pass estimators for inputs that are too large to
+collect and sort: `RunningStats` (Welford's mean and variance) and
+`P2Quantile` (the P² quantile estimator of Jain and Chlamtac), plus
+`approx_percentile` built on top of it.
+*/
+
+use crate::math::statistics::{check_percentile, percentile_of_sorted, PercentileMethod};
+use crate::math::Numeric;
+use crate::utils::{LodashError, Result};
+
+/// Running count, mean, variance, minimum and maximum of a stream of values,
+/// computed with Welford's algorithm. Two accumulators can be merged, which
+/// makes it suitable for parallel reductions. `NaN` values are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::math::RunningStats;
+///
+/// let stats: RunningStats = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0].into_iter().collect();
+/// assert_eq!(stats.count(), 8);
+/// assert_eq!(stats.mean(), Some(5.0));
+/// assert_eq!(stats.variance(), Some(4.0));
+/// assert_eq!(stats.std_dev(), Some(2.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct RunningStats {
+    count: u64,
+    mean: f64,
+    m2: f64,
+    min: f64,
+    max: f64,
+}
+
+impl RunningStats {
+    /// Create an empty accumulator.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `value` to the accumulator.
+    pub fn push(&mut self, value: f64) {
+        if value.is_nan() {
+            return;
+        }
+        if self.count == 0 {
+            self.min = value;
+            self.max = value;
+        } else {
+            self.min = self.min.min(value);
+            self.max = self.max.max(value);
+        }
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count_f64();
+        self.m2 += delta * (value - self.mean);
+    }
+
+    /// Merge `other` into this accumulator, using Chan's parallel update.
+    pub fn merge(&mut self, other: &Self) {
+        if other.count == 0 {
+            return;
+        }
+        if self.count == 0 {
+            *self = *other;
+            return;
+        }
+        let count = self.count + other.count;
+        #[allow(clippy::cast_precision_loss)]
+        let (total, theirs) = (count as f64, other.count as f64);
+        let delta = other.mean - self.mean;
+        self.mean += delta * theirs / total;
+        self.m2 += other.m2 + delta * delta * self.count_f64() * theirs / total;
+        self.count = count;
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+    }
+
+    /// The number of values pushed.
+    #[must_use]
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// The mean, or `None` if no values were pushed.
+    #[must_use]
+    pub fn mean(&self) -> Option<f64> {
+        (self.count > 0).then_some(self.mean)
+    }
+
+    /// The population variance, or `None` if no values were pushed.
+    #[must_use]
+    pub fn variance(&self) -> Option<f64> {
+        (self.count > 0).then(|| self.m2 / self.count_f64())
+    }
+
+    /// The sample variance (with Bessel's correction), or `None` if fewer
+    /// than two values were pushed.
+    #[must_use]
+    pub fn sample_variance(&self) -> Option<f64> {
+        (self.count > 1).then(|| self.m2 / (self.count_f64() - 1.0))
+    }
+
+    /// The population standard deviation, or `None` if no values were pushed.
+    #[must_use]
+    pub fn std_dev(&self) -> Option<f64> {
+        self.variance().map(f64::sqrt)
+    }
+
+    /// The smallest value, or `None` if no values were pushed.
+    #[must_use]
+    pub fn min(&self) -> Option<f64> {
+        (self.count > 0).then_some(self.min)
+    }
+
+    /// The largest value, or `None` if no values were pushed.
+    #[must_use]
+    pub fn max(&self) -> Option<f64> {
+        (self.count > 0).then_some(self.max)
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn count_f64(&self) -> f64 {
+        self.count as f64
+    }
+}
+
+impl Extend<f64> for RunningStats {
+    fn extend<I: IntoIterator<Item = f64>>(&mut self, values: I) {
+        for value in values {
+            self.push(value);
+        }
+    }
+}
+
+impl FromIterator<f64> for RunningStats {
+    fn from_iter<I: IntoIterator<Item = f64>>(values: I) -> Self {
+        let mut stats = Self::new();
+        stats.extend(values);
+        stats
+    }
+}
+
+/// Streaming quantile estimator using the P² algorithm.
+///
+/// It keeps five markers instead of the values themselves, so memory use is
+/// constant regardless of the input size. Estimates are exact for the first
+/// five values and approximate afterwards. `NaN` values are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::math::P2Quantile;
+///
+/// let mut median = P2Quantile::new(50.0).unwrap();
+/// for value in 1..=1001 {
+///     median.push(f64::from(value));
+/// }
+/// assert!((median.estimate().unwrap() - 501.0).abs() < 1.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct P2Quantile {
+    fraction: f64,
+    count: usize,
+    heights: [f64; 5],
+    positions: [f64; 5],
+    desired: [f64; 5],
+    increments: [f64; 5],
+}
+
+impl P2Quantile {
+    /// Create an estimator for the given `percentile`, between 0 and 100.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LodashError::InvalidInput` if `percentile` is not between 0
+    /// and 100.
+    pub fn new(percentile: f64) -> Result<Self> {
+        let p = check_percentile(percentile)?;
+        Ok(Self {
+            fraction: p,
+            count: 0,
+            heights: [0.0; 5],
+            positions: [0.0, 1.0, 2.0, 3.0, 4.0],
+            desired: [0.0, 2.0 * p, 4.0 * p, 2.0 + 2.0 * p, 4.0],
+            increments: [0.0, p / 2.0, p, (1.0 + p) / 2.0, 1.0],
+        })
+    }
+
+    /// Add `value` to the estimator.
+    pub fn push(&mut self, value: f64) {
+        if value.is_nan() {
+            return;
+        }
+        if self.count < 5 {
+            self.heights[self.count] = value;
+            self.count += 1;
+            if self.count == 5 {
+                self.heights.sort_by(f64::total_cmp);
+            }
+            return;
+        }
+        self.count += 1;
+
+        let cell = if value < self.heights[0] {
+            self.heights[0] = value;
+            0
+        } else if value >= self.heights[4] {
+            self.heights[4] = value;
+            3
+        } else {
+            (1..5)
+                .find(|&i| value < self.heights[i])
+                .map_or(3, |i| i - 1)
+        };
+        for position in &mut self.positions[cell + 1..] {
+            *position += 1.0;
+        }
+        for (desired, increment) in self.desired.iter_mut().zip(self.increments) {
+            *desired += increment;
+        }
+
+        for i in 1..4 {
+            let offset = self.desired[i] - self.positions[i];
+            let gap_after = self.positions[i + 1] - self.positions[i];
+            let gap_before = self.positions[i - 1] - self.positions[i];
+            if (offset >= 1.0 && gap_after > 1.0) || (offset <= -1.0 && gap_before < -1.0) {
+                let step = offset.signum();
+                let parabolic = self.parabolic(i, step);
+                self.heights[i] =
+                    if self.heights[i - 1] < parabolic && parabolic < self.heights[i + 1] {
+                        parabolic
+                    } else {
+                        self.linear(i, step)
+                    };
+                self.positions[i] += step;
+            }
+        }
+    }
+
+    /// The current estimate, or `None` if no values were pushed.
+    #[must_use]
+    pub fn estimate(&self) -> Option<f64> {
+        match self.count {
+            0 => None,
+            1..=4 => {
+                let mut seen = self.heights[..self.count].to_vec();
+                seen.sort_by(f64::total_cmp);
+                Some(percentile_of_sorted(
+                    &seen,
+                    self.fraction,
+                    PercentileMethod::Linear,
+                ))
+            }
+            _ => Some(self.heights[2]),
+        }
+    }
+
+    /// The number of values pushed.
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    fn parabolic(&self, i: usize, step: f64) -> f64 {
+        let (q, n) = (&self.heights, &self.positions);
+        q[i] + step / (n[i + 1] - n[i - 1])
+            * ((n[i] - n[i - 1] + step) * (q[i + 1] - q[i]) / (n[i + 1] - n[i])
+                + (n[i + 1] - n[i] - step) * (q[i] - q[i - 1]) / (n[i] - n[i - 1]))
+    }
+
+    fn linear(&self, i: usize, step: f64) -> f64 {
+        let (q, n) = (&self.heights, &self.positions);
+        let j = if step > 0.0 { i + 1 } else { i - 1 };
+        q[i] + step * (q[j] - q[i]) / (n[j] - n[i])
+    }
+}
+
+impl Extend<f64> for P2Quantile {
+    fn extend<I: IntoIterator<Item = f64>>(&mut self, values: I) {
+        for value in values {
+            self.push(value);
+        }
+    }
+}
+
+/// Estimate the `percentile` (between 0 and 100) of `values` in a single
+/// pass with constant memory, using [`P2Quantile`].
+///
+/// # Errors
+///
+/// Returns `LodashError::InvalidInput` if `percentile` is not between 0 and
+/// 100, and `LodashError::EmptyCollection` if `values` has no non-`NaN` values.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::math::approx_percentile;
+///
+/// let p90 = approx_percentile((0..10_000).map(|i| i % 100), 90.0).unwrap();
+/// assert!((p90 - 89.1).abs() < 1.0);
+/// ```
+pub fn approx_percentile<I>(values: I, percentile: f64) -> Result<f64>
+where
+    I: IntoIterator,
+    I::Item: Numeric,
+{
+    let mut estimator = P2Quantile::new(percentile)?;
+    estimator.extend(values.into_iter().map(Numeric::to_f64));
+    estimator.estimate().ok_or(LodashError::EmptyCollection)
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_running_stats_merge() {
+        let values: Vec<f64> = (1..=100).map(f64::from).collect();
+        let whole: RunningStats = values.iter().copied().collect();
+        let mut left: RunningStats = values[..37].iter().copied().collect();
+        let right: RunningStats = values[37..].iter().copied().collect();
+        left.merge(&right);
+        assert_eq!(left.count(), whole.count());
+        assert!((left.mean().unwrap() - whole.mean().unwrap()).abs() < 1e-12);
+        assert!((left.variance().unwrap() - whole.variance().unwrap()).abs() < 1e-9);
+        assert_eq!(left.min(), Some(1.0));
+        assert_eq!(left.max(), Some(100.0));
+    }
+
+    #[test]
+    fn test_running_stats_empty_and_single() {
+        let mut stats = RunningStats::new();
+        assert_eq!(stats.mean(), None);
+        stats.push(f64::NAN);
+        assert_eq!(stats.count(), 0);
+        stats.push(3.0);
+        assert_eq!(stats.variance(), Some(0.0));
+        assert_eq!(stats.sample_variance(), None);
+    }
+
+    #[test]
+    fn test_p2_small_inputs_are_exact() {
+        let mut estimator = P2Quantile::new(50.0).unwrap();
+        assert_eq!(estimator.estimate(), None);
+        estimator.extend([3.0, 1.0, 2.0]);
+        assert_eq!(estimator.estimate(), Some(2.0));
+    }
+
+    #[test]
+    fn test_p2_tracks_quantiles() {
+        // A deterministic permutation of 0..10_000.
+        let values: Vec<f64> = (0..10_000_u32)
+            .map(|i| f64::from((i * 7_919) % 10_000))
+            .collect();
+        for percentile in [10.0, 50.0, 99.0] {
+            let mut estimator = P2Quantile::new(percentile).unwrap();
+            estimator.extend(values.iter().copied());
+            let expected = percentile / 100.0 * 9_999.0;
+            let estimate = estimator.estimate().unwrap();
+            assert!(
+                (estimate - expected).abs() < 100.0,
+                "p{percentile}: {estimate}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_approx_percentile_errors() {
+        assert_eq!(
+            approx_percentile(Vec::<f64>::new(), 50.0),
+            Err(LodashError::EmptyCollection)
+        );
+        assert!(matches!(
+            approx_percentile([1.0], 101.0),
+            Err(LodashError::InvalidInput { .. })
+        ));
+    }
+}
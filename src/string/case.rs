@@ -0,0 +1,245 @@
+/*!
+Case conversion methods for Lodash-RS.
+
+This module provides case conversion methods like `camel_case`, `snake_case`,
+`kebab_case` and `start_case`. Words are split with [`words`], so acronyms,
+numbers and emoji are handled the same way as in Lodash.
+*/
+
+use crate::string::deburr::deburr;
+use crate::string::words::words;
+
+/// Split `string` into words after deburring it and removing contraction
+/// apostrophes, then fold them into a single string.
+fn compound<F>(string: &str, mut fold: F) -> String
+where
+    F: FnMut(&mut String, &str, usize),
+{
+    let cleaned: String = deburr(string)
+        .chars()
+        .filter(|&c| c != '\'' && c != '\u{2019}')
+        .collect();
+    let mut result = String::with_capacity(cleaned.len());
+    for (index, word) in words(&cleaned).iter().enumerate() {
+        fold(&mut result, word, index);
+    }
+    result
+}
+
+/// Convert the first character of `string` with `convert`, leaving the rest untouched.
+fn convert_first<I>(string: &str, convert: fn(char) -> I) -> String
+where
+    I: Iterator<Item = char>,
+{
+    let mut chars = string.chars();
+    match chars.next() {
+        Some(first) => convert(first).chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Convert `string` to camel case.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::string::camel_case;
+///
+/// assert_eq!(camel_case("Foo Bar"), "fooBar");
+/// assert_eq!(camel_case("--foo-bar--"), "fooBar");
+/// assert_eq!(camel_case("__FOO_BAR__"), "fooBar");
+/// assert_eq!(camel_case("XMLHttpRequest"), "xmlHttpRequest");
+/// ```
+#[must_use]
+pub fn camel_case(string: &str) -> String {
+    compound(string, |result, word, index| {
+        let word = word.to_lowercase();
+        if index == 0 {
+            result.push_str(&word);
+        } else {
+            result.push_str(&upper_first(&word));
+        }
+    })
+}
+
+/// Convert `string` to kebab case.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::string::kebab_case;
+///
+/// assert_eq!(kebab_case("Foo Bar"), "foo-bar");
+/// assert_eq!(kebab_case("fooBar"), "foo-bar");
+/// assert_eq!(kebab_case("__FOO_BAR__"), "foo-bar");
+/// ```
+#[must_use]
+pub fn kebab_case(string: &str) -> String {
+    compound(string, |result, word, index| {
+        if index > 0 {
+            result.push('-');
+        }
+        result.push_str(&word.to_lowercase());
+    })
+}
+
+/// Convert `string` to snake case.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::string::snake_case;
+///
+/// assert_eq!(snake_case("Foo Bar"), "foo_bar");
+/// assert_eq!(snake_case("fooBar"), "foo_bar");
+/// assert_eq!(snake_case("--FOO-BAR--"), "foo_bar");
+/// ```
+#[must_use]
+pub fn snake_case(string: &str) -> String {
+    compound(string, |result, word, index| {
+        if index > 0 {
+            result.push('_');
+        }
+        result.push_str(&word.to_lowercase());
+    })
+}
+
+/// Convert `string` to start case.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::string::start_case;
+///
+/// assert_eq!(start_case("--foo-bar--"), "Foo Bar");
+/// assert_eq!(start_case("fooBar"), "Foo Bar");
+/// assert_eq!(start_case("__FOO_BAR__"), "FOO BAR");
+/// ```
+#[must_use]
+pub fn start_case(string: &str) -> String {
+    compound(string, |result, word, index| {
+        if index > 0 {
+            result.push(' ');
+        }
+        result.push_str(&upper_first(word));
+    })
+}
+
+/// Convert `string`, as space separated words, to lower case.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::string::lower_case;
+///
+/// assert_eq!(lower_case("--Foo-Bar--"), "foo bar");
+/// assert_eq!(lower_case("fooBar"), "foo bar");
+/// assert_eq!(lower_case("__FOO_BAR__"), "foo bar");
+/// ```
+#[must_use]
+pub fn lower_case(string: &str) -> String {
+    compound(string, |result, word, index| {
+        if index > 0 {
+            result.push(' ');
+        }
+        result.push_str(&word.to_lowercase());
+    })
+}
+
+/// Convert `string`, as space separated words, to upper case.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::string::upper_case;
+///
+/// assert_eq!(upper_case("--foo-bar--"), "FOO BAR");
+/// assert_eq!(upper_case("fooBar"), "FOO BAR");
+/// assert_eq!(upper_case("__foo_bar__"), "FOO BAR");
+/// ```
+#[must_use]
+pub fn upper_case(string: &str) -> String {
+    compound(string, |result, word, index| {
+        if index > 0 {
+            result.push(' ');
+        }
+        result.push_str(&word.to_uppercase());
+    })
+}
+
+/// Convert the first character of `string` to upper case.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::string::upper_first;
+///
+/// assert_eq!(upper_first("fred"), "Fred");
+/// assert_eq!(upper_first("FRED"), "FRED");
+/// ```
+#[must_use]
+pub fn upper_first(string: &str) -> String {
+    convert_first(string, char::to_uppercase)
+}
+
+/// Convert the first character of `string` to lower case.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::string::lower_first;
+///
+/// assert_eq!(lower_first("Fred"), "fred");
+/// assert_eq!(lower_first("FRED"), "fRED");
+/// ```
+#[must_use]
+pub fn lower_first(string: &str) -> String {
+    convert_first(string, char::to_lowercase)
+}
+
+/// Convert the first character of `string` to upper case and the remaining
+/// to lower case.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::string::capitalize;
+///
+/// assert_eq!(capitalize("FRED"), "Fred");
+/// ```
+#[must_use]
+pub fn capitalize(string: &str) -> String {
+    upper_first(&string.to_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_camel_case_numbers() {
+        assert_eq!(camel_case("12 feet"), "12Feet");
+        assert_eq!(camel_case("enable 6h format"), "enable6HFormat");
+        assert_eq!(camel_case("xhr2 request"), "xhr2Request");
+    }
+
+    #[test]
+    fn test_contractions() {
+        assert_eq!(camel_case("a b'd c"), "aBdC");
+        assert_eq!(kebab_case("A B\u{2019}LL C"), "a-bll-c");
+    }
+
+    #[test]
+    fn test_case_first() {
+        assert_eq!(upper_first(""), "");
+        assert_eq!(upper_first("ßa"), "SSa");
+        assert_eq!(lower_first("Éa"), "éa");
+        assert_eq!(capitalize("éCLAIR"), "Éclair");
+    }
+
+    #[test]
+    fn test_non_latin() {
+        assert_eq!(camel_case("Привет мир"), "приветМир");
+        assert_eq!(snake_case("Привет мир"), "привет_мир");
+    }
+}
@@ -0,0 +1,140 @@
+/*!
+Deburring for Lodash-RS.
+
+This module provides `deburr`, which converts Latin-1 Supplement and Latin
+Extended-A letters to basic Latin letters and removes combining diacritical
+marks.
+*/
+
+/// Map a Latin-1 Supplement or Latin Extended-A letter to its basic Latin
+/// equivalent, as in Lodash's `deburredLetters` table.
+///
+/// Arms are grouped by Unicode block to mirror that table.
+#[allow(clippy::match_same_arms)]
+fn deburr_letter(c: char) -> Option<&'static str> {
+    let deburred = match c {
+        // Latin-1 Supplement block.
+        '\u{c0}'..='\u{c5}' => "A",
+        '\u{e0}'..='\u{e5}' => "a",
+        '\u{c7}' => "C",
+        '\u{e7}' => "c",
+        '\u{d0}' => "D",
+        '\u{f0}' => "d",
+        '\u{c8}'..='\u{cb}' => "E",
+        '\u{e8}'..='\u{eb}' => "e",
+        '\u{cc}'..='\u{cf}' => "I",
+        '\u{ec}'..='\u{ef}' => "i",
+        '\u{d1}' => "N",
+        '\u{f1}' => "n",
+        '\u{d2}'..='\u{d6}' | '\u{d8}' => "O",
+        '\u{f2}'..='\u{f6}' | '\u{f8}' => "o",
+        '\u{d9}'..='\u{dc}' => "U",
+        '\u{f9}'..='\u{fc}' => "u",
+        '\u{dd}' => "Y",
+        '\u{fd}' | '\u{ff}' => "y",
+        '\u{c6}' => "Ae",
+        '\u{e6}' => "ae",
+        '\u{de}' => "Th",
+        '\u{fe}' => "th",
+        '\u{df}' => "ss",
+        // Latin Extended-A block.
+        '\u{100}' | '\u{102}' | '\u{104}' => "A",
+        '\u{101}' | '\u{103}' | '\u{105}' => "a",
+        '\u{106}' | '\u{108}' | '\u{10a}' | '\u{10c}' => "C",
+        '\u{107}' | '\u{109}' | '\u{10b}' | '\u{10d}' => "c",
+        '\u{10e}' | '\u{110}' => "D",
+        '\u{10f}' | '\u{111}' => "d",
+        '\u{112}' | '\u{114}' | '\u{116}' | '\u{118}' | '\u{11a}' => "E",
+        '\u{113}' | '\u{115}' | '\u{117}' | '\u{119}' | '\u{11b}' => "e",
+        '\u{11c}' | '\u{11e}' | '\u{120}' | '\u{122}' => "G",
+        '\u{11d}' | '\u{11f}' | '\u{121}' | '\u{123}' => "g",
+        '\u{124}' | '\u{126}' => "H",
+        '\u{125}' | '\u{127}' => "h",
+        '\u{128}' | '\u{12a}' | '\u{12c}' | '\u{12e}' | '\u{130}' => "I",
+        '\u{129}' | '\u{12b}' | '\u{12d}' | '\u{12f}' | '\u{131}' => "i",
+        '\u{134}' => "J",
+        '\u{135}' => "j",
+        '\u{136}' => "K",
+        '\u{137}' | '\u{138}' => "k",
+        '\u{139}' | '\u{13b}' | '\u{13d}' | '\u{13f}' | '\u{141}' => "L",
+        '\u{13a}' | '\u{13c}' | '\u{13e}' | '\u{140}' | '\u{142}' => "l",
+        '\u{143}' | '\u{145}' | '\u{147}' | '\u{14a}' => "N",
+        '\u{144}' | '\u{146}' | '\u{148}' | '\u{14b}' => "n",
+        '\u{14c}' | '\u{14e}' | '\u{150}' => "O",
+        '\u{14d}' | '\u{14f}' | '\u{151}' => "o",
+        '\u{154}' | '\u{156}' | '\u{158}' => "R",
+        '\u{155}' | '\u{157}' | '\u{159}' => "r",
+        '\u{15a}' | '\u{15c}' | '\u{15e}' | '\u{160}' => "S",
+        '\u{15b}' | '\u{15d}' | '\u{15f}' | '\u{161}' | '\u{17f}' => "s",
+        '\u{162}' | '\u{164}' | '\u{166}' => "T",
+        '\u{163}' | '\u{165}' | '\u{167}' => "t",
+        '\u{168}' | '\u{16a}' | '\u{16c}' | '\u{16e}' | '\u{170}' | '\u{172}' => "U",
+        '\u{169}' | '\u{16b}' | '\u{16d}' | '\u{16f}' | '\u{171}' | '\u{173}' => "u",
+        '\u{174}' => "W",
+        '\u{175}' => "w",
+        '\u{176}' | '\u{178}' => "Y",
+        '\u{177}' => "y",
+        '\u{179}' | '\u{17b}' | '\u{17d}' => "Z",
+        '\u{17a}' | '\u{17c}' | '\u{17e}' => "z",
+        '\u{132}' => "IJ",
+        '\u{133}' => "ij",
+        '\u{152}' => "Oe",
+        '\u{153}' => "oe",
+        '\u{149}' => "'n",
+        _ => return None,
+    };
+    Some(deburred)
+}
+
+/// Whether `c` is a combining diacritical mark removed by `deburr`.
+fn is_combo_mark(c: char) -> bool {
+    matches!(c, '\u{300}'..='\u{36f}' | '\u{fe20}'..='\u{fe2f}' | '\u{20d0}'..='\u{20ff}')
+}
+
+/// Deburr `string` by converting Latin-1 Supplement and Latin Extended-A
+/// letters to basic Latin letters and removing combining diacritical marks.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::string::deburr;
+///
+/// assert_eq!(deburr("déjà vu"), "deja vu");
+/// assert_eq!(deburr("Straße"), "Strasse");
+/// assert_eq!(deburr("e\u{301}"), "e");
+/// ```
+#[must_use]
+pub fn deburr(string: &str) -> String {
+    let mut result = String::with_capacity(string.len());
+    for c in string.chars() {
+        if is_combo_mark(c) {
+            continue;
+        }
+        match deburr_letter(c) {
+            Some(deburred) => result.push_str(deburred),
+            None => result.push(c),
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deburr_latin_blocks() {
+        assert_eq!(deburr("\u{c0}\u{c5}\u{c6}\u{de}\u{df}\u{ff}"), "AAAeThssy");
+        assert_eq!(deburr("\u{132}\u{149}\u{152}\u{17f}\u{17e}"), "IJ'nOesz");
+    }
+
+    #[test]
+    fn test_deburr_keeps_math_operators() {
+        assert_eq!(deburr("\u{d7}\u{f7}"), "\u{d7}\u{f7}");
+    }
+
+    #[test]
+    fn test_deburr_removes_combining_marks() {
+        assert_eq!(deburr("a\u{300}\u{fe20}\u{20d0}b"), "ab");
+    }
+}
@@ -0,0 +1,121 @@
+/*!
+Escaping methods for Lodash-RS.
+
+This module provides `escape`, `unescape` and `escape_reg_exp`.
+*/
+
+const HTML_ENTITIES: [(char, &str); 5] = [
+    ('&', "&amp;"),
+    ('<', "&lt;"),
+    ('>', "&gt;"),
+    ('"', "&quot;"),
+    ('\'', "&#39;"),
+];
+
+const REG_EXP_CHARS: &str = "^$\\.*+?()[]{}|";
+
+/// Convert the characters "&", "<", ">", '"', and "'" in `string` to their
+/// corresponding HTML entities.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::string::escape;
+///
+/// assert_eq!(escape("fred, barney, & pebbles"), "fred, barney, &amp; pebbles");
+/// ```
+#[must_use]
+pub fn escape(string: &str) -> String {
+    let mut result = String::with_capacity(string.len());
+    for c in string.chars() {
+        match HTML_ENTITIES.iter().find(|(from, _)| *from == c) {
+            Some((_, entity)) => result.push_str(entity),
+            None => result.push(c),
+        }
+    }
+    result
+}
+
+/// The inverse of `escape`; converts the HTML entities `&amp;`, `&lt;`,
+/// `&gt;`, `&quot;`, and `&#39;` in `string` to their corresponding characters.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::string::unescape;
+///
+/// assert_eq!(unescape("fred, barney, &amp; pebbles"), "fred, barney, & pebbles");
+/// ```
+#[must_use]
+pub fn unescape(string: &str) -> String {
+    let mut result = String::with_capacity(string.len());
+    let mut rest = string;
+    while let Some(index) = rest.find('&') {
+        result.push_str(&rest[..index]);
+        rest = &rest[index..];
+        if let Some((c, entity)) = HTML_ENTITIES
+            .iter()
+            .find(|(_, entity)| rest.starts_with(entity))
+        {
+            result.push(*c);
+            rest = &rest[entity.len()..];
+        } else {
+            result.push('&');
+            rest = &rest[1..];
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+/// Escape the regular expression special characters `^`, `$`, `\`, `.`,
+/// `*`, `+`, `?`, `(`, `)`, `[`, `]`, `{`, `}`, and `|` in `string`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::string::escape_reg_exp;
+///
+/// assert_eq!(escape_reg_exp("[lodash](https://lodash.com/)"), "\\[lodash\\]\\(https://lodash\\.com/\\)");
+/// ```
+#[must_use]
+pub fn escape_reg_exp(string: &str) -> String {
+    let mut result = String::with_capacity(string.len());
+    for c in string.chars() {
+        if REG_EXP_CHARS.contains(c) {
+            result.push('\\');
+        }
+        result.push(c);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_roundtrip() {
+        let unescaped = "&<>\"'/";
+        let escaped = "&amp;&lt;&gt;&quot;&#39;/";
+        assert_eq!(escape(unescaped), escaped);
+        assert_eq!(unescape(escaped), unescaped);
+        assert_eq!(unescape(&escape(unescaped)), unescaped);
+    }
+
+    #[test]
+    fn test_unescape_leaves_unknown_entities() {
+        assert_eq!(unescape("&amp;lt;"), "&lt;");
+        assert_eq!(unescape("a & b &nbsp;"), "a & b &nbsp;");
+        assert_eq!(unescape("&#96;"), "&#96;");
+    }
+
+    #[test]
+    fn test_escape_reg_exp() {
+        assert_eq!(
+            escape_reg_exp("^$.*+?()[]{}|\\"),
+            "\\^\\$\\.\\*\\+\\?\\(\\)\\[\\]\\{\\}\\|\\\\"
+        );
+        assert_eq!(escape_reg_exp("abc"), "abc");
+    }
+}
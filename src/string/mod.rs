@@ -0,0 +1,26 @@
+/*!
+String module for Lodash-RS.
+
+This module contains Lodash's string methods, such as word splitting, case
+conversion, padding, escaping and templates, organized by functionality.
+*/
+
+pub mod case;
+pub mod deburr;
+pub mod escape;
+pub mod pad;
+#[cfg(feature = "serialize")]
+pub mod template;
+pub mod words;
+
+// Re-export commonly used items
+pub use case::{
+    camel_case, capitalize, kebab_case, lower_case, lower_first, snake_case, start_case,
+    upper_case, upper_first,
+};
+pub use deburr::deburr;
+pub use escape::{escape, escape_reg_exp, unescape};
+pub use pad::{pad, pad_end, pad_start, repeat, truncate, TruncateOptions};
+#[cfg(feature = "serialize")]
+pub use template::{template, Template};
+pub use words::{words, words_by};
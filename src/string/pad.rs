@@ -0,0 +1,256 @@
+/*!
+Padding and truncation methods for Lodash-RS.
+
+This module provides `pad`, `pad_start`, `pad_end`, `repeat` and `truncate`.
+Lengths are measured in grapheme clusters, so combining marks and emoji
+sequences count as a single character.
+*/
+
+use unicode_segmentation::UnicodeSegmentation;
+
+fn size(string: &str) -> usize {
+    string.graphemes(true).count()
+}
+
+/// Byte offset of the end of the first `n` graphemes of `string`.
+fn grapheme_offset(string: &str, n: usize) -> usize {
+    string
+        .grapheme_indices(true)
+        .nth(n)
+        .map_or(string.len(), |(offset, _)| offset)
+}
+
+/// Create a padding of `length` graphemes made of repeated `chars`.
+fn create_padding(length: usize, chars: &str) -> String {
+    let chars_size = size(chars);
+    if chars_size == 0 || length == 0 {
+        return String::new();
+    }
+    let padding = chars.repeat((length + chars_size - 1) / chars_size);
+    let end = grapheme_offset(&padding, length);
+    padding[..end].to_string()
+}
+
+/// Pad `string` on the left and right sides if it's shorter than `length`.
+/// Padding characters are truncated if they can't be evenly divided by `length`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::string::pad;
+///
+/// assert_eq!(pad("abc", 8, " "), "  abc   ");
+/// assert_eq!(pad("abc", 8, "_-"), "_-abc_-_");
+/// assert_eq!(pad("abc", 3, " "), "abc");
+/// ```
+#[must_use]
+pub fn pad(string: &str, length: usize, chars: &str) -> String {
+    let string_size = size(string);
+    if string_size >= length {
+        return string.to_string();
+    }
+    let padding = length - string_size;
+    let left = padding / 2;
+    format!(
+        "{}{string}{}",
+        create_padding(left, chars),
+        create_padding(padding - left, chars)
+    )
+}
+
+/// Pad `string` on the left side if it's shorter than `length`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::string::pad_start;
+///
+/// assert_eq!(pad_start("abc", 6, "_-"), "_-_abc");
+/// assert_eq!(pad_start("7", 3, "0"), "007");
+/// ```
+#[must_use]
+pub fn pad_start(string: &str, length: usize, chars: &str) -> String {
+    let padding = length.saturating_sub(size(string));
+    format!("{}{string}", create_padding(padding, chars))
+}
+
+/// Pad `string` on the right side if it's shorter than `length`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::string::pad_end;
+///
+/// assert_eq!(pad_end("abc", 6, "_-"), "abc_-_");
+/// assert_eq!(pad_end("abc", 2, " "), "abc");
+/// ```
+#[must_use]
+pub fn pad_end(string: &str, length: usize, chars: &str) -> String {
+    let padding = length.saturating_sub(size(string));
+    format!("{string}{}", create_padding(padding, chars))
+}
+
+/// Repeat `string` `n` times.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::string::repeat;
+///
+/// assert_eq!(repeat("*", 3), "***");
+/// assert_eq!(repeat("abc", 0), "");
+/// ```
+#[must_use]
+pub fn repeat(string: &str, n: usize) -> String {
+    string.repeat(n)
+}
+
+/// Options for `truncate`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::string::TruncateOptions;
+///
+/// let options = TruncateOptions::default().length(24).separator(" ");
+/// assert_eq!(options.length, 24);
+/// assert_eq!(options.omission, "...");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TruncateOptions {
+    /// The maximum string length, in graphemes, including the omission.
+    pub length: usize,
+    /// The string to indicate text is omitted.
+    pub omission: String,
+    /// The separator to truncate to.
+    pub separator: Option<String>,
+}
+
+impl Default for TruncateOptions {
+    fn default() -> Self {
+        Self {
+            length: 30,
+            omission: "...".to_string(),
+            separator: None,
+        }
+    }
+}
+
+impl TruncateOptions {
+    /// Set the maximum string length.
+    #[must_use]
+    pub fn length(mut self, length: usize) -> Self {
+        self.length = length;
+        self
+    }
+
+    /// Set the string to indicate text is omitted.
+    #[must_use]
+    pub fn omission(mut self, omission: impl Into<String>) -> Self {
+        self.omission = omission.into();
+        self
+    }
+
+    /// Set the separator to truncate to.
+    #[must_use]
+    pub fn separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = Some(separator.into());
+        self
+    }
+}
+
+/// Truncate `string` if it's longer than the given maximum string length.
+/// The last characters of the truncated string are replaced with the omission
+/// string.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::string::{truncate, TruncateOptions};
+///
+/// let text = "hi-diddly-ho there, neighborino";
+/// assert_eq!(truncate(text, &TruncateOptions::default()), "hi-diddly-ho there, neighbo...");
+/// assert_eq!(
+///     truncate(text, &TruncateOptions::default().length(24).separator(" ")),
+///     "hi-diddly-ho there,..."
+/// );
+/// assert_eq!(
+///     truncate(text, &TruncateOptions::default().omission(" [...]")),
+///     "hi-diddly-ho there, neig [...]"
+/// );
+/// ```
+#[must_use]
+pub fn truncate(string: &str, options: &TruncateOptions) -> String {
+    if options.length >= size(string) {
+        return string.to_string();
+    }
+    let end = options.length.saturating_sub(size(&options.omission));
+    if end < 1 {
+        return options.omission.clone();
+    }
+    let end = grapheme_offset(string, end);
+    let mut result = &string[..end];
+    if let Some(separator) = options.separator.as_deref() {
+        if !separator.is_empty() && !string[end..].starts_with(separator) {
+            if let Some(index) = result.rfind(separator) {
+                result = &result[..index];
+            }
+        }
+    }
+    format!("{result}{}", options.omission)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pad() {
+        assert_eq!(pad("abc", 9, " "), "   abc   ");
+        assert_eq!(pad("abc", 9, "_-"), "_-_abc_-_");
+        assert_eq!(pad("abc", 6, ""), "abc");
+        assert_eq!(pad("", 2, "x"), "xx");
+    }
+
+    #[test]
+    fn test_pad_graphemes() {
+        assert_eq!(pad_start("e\u{301}", 3, "*"), "**e\u{301}");
+        assert_eq!(
+            pad_end("ab", 4, "\u{1f44d}\u{1f3fd}"),
+            "ab\u{1f44d}\u{1f3fd}\u{1f44d}\u{1f3fd}"
+        );
+        assert_eq!(
+            pad("a", 4, "\u{1f1fa}\u{1f1f8}x"),
+            "\u{1f1fa}\u{1f1f8}a\u{1f1fa}\u{1f1f8}x"
+        );
+    }
+
+    #[test]
+    fn test_truncate() {
+        let text = "hi-diddly-ho there, neighborino";
+        assert_eq!(
+            truncate(text, &TruncateOptions::default().length(text.len())),
+            text
+        );
+        assert_eq!(truncate(text, &TruncateOptions::default().length(2)), "...");
+        assert_eq!(
+            truncate(text, &TruncateOptions::default().length(4)),
+            "h..."
+        );
+        assert_eq!(
+            truncate(text, &TruncateOptions::default().length(24).separator(",")),
+            "hi-diddly-ho there..."
+        );
+        assert_eq!(
+            truncate(text, &TruncateOptions::default().length(22).separator(" ")),
+            "hi-diddly-ho there,..."
+        );
+    }
+
+    #[test]
+    fn test_truncate_graphemes() {
+        let text = "\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}abcdef";
+        let truncated = truncate(text, &TruncateOptions::default().length(5).omission("."));
+        assert_eq!(truncated, "\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}abc.");
+    }
+}
@@ -0,0 +1,210 @@
+/*!
+Safe string templates for Lodash-RS.
+
+This module provides `template`, a restricted version of Lodash's `template`.
+Templates support `<%= path %>` interpolation and `<%- path %>` HTML-escaped
+interpolation of values looked up in a `serde_json::Value` context. Unlike
+Lodash, no code is ever evaluated: `<% ... %>` evaluate blocks are rejected
+when the template is compiled.
+*/
+
+use crate::string::escape::escape;
+use crate::utils::path::{get_path, parse_path, PathPart};
+use crate::utils::{LodashError, Result};
+use serde_json::Value;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Text(String),
+    Interpolate(Vec<PathPart>),
+    Escape(Vec<PathPart>),
+}
+
+/// A compiled template.
+///
+/// Create one with [`template`] and render it with [`Template::render`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Template {
+    segments: Vec<Segment>,
+}
+
+/// Compile `source` into a [`Template`].
+///
+/// # Errors
+///
+/// Returns `LodashError::InvalidInput` if the template contains an unclosed
+/// tag, an evaluate block (`<% ... %>`) or an invalid property path. The
+/// message includes the byte offset of the offending tag.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::string::template;
+/// use serde_json::json;
+///
+/// let compiled = template("hello <%= user %>!").unwrap();
+/// assert_eq!(compiled.render(&json!({ "user": "fred" })).unwrap(), "hello fred!");
+///
+/// let compiled = template("<b><%- value %></b>").unwrap();
+/// assert_eq!(compiled.render(&json!({ "value": "<script>" })).unwrap(), "<b>&lt;script&gt;</b>");
+///
+/// assert!(template("<% alert(1) %>").is_err());
+/// ```
+pub fn template(source: &str) -> Result<Template> {
+    let mut segments = Vec::new();
+    let mut offset = 0;
+    while let Some(start) = source[offset..].find("<%").map(|index| offset + index) {
+        if start > offset {
+            segments.push(Segment::Text(source[offset..start].to_string()));
+        }
+        let body_start = start + 2;
+        let Some(end) = source[body_start..]
+            .find("%>")
+            .map(|index| body_start + index)
+        else {
+            return Err(LodashError::invalid_input(format!(
+                "unclosed template tag at offset {start}"
+            )));
+        };
+        let body = &source[body_start..end];
+        let segment = if let Some(expression) = body.strip_prefix('=') {
+            Segment::Interpolate(parse_tag_path(expression, start)?)
+        } else if let Some(expression) = body.strip_prefix('-') {
+            Segment::Escape(parse_tag_path(expression, start)?)
+        } else {
+            return Err(LodashError::invalid_input(format!(
+                "evaluate blocks are not supported, found `<%{body}%>` at offset {start}"
+            )));
+        };
+        segments.push(segment);
+        offset = end + 2;
+    }
+    if offset < source.len() {
+        segments.push(Segment::Text(source[offset..].to_string()));
+    }
+    Ok(Template { segments })
+}
+
+impl Template {
+    /// Render the template against `context`.
+    ///
+    /// Missing nested properties and `null` render as an empty string,
+    /// strings render verbatim, and arrays and objects render as compact JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LodashError::InvalidInput` if a path refers to a top-level
+    /// name that is not defined in `context`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_lodash::string::template;
+    /// use serde_json::json;
+    ///
+    /// let compiled = template("<%= user.name %> likes <%= user.tags[1] %>").unwrap();
+    /// let context = json!({ "user": { "name": "barney", "tags": ["a", "b"] } });
+    /// assert_eq!(compiled.render(&context).unwrap(), "barney likes b");
+    ///
+    /// assert!(compiled.render(&json!({})).is_err());
+    /// ```
+    pub fn render(&self, context: &Value) -> Result<String> {
+        let mut result = String::new();
+        for segment in &self.segments {
+            match segment {
+                Segment::Text(text) => result.push_str(text),
+                Segment::Interpolate(path) => result.push_str(&to_text(resolve(context, path)?)),
+                Segment::Escape(path) => {
+                    result.push_str(&escape(&to_text(resolve(context, path)?)));
+                }
+            }
+        }
+        Ok(result)
+    }
+}
+
+/// Parse the property path in the body of the tag at `offset`.
+fn parse_tag_path(expression: &str, offset: usize) -> Result<Vec<PathPart>> {
+    parse_path(expression).ok_or_else(|| {
+        LodashError::invalid_input(format!(
+            "invalid template path `{}` at offset {offset}",
+            expression.trim()
+        ))
+    })
+}
+
+/// Resolve `path` in `context`. The first key must be defined; later steps
+/// resolve to `Value::Null` when missing.
+fn resolve<'a>(context: &'a Value, path: &[PathPart]) -> Result<&'a Value> {
+    let Some((PathPart::Key(name), rest)) = path.split_first() else {
+        return Err(LodashError::invalid_input("empty template path"));
+    };
+    let current = context
+        .get(name)
+        .ok_or_else(|| LodashError::invalid_input(format!("`{name}` is not defined")))?;
+    Ok(get_path(current, rest).unwrap_or(&Value::Null))
+}
+
+fn to_text(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(string) => string.clone(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_template_interpolation() {
+        let compiled = template("<%=a%>-<%= b.c %>-<%= d[0] %>-<%= e[\"f g\"] %>").unwrap();
+        let context = json!({ "a": 1, "b": { "c": true }, "d": [null], "e": { "f g": "h" } });
+        assert_eq!(compiled.render(&context).unwrap(), "1-true--h");
+    }
+
+    #[test]
+    fn test_template_missing_values() {
+        let compiled = template("[<%= a.b.c %>]").unwrap();
+        assert_eq!(compiled.render(&json!({ "a": {} })).unwrap(), "[]");
+        let error = compiled.render(&json!({})).unwrap_err();
+        assert!(matches!(error, LodashError::InvalidInput { .. }));
+    }
+
+    #[test]
+    fn test_template_compound_values() {
+        let compiled = template("<%= list %> <%- map %>").unwrap();
+        let context = json!({ "list": [1, "a"], "map": { "k": "<v>" } });
+        assert_eq!(
+            compiled.render(&context).unwrap(),
+            "[1,\"a\"] {&quot;k&quot;:&quot;&lt;v&gt;&quot;}"
+        );
+    }
+
+    #[test]
+    fn test_template_rejects_unsafe_or_malformed_tags() {
+        for source in [
+            "a <% if (x) { %>",
+            "<%= a",
+            "<%= a + b %>",
+            "<%= [0] %>",
+            "<%= a[x] %>",
+        ] {
+            let error = template(source).unwrap_err();
+            assert!(
+                matches!(error, LodashError::InvalidInput { .. }),
+                "{source:?}"
+            );
+        }
+        let error = template("abc <%= a").unwrap_err();
+        assert!(error.to_string().contains("offset 4"));
+    }
+
+    #[test]
+    fn test_template_plain_text() {
+        let compiled = template("no tags % > here").unwrap();
+        assert_eq!(compiled.render(&Value::Null).unwrap(), "no tags % > here");
+    }
+}
@@ -0,0 +1,424 @@
+/*!
+Word splitting for Lodash-RS.
+
+This module provides `words`, which splits a string into an array of its
+words following the behaviour of Lodash's `words` regular expressions,
+including acronyms (`XMLHttpRequest`), numbers, ordinals, contractions and
+emoji sequences. It is the basis of the case conversion methods.
+*/
+
+/// Split `string` into an array of its words.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::string::words;
+///
+/// assert_eq!(words("fred, barney, & pebbles"), vec!["fred", "barney", "pebbles"]);
+/// assert_eq!(words("XMLHttpRequest"), vec!["XML", "Http", "Request"]);
+/// assert_eq!(words("isISO8601"), vec!["is", "ISO", "8601"]);
+/// ```
+#[must_use]
+pub fn words(string: &str) -> Vec<String> {
+    let chars: Vec<char> = string.chars().collect();
+    let spans = if has_unicode_word(&chars) {
+        UnicodeWords { chars: &chars }.spans()
+    } else {
+        ascii_word_spans(&chars)
+    };
+    spans
+        .into_iter()
+        .map(|(start, end)| chars[start..end].iter().collect())
+        .collect()
+}
+
+/// Split `string` into maximal runs of characters matching `predicate`.
+///
+/// This is the counterpart of Lodash's `words(string, pattern)` for a custom
+/// character class.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::string::words_by;
+///
+/// let parts = words_by("fred, barney, & pebbles", |c| c.is_alphabetic() || c == '&');
+/// assert_eq!(parts, vec!["fred", "barney", "&", "pebbles"]);
+/// ```
+#[must_use]
+pub fn words_by<P>(string: &str, predicate: P) -> Vec<String>
+where
+    P: Fn(char) -> bool,
+{
+    string
+        .split(|c| !predicate(c))
+        .filter(|word| !word.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn is_ascii_word_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+/// Check whether Lodash would use its Unicode word pattern for `chars`.
+fn has_unicode_word(chars: &[char]) -> bool {
+    chars.iter().enumerate().any(|(i, &c)| {
+        let next = chars.get(i + 1).copied();
+        let after = chars.get(i + 2).copied();
+        (c.is_ascii_lowercase() && next.is_some_and(|n| n.is_ascii_uppercase()))
+            || (c.is_ascii_uppercase()
+                && next.is_some_and(|n| n.is_ascii_uppercase())
+                && after.is_some_and(|a| a.is_ascii_lowercase()))
+            || (c.is_ascii_digit() && next.is_some_and(|n| n.is_ascii_alphabetic()))
+            || (c.is_ascii_alphabetic() && next.is_some_and(|n| n.is_ascii_digit()))
+            || !(c.is_ascii_alphanumeric() || c == ' ')
+    })
+}
+
+/// Split on ASCII punctuation, symbols and whitespace.
+fn ascii_word_spans(chars: &[char]) -> Vec<(usize, usize)> {
+    let is_word = |c: char| c.is_ascii_alphanumeric() || !c.is_ascii();
+    let mut spans = Vec::new();
+    let mut start = None;
+    for (i, &c) in chars.iter().enumerate() {
+        match (is_word(c), start) {
+            (true, None) => start = Some(i),
+            (false, Some(s)) => {
+                spans.push((s, i));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(s) = start {
+        spans.push((s, chars.len()));
+    }
+    spans
+}
+
+fn in_ranges(c: char, ranges: &[(u32, u32)]) -> bool {
+    let c = c as u32;
+    ranges.iter().any(|&(lo, hi)| lo <= c && c <= hi)
+}
+
+fn is_astral(c: char) -> bool {
+    c as u32 > 0xFFFF
+}
+
+fn is_break(c: char) -> bool {
+    in_ranges(
+        c,
+        &[
+            // Math operators
+            (0xAC, 0xAC),
+            (0xB1, 0xB1),
+            (0xD7, 0xD7),
+            (0xF7, 0xF7),
+            // Non-characters
+            (0x00, 0x2F),
+            (0x3A, 0x40),
+            (0x5B, 0x60),
+            (0x7B, 0xBF),
+            // Punctuation
+            (0x2000, 0x206F),
+            // Spaces outside the ranges above
+            (0x1680, 0x1680),
+            (0x180E, 0x180E),
+            (0x3000, 0x3000),
+            (0xFEFF, 0xFEFF),
+        ],
+    )
+}
+
+fn is_digit(c: char) -> bool {
+    c.is_ascii_digit()
+}
+
+fn is_lower(c: char) -> bool {
+    in_ranges(c, &[(0x61, 0x7A), (0xDF, 0xF6), (0xF8, 0xFF)])
+}
+
+fn is_upper(c: char) -> bool {
+    in_ranges(c, &[(0x41, 0x5A), (0xC0, 0xD6), (0xD8, 0xDE)])
+}
+
+fn is_dingbat(c: char) -> bool {
+    in_ranges(c, &[(0x2700, 0x27BF)])
+}
+
+fn is_misc(c: char) -> bool {
+    !(is_astral(c) || is_break(c) || is_digit(c) || is_dingbat(c) || is_lower(c) || is_upper(c))
+}
+
+fn is_misc_lower(c: char) -> bool {
+    is_lower(c) || is_misc(c)
+}
+
+fn is_misc_upper(c: char) -> bool {
+    is_upper(c) || is_misc(c)
+}
+
+fn is_combo(c: char) -> bool {
+    in_ranges(c, &[(0x300, 0x36F), (0xFE20, 0xFE2F), (0x20D0, 0x20FF)])
+}
+
+fn is_fitz(c: char) -> bool {
+    in_ranges(c, &[(0x1F3FB, 0x1F3FF)])
+}
+
+fn is_regional(c: char) -> bool {
+    in_ranges(c, &[(0x1F1E6, 0x1F1FF)])
+}
+
+fn is_variation(c: char) -> bool {
+    c == '\u{FE0E}' || c == '\u{FE0F}'
+}
+
+fn is_apostrophe(c: char) -> bool {
+    c == '\'' || c == '\u{2019}'
+}
+
+const LOWER_CONTRACTIONS: [&str; 7] = ["d", "ll", "m", "re", "s", "t", "ve"];
+const UPPER_CONTRACTIONS: [&str; 7] = ["D", "LL", "M", "RE", "S", "T", "VE"];
+const LOWER_ORDINALS: [&str; 3] = ["1st", "2nd", "3rd"];
+const UPPER_ORDINALS: [&str; 3] = ["1ST", "2ND", "3RD"];
+
+/// Matcher equivalent to Lodash's `reUnicodeWord`.
+///
+/// Each `match_*` method corresponds to one alternative of the pattern and
+/// returns the end of the match starting at `i`, backtracking where the
+/// pattern has a lookahead.
+struct UnicodeWords<'a> {
+    chars: &'a [char],
+}
+
+impl UnicodeWords<'_> {
+    fn at(&self, i: usize) -> Option<char> {
+        self.chars.get(i).copied()
+    }
+
+    fn is(&self, i: usize, class: fn(char) -> bool) -> bool {
+        self.at(i).is_some_and(class)
+    }
+
+    fn run(&self, i: usize, class: fn(char) -> bool) -> usize {
+        self.chars[i.min(self.chars.len())..]
+            .iter()
+            .take_while(|&&c| class(c))
+            .count()
+    }
+
+    fn starts_with(&self, i: usize, literal: &str) -> bool {
+        (i..)
+            .zip(literal.chars())
+            .all(|(index, expected)| self.at(index) == Some(expected))
+    }
+
+    /// Possible ends after an optional contraction at `i`, greedy first.
+    fn contraction_ends(&self, i: usize, suffixes: &[&str]) -> Vec<usize> {
+        let mut ends = Vec::with_capacity(2);
+        if self.is(i, is_apostrophe) {
+            if let Some(suffix) = suffixes.iter().find(|s| self.starts_with(i + 1, s)) {
+                ends.push(i + 1 + suffix.chars().count());
+            }
+        }
+        ends.push(i);
+        ends
+    }
+
+    fn is_end_or_break(&self, i: usize) -> bool {
+        i == self.chars.len() || self.is(i, is_break)
+    }
+
+    fn is_word_boundary(&self, i: usize) -> bool {
+        let before = i > 0 && self.is(i - 1, is_ascii_word_char);
+        before != self.is(i, is_ascii_word_char)
+    }
+
+    fn optional_upper(&self, i: usize) -> &'static [usize] {
+        if self.is(i, is_upper) {
+            &[1, 0]
+        } else {
+            &[0]
+        }
+    }
+
+    /// `Upper? Lower+ ContrLower? (?= Break | Upper | $)`
+    fn match_lower_word(&self, i: usize) -> Option<usize> {
+        for &upper in self.optional_upper(i) {
+            let start = i + upper;
+            for len in (1..=self.run(start, is_lower)).rev() {
+                for end in self.contraction_ends(start + len, &LOWER_CONTRACTIONS) {
+                    if self.is_end_or_break(end) || self.is(end, is_upper) {
+                        return Some(end);
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// `MiscUpper+ ContrUpper? (?= Break | Upper MiscLower | $)`
+    fn match_upper_word(&self, i: usize) -> Option<usize> {
+        for len in (1..=self.run(i, is_misc_upper)).rev() {
+            for end in self.contraction_ends(i + len, &UPPER_CONTRACTIONS) {
+                if self.is_end_or_break(end)
+                    || (self.is(end, is_upper) && self.is(end + 1, is_misc_lower))
+                {
+                    return Some(end);
+                }
+            }
+        }
+        None
+    }
+
+    /// `Upper? MiscLower+ ContrLower?`
+    fn match_misc_lower_word(&self, i: usize) -> Option<usize> {
+        for &upper in self.optional_upper(i) {
+            let start = i + upper;
+            let len = self.run(start, is_misc_lower);
+            if len > 0 {
+                return self.contraction_ends(start + len, &LOWER_CONTRACTIONS).first().copied();
+            }
+        }
+        None
+    }
+
+    /// `Upper+ ContrUpper?`
+    fn match_acronym(&self, i: usize) -> Option<usize> {
+        let len = self.run(i, is_upper);
+        if len == 0 {
+            return None;
+        }
+        self.contraction_ends(i + len, &UPPER_CONTRACTIONS).first().copied()
+    }
+
+    /// `\d*(?:1st|2nd|3rd|(?![123])\dth)` followed by a word boundary or one
+    /// of `after`.
+    fn match_ordinal(&self, i: usize, ordinals: &[&str; 3], th: &str, after: fn(char) -> bool) -> Option<usize> {
+        for digits in (0..=self.run(i, is_digit)).rev() {
+            let start = i + digits;
+            let suffix_end = ordinals
+                .iter()
+                .find(|ordinal| self.starts_with(start, ordinal))
+                .map(|ordinal| start + ordinal.len())
+                .or_else(|| {
+                    let digit = self.at(start).filter(|c| c.is_ascii_digit() && !"123".contains(*c));
+                    (digit.is_some() && self.starts_with(start + 1, th)).then_some(start + 1 + th.len())
+                });
+            if let Some(end) = suffix_end {
+                if self.is_word_boundary(end) || self.is(end, after) {
+                    return Some(end);
+                }
+            }
+        }
+        None
+    }
+
+    /// `\d+`
+    fn match_digits(&self, i: usize) -> Option<usize> {
+        let len = self.run(i, is_digit);
+        (len > 0).then_some(i + len)
+    }
+
+    /// `(?:Regional | SurrPair)` or a dingbat, at `i`.
+    fn match_emoji_base(&self, i: usize, allow_non_astral: bool) -> Option<usize> {
+        let c = self.at(i)?;
+        if allow_non_astral && !is_astral(c) {
+            return Some(i + 1);
+        }
+        if is_regional(c) && self.is(i + 1, is_regional) {
+            return Some(i + 2);
+        }
+        is_astral(c).then_some(i + 1)
+    }
+
+    /// `Variation? (Combo | Fitz)?`
+    fn skip_modifiers(&self, mut i: usize) -> usize {
+        if self.is(i, is_variation) {
+            i += 1;
+        }
+        if self.is(i, is_combo) || self.is(i, is_fitz) {
+            i += 1;
+        }
+        i
+    }
+
+    /// `(?:Dingbat | Regional | SurrPair) Variation? Modifier? (?:ZWJ ...)*`
+    fn match_emoji(&self, i: usize) -> Option<usize> {
+        let mut end = if self.is(i, is_dingbat) {
+            i + 1
+        } else {
+            self.match_emoji_base(i, false)?
+        };
+        end = self.skip_modifiers(end);
+        while self.at(end) == Some('\u{200D}') {
+            let Some(joined) = self.match_emoji_base(end + 1, true) else {
+                break;
+            };
+            end = self.skip_modifiers(joined);
+        }
+        Some(end)
+    }
+
+    fn match_at(&self, i: usize) -> Option<usize> {
+        self.match_lower_word(i)
+            .or_else(|| self.match_upper_word(i))
+            .or_else(|| self.match_misc_lower_word(i))
+            .or_else(|| self.match_acronym(i))
+            .or_else(|| self.match_ordinal(i, &UPPER_ORDINALS, "TH", |c| c.is_ascii_lowercase() || c == '_'))
+            .or_else(|| self.match_ordinal(i, &LOWER_ORDINALS, "th", |c| c.is_ascii_uppercase() || c == '_'))
+            .or_else(|| self.match_digits(i))
+            .or_else(|| self.match_emoji(i))
+    }
+
+    fn spans(&self) -> Vec<(usize, usize)> {
+        let mut spans = Vec::new();
+        let mut i = 0;
+        while i < self.chars.len() {
+            match self.match_at(i) {
+                Some(end) => {
+                    spans.push((i, end));
+                    i = end;
+                }
+                None => i += 1,
+            }
+        }
+        spans
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_words_ascii() {
+        assert_eq!(words("a b c"), vec!["a", "b", "c"]);
+        assert_eq!(words("foo bar"), vec!["foo", "bar"]);
+        assert!(words("").is_empty());
+        assert!(words("  ").is_empty());
+    }
+
+    #[test]
+    fn test_words_compound() {
+        assert_eq!(words("aeiouAreVowels"), vec!["aeiou", "Are", "Vowels"]);
+        assert_eq!(words("XMLHttp"), vec!["XML", "Http"]);
+        assert_eq!(words("XmlHTTP"), vec!["Xml", "HTTP"]);
+        assert_eq!(words("walk500Miles"), vec!["walk", "500", "Miles"]);
+    }
+
+    #[test]
+    fn test_words_non_latin() {
+        assert_eq!(words("привет мир"), vec!["привет", "мир"]);
+        assert_eq!(words("日本語のテキスト"), vec!["日本語のテキスト"]);
+    }
+
+    #[test]
+    fn test_words_emoji() {
+        assert_eq!(words("I ❤️ rust 🚀"), vec!["I", "❤️", "rust", "🚀"]);
+        assert_eq!(words("👨‍👩‍👧 🇺🇸"), vec!["👨‍👩‍👧", "🇺🇸"]);
+        assert_eq!(words("👍🏽ok"), vec!["👍🏽", "ok"]);
+    }
+}
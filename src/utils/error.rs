@@ -3,34 +3,89 @@ Error handling for Lodash-RS.
 
 This module provides comprehensive error types and handling for all operations
 in the Lodash-RS library.
+
+An error can be wrapped in [`LodashError::Context`] to record the operation
+that failed and the index of the element it failed on, and errors from other
+crates are kept as the `source()` of a [`LodashError::Custom`]. `Display`
+prints one layer; `{:#}` prints the whole chain:
+
+```
+use rust_lodash::lodash_try;
+use rust_lodash::utils::{LodashError, Result};
+
+fn parse_rows(rows: &[&str]) -> Result<Vec<i32>> {
+    let mut values = Vec::new();
+    for (index, row) in rows.iter().enumerate() {
+        values.push(lodash_try!(row.parse::<i32>(), "parse_rows", index));
+    }
+    Ok(values)
+}
+
+let error = parse_rows(&["1", "2", "x"]).unwrap_err();
+assert_eq!(error.operation(), Some("parse_rows"));
+assert_eq!(error.index(), Some(2));
+assert_eq!(error.to_string(), "parse_rows failed at element 2");
+assert_eq!(
+    format!("{error:#}"),
+    "parse_rows failed at element 2: Custom error: invalid digit found in string"
+);
+
+let source = std::error::Error::source(error.root()).unwrap();
+assert!(source.is::<std::num::ParseIntError>());
+```
 */
 
+use alloc::boxed::Box;
+use alloc::string::String;
+#[cfg(feature = "std")]
+use alloc::string::ToString;
+#[cfg(feature = "std")]
+use alloc::sync::Arc;
+
+#[doc(hidden)]
+pub use alloc::format as __format;
+
 /// Main error type for Lodash-RS operations.
+///
+/// With the `serialize` feature it serializes as an internally tagged enum,
+/// e.g. `{"kind": "index_out_of_bounds", "index": 5, "size": 3}`. The
+/// `source` of a `Custom` error is not serialized.
+///
+/// `Display` prints only this error's own layer and
+/// [`std::error::Error::source`] returns the next one, so error reporters
+/// walk the chain without repeating it. The alternate form, `{:#}`, prints
+/// the whole chain on one line for logging.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "kind", rename_all = "snake_case")
+)]
+#[non_exhaustive]
 pub enum LodashError {
     /// Invalid input data
     /// Invalid input provided to a function.
-    InvalidInput { 
+    InvalidInput {
         /// The error message describing the invalid input.
-        message: String 
+        message: String,
     },
 
     /// Type conversion error
     /// Type conversion error.
-    TypeConversion { 
+    TypeConversion {
         /// The source type that failed to convert.
-        from: String, 
+        from: String,
         /// The target type that was attempted.
-        to: String 
+        to: String,
     },
 
     /// Index out of bounds
     /// Index out of bounds error.
-    IndexOutOfBounds { 
+    IndexOutOfBounds {
         /// The index that was out of bounds.
-        index: usize, 
+        index: usize,
         /// The size of the collection.
-        size: usize 
+        size: usize,
     },
 
     /// Empty collection operation
@@ -38,33 +93,60 @@ pub enum LodashError {
 
     /// Invalid predicate function
     /// Invalid predicate function error.
-    InvalidPredicate { 
+    InvalidPredicate {
         /// The error message describing the invalid predicate.
-        message: String 
+        message: String,
     },
 
     /// Async operation error
     #[cfg(feature = "async")]
-    AsyncError { message: String },
+    AsyncError {
+        /// The error message describing the failed async operation.
+        message: String,
+    },
 
     /// Parallel operation error
     #[cfg(feature = "parallel")]
-    ParallelError { message: String },
+    ParallelError {
+        /// The error message describing the failed parallel operation.
+        message: String,
+    },
 
     /// WASM operation error
     #[cfg(feature = "wasm")]
-    WasmError { message: String },
+    WasmError {
+        /// The error message describing the failed WASM operation.
+        message: String,
+    },
 
     /// Custom error from user-provided functions
     /// Custom error with a specific message.
-    Custom { 
+    ///
+    /// The `source` field only exists with the `std` feature, so build this
+    /// variant with [`LodashError::custom`] or `custom_with_source`.
+    #[non_exhaustive]
+    Custom {
         /// The custom error message.
-        message: String 
+        message: String,
+        /// The error this one was converted from, if any.
+        #[cfg(feature = "std")]
+        #[cfg_attr(feature = "serialize", serde(skip))]
+        source: Option<ErrorSource>,
+    },
+
+    /// An error raised by an operation, with where it happened.
+    Context {
+        /// The name of the operation that failed.
+        operation: Option<String>,
+        /// The index of the element the operation failed on.
+        index: Option<usize>,
+        /// The underlying error.
+        source: Box<LodashError>,
     },
 }
 
-impl std::fmt::Display for LodashError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for LodashError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             LodashError::InvalidInput { message } => {
                 write!(f, "Invalid input: {message}")
@@ -73,7 +155,10 @@ impl std::fmt::Display for LodashError {
                 write!(f, "Type conversion failed: {from} -> {to}")
             }
             LodashError::IndexOutOfBounds { index, size } => {
-                write!(f, "Index {index} is out of bounds for collection of size {size}")
+                write!(
+                    f,
+                    "Index {index} is out of bounds for collection of size {size}"
+                )
             }
             LodashError::EmptyCollection => {
                 write!(f, "Operation requires non-empty collection")
@@ -83,27 +168,126 @@ impl std::fmt::Display for LodashError {
             }
             #[cfg(feature = "async")]
             LodashError::AsyncError { message } => {
-                write!(f, "Async operation failed: {}", message)
+                write!(f, "Async operation failed: {message}")
             }
             #[cfg(feature = "parallel")]
             LodashError::ParallelError { message } => {
-                write!(f, "Parallel operation failed: {}", message)
+                write!(f, "Parallel operation failed: {message}")
             }
             #[cfg(feature = "wasm")]
             LodashError::WasmError { message } => {
-                write!(f, "WASM operation failed: {}", message)
+                write!(f, "WASM operation failed: {message}")
+            }
+            #[cfg(feature = "std")]
+            LodashError::Custom {
+                message,
+                source: Some(source),
+            } => {
+                if source.restated {
+                    write!(f, "Custom error")?;
+                } else {
+                    write!(f, "Custom error: {message}")?;
+                }
+                if f.alternate() {
+                    let mut cause: Option<&dyn std::error::Error> = Some(source.get());
+                    while let Some(error) = cause {
+                        write!(f, ": {error}")?;
+                        cause = error.source();
+                    }
+                }
+                Ok(())
             }
-            LodashError::Custom { message } => {
+            LodashError::Custom { message, .. } => {
                 write!(f, "Custom error: {message}")
             }
+            LodashError::Context {
+                operation,
+                index,
+                source,
+            } => {
+                match (operation, index) {
+                    (Some(operation), Some(index)) => {
+                        write!(f, "{operation} failed at element {index}")?;
+                    }
+                    (Some(operation), None) => write!(f, "{operation} failed")?,
+                    (None, Some(index)) => write!(f, "Failed at element {index}")?,
+                    (None, None) => write!(f, "Operation failed")?,
+                }
+                if f.alternate() {
+                    write!(f, ": {source:#}")?;
+                }
+                Ok(())
+            }
         }
     }
 }
 
-impl std::error::Error for LodashError {}
+#[cfg(feature = "std")]
+impl std::error::Error for LodashError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            LodashError::Custom {
+                source: Some(source),
+                ..
+            } => Some(source.get()),
+            LodashError::Context { source, .. } => Some(&**source),
+            _ => None,
+        }
+    }
+}
 
 /// Result type alias for Lodash-RS operations.
-pub type Result<T> = std::result::Result<T, LodashError>;
+pub type Result<T> = core::result::Result<T, LodashError>;
+
+/// A shared handle to the error a [`LodashError::Custom`] was converted from.
+///
+/// Two sources are equal when they are the same error value.
+#[cfg(feature = "std")]
+#[derive(Clone)]
+pub struct ErrorSource {
+    /// The wrapped error
+    error: Arc<dyn std::error::Error + Send + Sync>,
+    /// Whether the `Custom` message is this error's message, so `Display`
+    /// leaves it to the source
+    restated: bool,
+}
+
+#[cfg(feature = "std")]
+impl ErrorSource {
+    /// Wrap an error.
+    pub fn new(error: impl Into<Box<dyn std::error::Error + Send + Sync>>) -> Self {
+        Self {
+            error: Arc::from(error.into()),
+            restated: false,
+        }
+    }
+
+    /// The wrapped error.
+    #[must_use]
+    pub fn get(&self) -> &(dyn std::error::Error + Send + Sync + 'static) {
+        &*self.error
+    }
+
+    /// The wrapped error, if it has type `E`.
+    #[must_use]
+    pub fn downcast_ref<E: std::error::Error + 'static>(&self) -> Option<&E> {
+        self.error.downcast_ref()
+    }
+}
+
+#[cfg(feature = "std")]
+impl core::fmt::Debug for ErrorSource {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&*self.error, f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl PartialEq for ErrorSource {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::as_ptr(&self.error).cast::<()>() == Arc::as_ptr(&other.error).cast::<()>()
+    }
+}
 
 impl LodashError {
     /// Create a new invalid input error.
@@ -144,6 +328,43 @@ impl LodashError {
     pub fn custom(message: impl Into<String>) -> Self {
         Self::Custom {
             message: message.into(),
+            #[cfg(feature = "std")]
+            source: None,
+        }
+    }
+
+    /// Create a new custom error caused by `source`.
+    ///
+    /// `message` describes this layer; `source`'s own message is left to
+    /// `source()` and the `{:#}` form.
+    #[cfg(feature = "std")]
+    pub fn custom_with_source(
+        message: impl Into<String>,
+        source: impl Into<Box<dyn std::error::Error + Send + Sync>>,
+    ) -> Self {
+        Self::Custom {
+            message: message.into(),
+            source: Some(ErrorSource::new(source)),
+        }
+    }
+
+    /// Convert any error into a `LodashError`.
+    ///
+    /// A `LodashError` is returned unchanged; any other error becomes a
+    /// `Custom` error whose `source()` is the original. The original's
+    /// message is copied for serialization, and `Display` leaves it to the
+    /// source so it is not printed twice.
+    #[cfg(feature = "std")]
+    pub fn from_error(error: impl Into<Box<dyn std::error::Error + Send + Sync>>) -> Self {
+        match error.into().downcast::<Self>() {
+            Ok(error) => *error,
+            Err(error) => Self::Custom {
+                message: error.to_string(),
+                source: Some(ErrorSource {
+                    error: Arc::from(error),
+                    restated: true,
+                }),
+            },
         }
     }
 
@@ -170,44 +391,214 @@ impl LodashError {
             message: message.into(),
         }
     }
+
+    /// Record the name of the operation that failed.
+    ///
+    /// Fills in a `Context` that has no operation yet, and otherwise wraps
+    /// the error in a new one.
+    #[must_use]
+    pub fn with_operation(self, operation: impl Into<String>) -> Self {
+        match self {
+            Self::Context {
+                operation: None,
+                index,
+                source,
+            } => Self::Context {
+                operation: Some(operation.into()),
+                index,
+                source,
+            },
+            error => Self::Context {
+                operation: Some(operation.into()),
+                index: None,
+                source: Box::new(error),
+            },
+        }
+    }
+
+    /// Record the index of the element the operation failed on.
+    ///
+    /// Fills in a `Context` that has no index yet, and otherwise wraps the
+    /// error in a new one.
+    #[must_use]
+    pub fn with_index(self, index: usize) -> Self {
+        match self {
+            Self::Context {
+                operation,
+                index: None,
+                source,
+            } => Self::Context {
+                operation,
+                index: Some(index),
+                source,
+            },
+            error => Self::Context {
+                operation: None,
+                index: Some(index),
+                source: Box::new(error),
+            },
+        }
+    }
+
+    /// The outermost operation name recorded in the chain.
+    #[must_use]
+    pub fn operation(&self) -> Option<&str> {
+        let mut error = self;
+        while let Self::Context {
+            operation, source, ..
+        } = error
+        {
+            if let Some(operation) = operation {
+                return Some(operation);
+            }
+            error = source;
+        }
+        None
+    }
+
+    /// The outermost element index recorded in the chain.
+    #[must_use]
+    pub fn index(&self) -> Option<usize> {
+        let mut error = self;
+        while let Self::Context { index, source, .. } = error {
+            if index.is_some() {
+                return *index;
+            }
+            error = source;
+        }
+        None
+    }
+
+    /// The underlying error, without any `Context` around it.
+    #[must_use]
+    pub fn root(&self) -> &Self {
+        let mut error = self;
+        while let Self::Context { source, .. } = error {
+            error = source;
+        }
+        error
+    }
+
+    /// The error the root `Custom` error was converted from, if any.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn original_error(&self) -> Option<&(dyn std::error::Error + Send + Sync + 'static)> {
+        match self.root() {
+            Self::Custom {
+                source: Some(source),
+                ..
+            } => Some(source.get()),
+            _ => None,
+        }
+    }
 }
 
 /// Extension trait for converting other error types to `LodashError`.
+///
+/// The original error is kept as the `source()` of the result, see
+/// [`LodashError::from_error`].
+#[cfg(feature = "std")]
 pub trait IntoLodashError<T> {
     /// Convert the error to a `LodashError`.
-    /// 
+    ///
     /// # Errors
-    /// 
+    ///
     /// Returns a `LodashError` if the conversion fails.
     fn into_lodash_error(self) -> Result<T>;
 }
 
-impl<T, E> IntoLodashError<T> for std::result::Result<T, E>
+#[cfg(feature = "std")]
+impl<T, E> IntoLodashError<T> for core::result::Result<T, E>
 where
-    E: std::fmt::Display,
+    E: Into<Box<dyn std::error::Error + Send + Sync>>,
 {
     fn into_lodash_error(self) -> Result<T> {
-        self.map_err(|e| LodashError::custom(e.to_string()))
+        self.map_err(LodashError::from_error)
     }
 }
 
-/// Macro for creating custom errors with context.
+/// Create a `LodashError` from a format string.
+///
+/// `lodash_error!("...", args)` creates a `Custom` error, and
+/// `lodash_error!(constructor: "...", args)` calls a message constructor such
+/// as `invalid_input` or `invalid_predicate`.
+///
+/// ```
+/// use rust_lodash::lodash_error;
+/// use rust_lodash::utils::LodashError;
+///
+/// let row = 7;
+/// assert_eq!(lodash_error!("row {row} is empty"), LodashError::custom("row 7 is empty"));
+/// assert_eq!(
+///     lodash_error!(invalid_input: "expected {} columns", 3),
+///     LodashError::invalid_input("expected 3 columns")
+/// );
+/// ```
 #[macro_export]
 macro_rules! lodash_error {
-    ($variant:ident, $($arg:expr),*) => {
-        LodashError::$variant {
-            $($arg),*
-        }
+    ($constructor:ident : $($arg:tt)+) => {
+        $crate::utils::LodashError::$constructor($crate::utils::error::__format!($($arg)+))
+    };
+    ($($arg:tt)+) => {
+        $crate::utils::LodashError::custom($crate::utils::error::__format!($($arg)+))
     };
 }
 
-/// Macro for early return on error with context.
+/// Unwrap a `Result`, or return its error as a `LodashError` with context.
+///
+/// `lodash_try!(expr)` converts the error with [`LodashError::from_error`],
+/// `lodash_try!(expr, operation)` also records the operation name, and
+/// `lodash_try!(expr, operation, index)` also records the element index. The
+/// enclosing function may return any error type that implements
+/// `From<LodashError>`. Requires the `std` feature.
+///
+/// ```
+/// use rust_lodash::lodash_try;
+/// use rust_lodash::utils::Result;
+///
+/// fn total(rows: &[&str]) -> Result<i64> {
+///     let mut total = 0;
+///     for (index, row) in rows.iter().enumerate() {
+///         total += lodash_try!(row.parse::<i64>(), "total", index);
+///     }
+///     Ok(total)
+/// }
+///
+/// assert_eq!(total(&["1", "2"]), Ok(3));
+/// assert_eq!(total(&["1", "", "3"]).unwrap_err().index(), Some(1));
+/// ```
 #[macro_export]
 macro_rules! lodash_try {
-    ($expr:expr) => {
+    ($expr:expr $(,)?) => {
         match $expr {
-            Ok(val) => val,
-            Err(err) => return Err(err.into_lodash_error().unwrap_err()),
+            Ok(value) => value,
+            Err(error) => {
+                return Err(::core::convert::From::from(
+                    $crate::utils::LodashError::from_error(error),
+                ))
+            }
+        }
+    };
+    ($expr:expr, $operation:expr $(,)?) => {
+        match $expr {
+            Ok(value) => value,
+            Err(error) => {
+                return Err(::core::convert::From::from(
+                    $crate::utils::LodashError::from_error(error).with_operation($operation),
+                ))
+            }
+        }
+    };
+    ($expr:expr, $operation:expr, $index:expr $(,)?) => {
+        match $expr {
+            Ok(value) => value,
+            Err(error) => {
+                return Err(::core::convert::From::from(
+                    $crate::utils::LodashError::from_error(error)
+                        .with_operation($operation)
+                        .with_index($index),
+                ))
+            }
         }
     };
 }
@@ -215,6 +606,10 @@ macro_rules! lodash_try {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::format;
+    use alloc::string::ToString;
+    #[cfg(feature = "std")]
+    use std::error::Error;
 
     #[test]
     fn test_error_creation() {
@@ -228,7 +623,10 @@ mod tests {
 
         let err = LodashError::index_out_of_bounds(5, 3);
         assert!(matches!(err, LodashError::IndexOutOfBounds { .. }));
-        assert_eq!(err.to_string(), "Index 5 is out of bounds for collection of size 3");
+        assert_eq!(
+            err.to_string(),
+            "Index 5 is out of bounds for collection of size 3"
+        );
 
         let err = LodashError::empty_collection();
         assert!(matches!(err, LodashError::EmptyCollection));
@@ -236,15 +634,180 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "std")]
     fn test_error_conversion() {
         let result: std::result::Result<i32, String> = Err("test error".to_string());
         let lodash_result: Result<i32> = result.into_lodash_error();
-        
+
         assert!(lodash_result.is_err());
-        if let Err(LodashError::Custom { message }) = lodash_result {
+        if let Err(LodashError::Custom { message, .. }) = lodash_result {
             assert_eq!(message, "test error");
         } else {
             panic!("Expected Custom error variant");
         }
+
+        let parsed: Result<i32> = "x".parse::<i32>().into_lodash_error();
+        let err = parsed.unwrap_err();
+        assert_eq!(err.to_string(), "Custom error");
+        assert_eq!(
+            format!("{err:#}"),
+            "Custom error: invalid digit found in string"
+        );
+        let source = err.source().unwrap();
+        assert!(source.downcast_ref::<std::num::ParseIntError>().is_some());
+        assert!(err.original_error().unwrap().is::<std::num::ParseIntError>());
+
+        let err = LodashError::custom_with_source("reading config", "disk full");
+        assert_eq!(err.to_string(), "Custom error: reading config");
+        assert_eq!(format!("{err:#}"), "Custom error: reading config: disk full");
+
+        let original = LodashError::empty_collection();
+        let converted: Result<()> = Err(original.clone()).into_lodash_error();
+        assert_eq!(converted, Err(original));
+    }
+
+    #[test]
+    fn test_error_context() {
+        let err = LodashError::invalid_input("bad row")
+            .with_operation("import")
+            .with_index(41);
+        assert_eq!(err.to_string(), "import failed at element 41");
+        assert_eq!(
+            format!("{err:#}"),
+            "import failed at element 41: Invalid input: bad row"
+        );
+        assert_eq!(err.operation(), Some("import"));
+        assert_eq!(err.index(), Some(41));
+        assert_eq!(err.root(), &LodashError::invalid_input("bad row"));
+        #[cfg(feature = "std")]
+        assert_eq!(err.source().unwrap().to_string(), "Invalid input: bad row");
+
+        let outer = err.clone().with_operation("batch").with_index(2);
+        assert_eq!(
+            format!("{outer:#}"),
+            "batch failed at element 2: import failed at element 41: Invalid input: bad row"
+        );
+        assert_eq!((outer.operation(), outer.index()), (Some("batch"), Some(2)));
+        #[cfg(feature = "std")]
+        assert_eq!(outer.source().unwrap().to_string(), err.to_string());
+
+        let err = LodashError::empty_collection().with_index(3);
+        assert_eq!(
+            format!("{err:#}"),
+            "Failed at element 3: Operation requires non-empty collection"
+        );
+        assert_eq!(err.operation(), None);
+        assert_eq!(LodashError::empty_collection().index(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_error_source_chain() {
+        fn chain(error: &dyn Error) -> Vec<String> {
+            let mut chain = Vec::new();
+            let mut current = Some(error);
+            while let Some(error) = current {
+                chain.push(error.to_string());
+                current = error.source();
+            }
+            chain
+        }
+
+        #[derive(Debug)]
+        struct Save(std::io::Error);
+
+        impl std::fmt::Display for Save {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "save failed")
+            }
+        }
+
+        impl Error for Save {
+            fn source(&self) -> Option<&(dyn Error + 'static)> {
+                Some(&self.0)
+            }
+        }
+
+        let io = std::io::Error::new(std::io::ErrorKind::NotFound, "missing file");
+        let err = LodashError::from_error(io).with_operation("load");
+        assert_eq!(chain(&err), ["load failed", "Custom error", "missing file"]);
+
+        let io = err.original_error().unwrap().downcast_ref::<std::io::Error>();
+        assert_eq!(io.unwrap().kind(), std::io::ErrorKind::NotFound);
+        assert!(LodashError::custom("plain").original_error().is_none());
+
+        let io = std::io::Error::new(std::io::ErrorKind::Other, "disk full");
+        let err = LodashError::from_error(Save(io)).with_index(3);
+        assert_eq!(
+            chain(&err),
+            ["Failed at element 3", "Custom error", "save failed", "disk full"]
+        );
+        assert_eq!(
+            format!("{err:#}"),
+            "Failed at element 3: Custom error: save failed: disk full"
+        );
+
+        assert_eq!(err.clone(), err);
+        assert_ne!(LodashError::from_error("a"), LodashError::from_error("a"),);
+        assert_eq!(LodashError::custom("a"), LodashError::custom("a"));
+    }
+
+    #[test]
+    fn test_lodash_error_macro() {
+        let column = "age";
+        assert_eq!(
+            lodash_error!("missing column {column}"),
+            LodashError::custom("missing column age")
+        );
+        assert_eq!(
+            lodash_error!(invalid_predicate: "{} panicked", "is_even"),
+            LodashError::invalid_predicate("is_even panicked")
+        );
+        assert_eq!(
+            lodash_error!(invalid_input: "plain"),
+            LodashError::invalid_input("plain")
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_lodash_try_macro() {
+        fn parse_all(rows: &[&str]) -> Result<Vec<u8>> {
+            let mut values = Vec::new();
+            for (index, row) in rows.iter().enumerate() {
+                values.push(lodash_try!(row.parse::<u8>(), "parse_all", index));
+            }
+            Ok(values)
+        }
+
+        fn first(rows: &[&str]) -> Result<u8> {
+            let row = lodash_try!(rows.first().ok_or(LodashError::empty_collection()));
+            Ok(lodash_try!(row.parse::<u8>(), "first"))
+        }
+
+        #[derive(Debug)]
+        struct AppError(LodashError);
+
+        impl From<LodashError> for AppError {
+            fn from(error: LodashError) -> Self {
+                Self(error)
+            }
+        }
+
+        fn app(row: &str) -> std::result::Result<u8, AppError> {
+            Ok(lodash_try!(row.parse::<u8>(), "app"))
+        }
+
+        assert_eq!(parse_all(&["1", "2"]), Ok(vec![1, 2]));
+        let err = parse_all(&["1", "2", "300", "x"]).unwrap_err();
+        assert_eq!(err.index(), Some(2));
+        assert_eq!(
+            format!("{err:#}"),
+            "parse_all failed at element 2: Custom error: number too large to fit in target type"
+        );
+
+        assert_eq!(first(&[]), Err(LodashError::empty_collection()));
+        assert_eq!(first(&["x"]).unwrap_err().operation(), Some("first"));
+        assert_eq!(app("x").unwrap_err().0.operation(), Some("app"));
     }
 }
@@ -0,0 +1,1103 @@
+/*!
+Expression mini-language for Lodash-RS.
+
+This module provides a small, safe expression language for writing
+predicates and mappers over `serde_json::Value` as strings, for example in
+declarative pipelines:
+
+```text
+age >= 18 && tags contains "admin"
+upper(name) + " (" + str(team.id) + ")"
+```
+
+Expressions are parsed once into an [`Expr`] and then evaluated; nothing is
+ever executed beyond the operators and functions listed below.
+
+- Literals: numbers, `"strings"` or `'strings'` (with `\\`, `\"`, `\'`,
+  `\n`, `\r` and `\t` escapes), `true`, `false`, `null`, `[arrays]` and
+  `{objects: "with", "quoted or bare": "keys"}`.
+- Properties: Lodash-style paths such as `user.name`, `tags[0]` or
+  `meta["first name"]`, resolved against the current value; `@` is the
+  current value itself. Missing properties are `null`.
+- Operators, from lowest to highest precedence: `||`, `&&`, `==` `!=`,
+  `<` `<=` `>` `>=` `contains` `in`, `+` `-`, `*` `/` `%`, and unary `!` `-`.
+  `&&` and `||` short-circuit and return one of their operands, `+`
+  concatenates when either side is a string, and `contains` tests substrings,
+  array elements and object keys (`in` is the same with the operands
+  swapped).
+- Functions: `upper`, `lower`, `trim`, `len`, `str`, `abs`, `floor`, `ceil`,
+  `round`, `camel_case`, `kebab_case`, `snake_case`, `starts_with`,
+  `ends_with` and `default(value, fallback)`.
+
+Evaluation never fails: an operation on values of the wrong type yields
+`null`, which is falsy. Parse errors carry the byte span of the offending
+input.
+*/
+
+use crate::string::{camel_case, kebab_case, snake_case};
+use crate::utils::path::{get_path, PathPart};
+use crate::utils::type_conv::{Mapper, Predicate};
+use crate::utils::LodashError;
+use serde_json::Value;
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::Range;
+
+/// A parsed expression.
+///
+/// `Expr` implements [`Predicate`] (the result's truthiness) and [`Mapper`]
+/// over `serde_json::Value`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::utils::expr::Expr;
+/// use serde_json::json;
+///
+/// let expr = Expr::parse(r#"age >= 18 && tags contains "admin""#).unwrap();
+/// assert!(expr.matches(&json!({"age": 30, "tags": ["admin"]})));
+/// assert!(!expr.matches(&json!({"age": 30, "tags": []})));
+///
+/// let expr = Expr::parse("upper(name)").unwrap();
+/// assert_eq!(expr.eval(&json!({"name": "ann"})), json!("ANN"));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expr {
+    /// The parsed syntax tree
+    root: Node,
+}
+
+/// An error found while parsing an expression.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::utils::expr::Expr;
+///
+/// let error = Expr::parse("age >= && ok").unwrap_err();
+/// assert_eq!(error.span(), 7..9);
+/// assert_eq!(error.to_string(), "expected an expression, found `&&` at 7..9");
+/// assert_eq!(
+///     error.display_with("age >= && ok"),
+///     "age >= && ok\n       ^^ expected an expression, found `&&`"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// What went wrong
+    message: String,
+    /// The byte range of the offending input
+    span: Range<usize>,
+}
+
+impl ParseError {
+    fn new(message: impl Into<String>, span: Range<usize>) -> Self {
+        Self {
+            message: message.into(),
+            span,
+        }
+    }
+
+    /// What went wrong, without the span.
+    #[must_use]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// The byte range of the offending input.
+    #[must_use]
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+
+    /// Show the error under `source`, with the span underlined.
+    #[must_use]
+    pub fn display_with(&self, source: &str) -> String {
+        let start = source[..self.span.start.min(source.len())].chars().count();
+        let width = source
+            .get(self.span.clone())
+            .map_or(1, |text| text.chars().count().max(1));
+        format!(
+            "{source}\n{}{} {}",
+            " ".repeat(start),
+            "^".repeat(width),
+            self.message
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} at {}..{}",
+            self.message, self.span.start, self.span.end
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl From<ParseError> for LodashError {
+    fn from(error: ParseError) -> Self {
+        LodashError::invalid_input(format!("invalid expression: {error}"))
+    }
+}
+
+/// Parse `source` into a predicate closure.
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] if `source` is not a valid expression.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::iteration::filter;
+/// use rust_lodash::utils::expr::compile_predicate;
+/// use serde_json::json;
+///
+/// let adult = compile_predicate("age >= 18").unwrap();
+/// let people = vec![json!({"age": 12}), json!({"age": 40})];
+/// assert_eq!(filter(&people, adult), vec![json!({"age": 40})]);
+/// ```
+pub fn compile_predicate(
+    source: &str,
+) -> Result<impl Fn(&Value) -> bool + Clone + Send + Sync + 'static, ParseError> {
+    let expr = Expr::parse(source)?;
+    Ok(move |value: &Value| expr.matches(value))
+}
+
+/// Parse `source` into a mapper closure.
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] if `source` is not a valid expression.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::iteration::map;
+/// use rust_lodash::utils::expr::compile_mapper;
+/// use serde_json::json;
+///
+/// let label = compile_mapper(r#"name + ": " + str(score * 10)"#).unwrap();
+/// let rows = vec![json!({"name": "a", "score": 1.5})];
+/// assert_eq!(map(&rows, label), vec![json!("a: 15")]);
+/// ```
+pub fn compile_mapper(
+    source: &str,
+) -> Result<impl Fn(&Value) -> Value + Clone + Send + Sync + 'static, ParseError> {
+    let expr = Expr::parse(source)?;
+    Ok(move |value: &Value| expr.eval(value))
+}
+
+impl Expr {
+    /// Parse an expression.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] for invalid tokens, unterminated strings,
+    /// unknown functions, wrong argument counts, malformed syntax and
+    /// nesting deeper than [`MAX_DEPTH`].
+    pub fn parse(source: &str) -> Result<Self, ParseError> {
+        let tokens = tokenize(source)?;
+        let mut parser = Parser {
+            tokens,
+            position: 0,
+            end: source.len(),
+            depth: 0,
+        };
+        let root = parser.expression()?;
+        match parser.peek() {
+            Some(token) => Err(ParseError::new(
+                format!("unexpected {} after the expression", token.kind.describe()),
+                token.span.clone(),
+            )),
+            None => Ok(Self { root }),
+        }
+    }
+
+    /// Evaluate the expression against `value`.
+    #[must_use]
+    pub fn eval(&self, value: &Value) -> Value {
+        eval(&self.root, value).into_owned()
+    }
+
+    /// Evaluate the expression against `value` and test the result's
+    /// truthiness.
+    #[must_use]
+    pub fn matches(&self, value: &Value) -> bool {
+        is_truthy(&eval(&self.root, value))
+    }
+}
+
+impl Predicate<Value> for Expr {
+    fn apply(&self, value: &Value) -> bool {
+        self.matches(value)
+    }
+}
+
+impl Mapper<Value, Value> for Expr {
+    fn apply(&self, value: &Value) -> Value {
+        self.eval(value)
+    }
+}
+
+/// Lodash truthiness: `null`, `false`, `0` and `""` are falsy.
+pub(crate) fn is_truthy(value: &Value) -> bool {
+    match value {
+        Value::Null => false,
+        Value::Bool(flag) => *flag,
+        Value::Number(number) => number.as_f64().is_some_and(|n| n != 0.0),
+        Value::String(string) => !string.is_empty(),
+        Value::Array(_) | Value::Object(_) => true,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Node {
+    Literal(Value),
+    Path(Vec<PathPart>),
+    Array(Vec<Node>),
+    Object(Vec<(String, Node)>),
+    Not(Box<Node>),
+    Negate(Box<Node>),
+    Binary(BinaryOp, Box<Node>, Box<Node>),
+    Call(Function, Vec<Node>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BinaryOp {
+    Or,
+    And,
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Contains,
+    In,
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Function {
+    Upper,
+    Lower,
+    Trim,
+    Len,
+    Str,
+    Abs,
+    Floor,
+    Ceil,
+    Round,
+    CamelCase,
+    KebabCase,
+    SnakeCase,
+    StartsWith,
+    EndsWith,
+    Default,
+}
+
+impl Function {
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "upper" => Self::Upper,
+            "lower" => Self::Lower,
+            "trim" => Self::Trim,
+            "len" => Self::Len,
+            "str" => Self::Str,
+            "abs" => Self::Abs,
+            "floor" => Self::Floor,
+            "ceil" => Self::Ceil,
+            "round" => Self::Round,
+            "camel_case" => Self::CamelCase,
+            "kebab_case" => Self::KebabCase,
+            "snake_case" => Self::SnakeCase,
+            "starts_with" => Self::StartsWith,
+            "ends_with" => Self::EndsWith,
+            "default" => Self::Default,
+            _ => return None,
+        })
+    }
+
+    fn arity(self) -> usize {
+        match self {
+            Self::StartsWith | Self::EndsWith | Self::Default => 2,
+            _ => 1,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum TokenKind {
+    Number(f64),
+    Str(String),
+    Ident(String),
+    At,
+    Dot,
+    Comma,
+    LParen,
+    RParen,
+    LBracket,
+    RBracket,
+    LBrace,
+    RBrace,
+    Colon,
+    Op(&'static str),
+}
+
+impl TokenKind {
+    fn describe(&self) -> String {
+        match self {
+            Self::Number(number) => format!("number `{number}`"),
+            Self::Str(string) => format!("string {string:?}"),
+            Self::Ident(name) => format!("`{name}`"),
+            Self::At => "`@`".to_string(),
+            Self::Dot => "`.`".to_string(),
+            Self::Comma => "`,`".to_string(),
+            Self::LParen => "`(`".to_string(),
+            Self::RParen => "`)`".to_string(),
+            Self::LBracket => "`[`".to_string(),
+            Self::RBracket => "`]`".to_string(),
+            Self::LBrace => "`{`".to_string(),
+            Self::RBrace => "`}`".to_string(),
+            Self::Colon => "`:`".to_string(),
+            Self::Op(op) => format!("`{op}`"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Token {
+    kind: TokenKind,
+    span: Range<usize>,
+}
+
+const OPERATORS: [&str; 14] = [
+    "&&", "||", "==", "!=", "<=", ">=", "<", ">", "+", "-", "*", "/", "%", "!",
+];
+
+fn tokenize(source: &str) -> Result<Vec<Token>, ParseError> {
+    let bytes = source.as_bytes();
+    let mut tokens = Vec::new();
+    let mut index = 0;
+    while index < source.len() {
+        let c = source[index..].chars().next().unwrap_or_default();
+        let start = index;
+        if c.is_whitespace() {
+            index += c.len_utf8();
+            continue;
+        }
+        let kind = if c.is_ascii_digit() {
+            while index < bytes.len() && (bytes[index].is_ascii_digit() || bytes[index] == b'.') {
+                index += 1;
+            }
+            if index < bytes.len() && matches!(bytes[index], b'e' | b'E') {
+                index += 1;
+                if index < bytes.len() && matches!(bytes[index], b'+' | b'-') {
+                    index += 1;
+                }
+                while index < bytes.len() && bytes[index].is_ascii_digit() {
+                    index += 1;
+                }
+            }
+            let text = &source[start..index];
+            let number = text
+                .parse()
+                .map_err(|_| ParseError::new(format!("invalid number `{text}`"), start..index))?;
+            TokenKind::Number(number)
+        } else if c == '"' || c == '\'' {
+            let (string, end) = lex_string(source, start, c)?;
+            index = end;
+            TokenKind::Str(string)
+        } else if c.is_alphabetic() || c == '_' || c == '$' {
+            while let Some(next) = source[index..].chars().next() {
+                if !(next.is_alphanumeric() || next == '_' || next == '$') {
+                    break;
+                }
+                index += next.len_utf8();
+            }
+            TokenKind::Ident(source[start..index].to_string())
+        } else {
+            let single = match c {
+                '@' => Some(TokenKind::At),
+                '.' => Some(TokenKind::Dot),
+                ',' => Some(TokenKind::Comma),
+                '(' => Some(TokenKind::LParen),
+                ')' => Some(TokenKind::RParen),
+                '[' => Some(TokenKind::LBracket),
+                ']' => Some(TokenKind::RBracket),
+                '{' => Some(TokenKind::LBrace),
+                '}' => Some(TokenKind::RBrace),
+                ':' => Some(TokenKind::Colon),
+                _ => None,
+            };
+            if let Some(kind) = single {
+                index += 1;
+                kind
+            } else if let Some(op) = OPERATORS.iter().find(|op| source[index..].starts_with(*op)) {
+                index += op.len();
+                TokenKind::Op(op)
+            } else {
+                let hint = match c {
+                    '&' => ", did you mean `&&`?",
+                    '|' => ", did you mean `||`?",
+                    '=' => ", did you mean `==`?",
+                    _ => "",
+                };
+                return Err(ParseError::new(
+                    format!("unexpected character `{c}`{hint}"),
+                    start..start + c.len_utf8(),
+                ));
+            }
+        };
+        tokens.push(Token {
+            kind,
+            span: start..index,
+        });
+    }
+    Ok(tokens)
+}
+
+/// Lex the string literal opening with `quote` at `start`, returning its
+/// value and the index after the closing quote.
+fn lex_string(source: &str, start: usize, quote: char) -> Result<(String, usize), ParseError> {
+    let mut string = String::new();
+    let mut chars = source[start + 1..].char_indices();
+    while let Some((offset, c)) = chars.next() {
+        let index = start + 1 + offset;
+        if c == quote {
+            return Ok((string, index + 1));
+        }
+        if c != '\\' {
+            string.push(c);
+            continue;
+        }
+        let escaped = match chars.next() {
+            Some((_, 'n')) => '\n',
+            Some((_, 'r')) => '\r',
+            Some((_, 't')) => '\t',
+            Some((_, c @ ('\\' | '"' | '\''))) => c,
+            Some((_, other)) => {
+                return Err(ParseError::new(
+                    format!("unknown escape `\\{other}`"),
+                    index..index + 1 + other.len_utf8(),
+                ))
+            }
+            None => break,
+        };
+        string.push(escaped);
+    }
+    Err(ParseError::new("unterminated string", start..source.len()))
+}
+
+/// The deepest an expression may nest parentheses, literals, unary operators
+/// and chained binary operators, which bounds the recursion of parsing and
+/// evaluation.
+pub const MAX_DEPTH: usize = 128;
+
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+    /// The length of the source, for errors at the end of input
+    end: usize,
+    /// The current nesting depth
+    depth: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn eat(&mut self, kind: &TokenKind) -> bool {
+        if self.peek().is_some_and(|token| token.kind == *kind) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// An error for the next token, or for the end of input.
+    fn unexpected(&self, expected: &str) -> ParseError {
+        match self.peek() {
+            Some(token) => ParseError::new(
+                format!("expected {expected}, found {}", token.kind.describe()),
+                token.span.clone(),
+            ),
+            None => ParseError::new(
+                format!("expected {expected}, found end of input"),
+                self.end..self.end,
+            ),
+        }
+    }
+
+    fn expect(&mut self, kind: &TokenKind) -> Result<(), ParseError> {
+        if self.eat(kind) {
+            Ok(())
+        } else {
+            Err(self.unexpected(&kind.describe()))
+        }
+    }
+
+    /// Enter one more level of nesting, failing past [`MAX_DEPTH`] at the
+    /// token just consumed.
+    fn nest(&mut self) -> Result<(), ParseError> {
+        self.depth += 1;
+        if self.depth <= MAX_DEPTH {
+            return Ok(());
+        }
+        let span = self
+            .position
+            .checked_sub(1)
+            .and_then(|index| self.tokens.get(index))
+            .map_or(0..0, |token| token.span.clone());
+        Err(ParseError::new(
+            format!("expression nested more than {MAX_DEPTH} levels deep"),
+            span,
+        ))
+    }
+
+    fn expression(&mut self) -> Result<Node, ParseError> {
+        let depth = self.depth;
+        self.nest()?;
+        let node = self.binary(0)?;
+        self.depth = depth;
+        Ok(node)
+    }
+
+    /// Parse a chain of binary operators at precedence `level` or higher.
+    fn binary(&mut self, level: usize) -> Result<Node, ParseError> {
+        const LEVELS: [&[(&str, BinaryOp)]; 6] = [
+            &[("||", BinaryOp::Or)],
+            &[("&&", BinaryOp::And)],
+            &[("==", BinaryOp::Eq), ("!=", BinaryOp::Ne)],
+            &[
+                ("<=", BinaryOp::Le),
+                (">=", BinaryOp::Ge),
+                ("<", BinaryOp::Lt),
+                (">", BinaryOp::Gt),
+                ("contains", BinaryOp::Contains),
+                ("in", BinaryOp::In),
+            ],
+            &[("+", BinaryOp::Add), ("-", BinaryOp::Sub)],
+            &[
+                ("*", BinaryOp::Mul),
+                ("/", BinaryOp::Div),
+                ("%", BinaryOp::Rem),
+            ],
+        ];
+        let Some(operators) = LEVELS.get(level) else {
+            return self.unary();
+        };
+        let depth = self.depth;
+        let mut left = self.binary(level + 1)?;
+        loop {
+            let op = self.peek().and_then(|token| {
+                let text = match &token.kind {
+                    TokenKind::Op(op) => *op,
+                    TokenKind::Ident(name) => name.as_str(),
+                    _ => return None,
+                };
+                operators
+                    .iter()
+                    .find(|(symbol, _)| *symbol == text)
+                    .map(|(_, op)| *op)
+            });
+            let Some(op) = op else {
+                self.depth = depth;
+                return Ok(left);
+            };
+            self.position += 1;
+            // Each operator in a chain nests the left operand one deeper.
+            self.nest()?;
+            let right = self.binary(level + 1)?;
+            left = Node::Binary(op, Box::new(left), Box::new(right));
+        }
+    }
+
+    fn unary(&mut self) -> Result<Node, ParseError> {
+        let wrap: fn(Box<Node>) -> Node = if self.eat(&TokenKind::Op("!")) {
+            Node::Not
+        } else if self.eat(&TokenKind::Op("-")) {
+            Node::Negate
+        } else {
+            return self.primary();
+        };
+        self.nest()?;
+        let operand = self.unary()?;
+        self.depth -= 1;
+        Ok(wrap(Box::new(operand)))
+    }
+
+    fn primary(&mut self) -> Result<Node, ParseError> {
+        let Some(token) = self.peek().cloned() else {
+            return Err(self.unexpected("an expression"));
+        };
+        match token.kind {
+            TokenKind::Number(number) => {
+                self.position += 1;
+                Ok(Node::Literal(number_value(number)))
+            }
+            TokenKind::Str(string) => {
+                self.position += 1;
+                Ok(Node::Literal(Value::String(string)))
+            }
+            TokenKind::LParen => {
+                self.position += 1;
+                let inner = self.expression()?;
+                self.expect(&TokenKind::RParen)?;
+                Ok(inner)
+            }
+            TokenKind::LBracket => {
+                self.position += 1;
+                let items = self.list(&TokenKind::RBracket)?;
+                Ok(Node::Array(items))
+            }
+            TokenKind::LBrace => {
+                self.position += 1;
+                self.object()
+            }
+            TokenKind::At => {
+                self.position += 1;
+                self.path(Vec::new())
+            }
+            TokenKind::Ident(name) => {
+                self.position += 1;
+                match name.as_str() {
+                    "true" => return Ok(Node::Literal(Value::Bool(true))),
+                    "false" => return Ok(Node::Literal(Value::Bool(false))),
+                    "null" => return Ok(Node::Literal(Value::Null)),
+                    "contains" | "in" => {
+                        return Err(ParseError::new(
+                            format!("expected an expression, found `{name}`"),
+                            token.span,
+                        ))
+                    }
+                    _ => {}
+                }
+                if self.eat(&TokenKind::LParen) {
+                    return self.call(&name, token.span);
+                }
+                self.path(vec![PathPart::Key(name)])
+            }
+            _ => Err(self.unexpected("an expression")),
+        }
+    }
+
+    /// Parse comma-separated expressions up to `close`.
+    fn list(&mut self, close: &TokenKind) -> Result<Vec<Node>, ParseError> {
+        let mut items = Vec::new();
+        if self.eat(close) {
+            return Ok(items);
+        }
+        loop {
+            items.push(self.expression()?);
+            if self.eat(close) {
+                return Ok(items);
+            }
+            if !self.eat(&TokenKind::Comma) {
+                return Err(self.unexpected(&format!("`,` or {}", close.describe())));
+            }
+        }
+    }
+
+    /// Parse `key: value` entries up to `}`. Keys are names or strings.
+    fn object(&mut self) -> Result<Node, ParseError> {
+        let mut entries = Vec::new();
+        loop {
+            if self.eat(&TokenKind::RBrace) {
+                return Ok(Node::Object(entries));
+            }
+            let Some(TokenKind::Ident(key) | TokenKind::Str(key)) =
+                self.next().map(|token| token.kind)
+            else {
+                self.position -= 1;
+                return Err(self.unexpected("a key or `}`"));
+            };
+            self.expect(&TokenKind::Colon)?;
+            entries.push((key, self.expression()?));
+            if !self.eat(&TokenKind::Comma) {
+                self.expect(&TokenKind::RBrace)?;
+                return Ok(Node::Object(entries));
+            }
+        }
+    }
+
+    fn call(&mut self, name: &str, span: Range<usize>) -> Result<Node, ParseError> {
+        let Some(function) = Function::from_name(name) else {
+            return Err(ParseError::new(format!("unknown function `{name}`"), span));
+        };
+        let arguments = self.list(&TokenKind::RParen)?;
+        if arguments.len() != function.arity() {
+            let end = self.tokens[self.position - 1].span.end;
+            return Err(ParseError::new(
+                format!(
+                    "`{name}` takes {} argument{}, found {}",
+                    function.arity(),
+                    if function.arity() == 1 { "" } else { "s" },
+                    arguments.len()
+                ),
+                span.start..end,
+            ));
+        }
+        Ok(Node::Call(function, arguments))
+    }
+
+    /// Parse the `.key`, `[index]` and `["key"]` steps following a path.
+    fn path(&mut self, mut parts: Vec<PathPart>) -> Result<Node, ParseError> {
+        loop {
+            if self.eat(&TokenKind::Dot) {
+                let Some(TokenKind::Ident(name)) = self.next().map(|token| token.kind) else {
+                    self.position -= 1;
+                    return Err(self.unexpected("a property name"));
+                };
+                parts.push(PathPart::Key(name));
+            } else if self.eat(&TokenKind::LBracket) {
+                match self.next().map(|token| token.kind) {
+                    Some(TokenKind::Str(key)) => parts.push(PathPart::Key(key)),
+                    Some(TokenKind::Number(number)) if number >= 0.0 && number.fract() == 0.0 => {
+                        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                        parts.push(PathPart::Index(number as usize));
+                    }
+                    _ => {
+                        self.position -= 1;
+                        return Err(self.unexpected("an index or a quoted key"));
+                    }
+                }
+                self.expect(&TokenKind::RBracket)?;
+            } else {
+                return Ok(Node::Path(parts));
+            }
+        }
+    }
+}
+
+fn eval<'a>(node: &'a Node, value: &'a Value) -> Cow<'a, Value> {
+    match node {
+        Node::Literal(literal) => Cow::Borrowed(literal),
+        Node::Path(path) => get_path(value, path).map_or(Cow::Owned(Value::Null), Cow::Borrowed),
+        Node::Array(items) => Cow::Owned(Value::Array(
+            items
+                .iter()
+                .map(|item| eval(item, value).into_owned())
+                .collect(),
+        )),
+        Node::Object(entries) => Cow::Owned(Value::Object(
+            entries
+                .iter()
+                .map(|(key, entry)| (key.clone(), eval(entry, value).into_owned()))
+                .collect(),
+        )),
+        Node::Not(inner) => Cow::Owned(Value::Bool(!is_truthy(&eval(inner, value)))),
+        Node::Negate(inner) => Cow::Owned(
+            eval(inner, value)
+                .as_f64()
+                .map_or(Value::Null, |number| number_value(-number)),
+        ),
+        Node::Binary(BinaryOp::And, left, right) => {
+            let left = eval(left, value);
+            if is_truthy(&left) {
+                eval(right, value)
+            } else {
+                left
+            }
+        }
+        Node::Binary(BinaryOp::Or, left, right) => {
+            let left = eval(left, value);
+            if is_truthy(&left) {
+                left
+            } else {
+                eval(right, value)
+            }
+        }
+        Node::Binary(op, left, right) => {
+            Cow::Owned(binary(*op, &eval(left, value), &eval(right, value)))
+        }
+        Node::Call(function, arguments) => {
+            let arguments: Vec<Cow<'a, Value>> = arguments
+                .iter()
+                .map(|argument| eval(argument, value))
+                .collect();
+            Cow::Owned(call(*function, &arguments))
+        }
+    }
+}
+
+fn binary(op: BinaryOp, left: &Value, right: &Value) -> Value {
+    let numbers = left.as_f64().zip(right.as_f64());
+    match op {
+        BinaryOp::Eq => Value::Bool(equals(left, right)),
+        BinaryOp::Ne => Value::Bool(!equals(left, right)),
+        BinaryOp::Lt | BinaryOp::Le | BinaryOp::Gt | BinaryOp::Ge => {
+            let ordering = match (left, right) {
+                (Value::String(a), Value::String(b)) => Some(a.cmp(b)),
+                _ => numbers.and_then(|(a, b)| a.partial_cmp(&b)),
+            };
+            Value::Bool(ordering.is_some_and(|ordering| match op {
+                BinaryOp::Lt => ordering == Ordering::Less,
+                BinaryOp::Le => ordering != Ordering::Greater,
+                BinaryOp::Gt => ordering == Ordering::Greater,
+                _ => ordering != Ordering::Less,
+            }))
+        }
+        BinaryOp::Contains => Value::Bool(contains(left, right)),
+        BinaryOp::In => Value::Bool(contains(right, left)),
+        BinaryOp::Add if left.is_string() || right.is_string() => {
+            Value::String(to_text(left) + &to_text(right))
+        }
+        BinaryOp::Add | BinaryOp::Sub | BinaryOp::Mul | BinaryOp::Div | BinaryOp::Rem => numbers
+            .map_or(Value::Null, |(a, b)| {
+                number_value(match op {
+                    BinaryOp::Add => a + b,
+                    BinaryOp::Sub => a - b,
+                    BinaryOp::Mul => a * b,
+                    BinaryOp::Div => a / b,
+                    _ => a % b,
+                })
+            }),
+        BinaryOp::And | BinaryOp::Or => {
+            unreachable!("short-circuiting operators are evaluated in `eval`")
+        }
+    }
+}
+
+fn call(function: Function, arguments: &[Cow<'_, Value>]) -> Value {
+    let first = &*arguments[0];
+    let text = |f: fn(&str) -> String| first.as_str().map_or(Value::Null, |s| Value::String(f(s)));
+    let number = |f: fn(f64) -> f64| first.as_f64().map_or(Value::Null, |n| number_value(f(n)));
+    match function {
+        Function::Upper => text(str::to_uppercase),
+        Function::Lower => text(str::to_lowercase),
+        Function::Trim => text(|s| s.trim().to_string()),
+        Function::CamelCase => text(camel_case),
+        Function::KebabCase => text(kebab_case),
+        Function::SnakeCase => text(snake_case),
+        Function::Len => match first {
+            Value::String(string) => Value::from(string.chars().count()),
+            Value::Array(items) => Value::from(items.len()),
+            Value::Object(object) => Value::from(object.len()),
+            _ => Value::Null,
+        },
+        Function::Str => Value::String(to_text(first)),
+        Function::Abs => number(f64::abs),
+        Function::Floor => number(f64::floor),
+        Function::Ceil => number(f64::ceil),
+        Function::Round => number(f64::round),
+        Function::StartsWith | Function::EndsWith => match (first, &*arguments[1]) {
+            (Value::String(string), Value::String(affix)) => {
+                Value::Bool(if function == Function::StartsWith {
+                    string.starts_with(affix.as_str())
+                } else {
+                    string.ends_with(affix.as_str())
+                })
+            }
+            _ => Value::Null,
+        },
+        Function::Default => {
+            if first.is_null() {
+                arguments[1].clone().into_owned()
+            } else {
+                first.clone()
+            }
+        }
+    }
+}
+
+/// A JSON number, as an integer when it is whole.
+fn number_value(number: f64) -> Value {
+    const MAX_EXACT: f64 = 9_007_199_254_740_992.0;
+    if number.fract() == 0.0 && number.abs() <= MAX_EXACT {
+        #[allow(clippy::cast_possible_truncation)]
+        return Value::from(number as i64);
+    }
+    serde_json::Number::from_f64(number).map_or(Value::Null, Value::Number)
+}
+
+/// Deep equality, comparing numbers by value.
+fn equals(left: &Value, right: &Value) -> bool {
+    match (left, right) {
+        (Value::Number(a), Value::Number(b)) => a.as_f64() == b.as_f64(),
+        (Value::Array(a), Value::Array(b)) => {
+            a.len() == b.len() && a.iter().zip(b).all(|(a, b)| equals(a, b))
+        }
+        _ => left == right,
+    }
+}
+
+fn contains(haystack: &Value, needle: &Value) -> bool {
+    match (haystack, needle) {
+        (Value::String(string), Value::String(part)) => string.contains(part.as_str()),
+        (Value::Array(items), _) => items.iter().any(|item| equals(item, needle)),
+        (Value::Object(object), Value::String(key)) => object.contains_key(key),
+        _ => false,
+    }
+}
+
+/// Strings as they are, `null` as an empty string and anything else as
+/// compact JSON.
+fn to_text(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(string) => string.clone(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn eval_str(source: &str, value: &Value) -> Value {
+        Expr::parse(source).unwrap().eval(value)
+    }
+
+    #[test]
+    fn test_predicates() {
+        let user = json!({"name": "ann", "age": 31, "tags": ["admin", "dev"], "team": {"id": 1}});
+        for (source, expected) in [
+            (r#"age >= 18 && tags contains "admin""#, true),
+            (r#"age > 40 || "dev" in tags"#, true),
+            ("!(age < 40) || team.id != 1", false),
+            ("tags[1] == 'dev' && team['id'] == 1.0", true),
+            ("missing.deep == null && !missing", true),
+            (r#"name contains "nn" && starts_with(name, "a")"#, true),
+            ("age % 2 == 1 && -age < 0", true),
+            ("[1, 2, 3] contains team.id", true),
+        ] {
+            assert_eq!(
+                Expr::parse(source).unwrap().matches(&user),
+                expected,
+                "{source}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_mappers() {
+        let row = json!({"name": "  Fred Flintstone ", "score": 7, "ratio": 0.25, "items": [1, 2]});
+        assert_eq!(
+            eval_str("upper(trim(name))", &row),
+            json!("FRED FLINTSTONE")
+        );
+        assert_eq!(eval_str("snake_case(name)", &row), json!("fred_flintstone"));
+        assert_eq!(eval_str("score * 2 + 1", &row), json!(15));
+        assert_eq!(eval_str("score / 2", &row), json!(3.5));
+        assert_eq!(eval_str("round(ratio * 10)", &row), json!(3));
+        assert_eq!(eval_str(r#""n=" + len(items)"#, &row), json!("n=2"));
+        assert_eq!(eval_str("default(nickname, 'none')", &row), json!("none"));
+        assert_eq!(eval_str("score || 0", &json!({})), json!(0));
+        assert_eq!(eval_str("@", &json!(5)), json!(5));
+        assert_eq!(
+            eval_str("{id: score, 'a b': [name == null], }", &row),
+            json!({"id": 7, "a b": [false]})
+        );
+        assert_eq!(eval_str("upper(score)", &row), Value::Null);
+        assert_eq!(eval_str("name - 1", &row), Value::Null);
+    }
+
+    #[test]
+    fn test_parse_errors_have_spans() {
+        for (source, message, span) in [
+            ("age >=", "expected an expression, found end of input", 6..6),
+            (
+                "age = 1",
+                "unexpected character `=`, did you mean `==`?",
+                4..5,
+            ),
+            (
+                "upper(name",
+                "expected `,` or `)`, found end of input",
+                10..10,
+            ),
+            ("uper(name)", "unknown function `uper`", 0..4),
+            ("default(a)", "`default` takes 2 arguments, found 1", 0..10),
+            (
+                "name 'x'",
+                "unexpected string \"x\" after the expression",
+                5..8,
+            ),
+            ("a.1", "expected a property name, found number `1`", 2..3),
+            ("a[b]", "expected an index or a quoted key, found `b`", 2..3),
+            ("{a 1}", "expected `:`, found number `1`", 3..4),
+            ("{1: 2}", "expected a key or `}`, found number `1`", 1..2),
+            ("'abc", "unterminated string", 0..4),
+            (r"'a\q'", "unknown escape `\\q`", 2..4),
+            ("1 + # 2", "unexpected character `#`", 4..5),
+        ] {
+            let error = Expr::parse(source).unwrap_err();
+            assert_eq!((error.message(), error.span()), (message, span), "{source}");
+        }
+
+        let error: LodashError = Expr::parse("(1").unwrap_err().into();
+        assert_eq!(
+            error.to_string(),
+            "Invalid input: invalid expression: expected `)`, found end of input at 2..2"
+        );
+    }
+
+    #[test]
+    fn test_nesting_limit() {
+        let nested = |depth: usize| format!("{}1{}", "(".repeat(depth), ")".repeat(depth));
+        assert_eq!(eval_str(&nested(MAX_DEPTH - 1), &json!({})), json!(1));
+
+        let error = Expr::parse(&nested(1000)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            format!("expression nested more than {MAX_DEPTH} levels deep at 127..128")
+        );
+        assert_eq!(error.span(), 127..128);
+
+        assert!(Expr::parse(&"!".repeat(20_000)).is_err());
+        assert!(Expr::parse(&format!("{}x", "-".repeat(MAX_DEPTH + 1))).is_err());
+        assert!(Expr::parse(&vec!["1"; 20_000].join(" + ")).is_err());
+        assert!(Expr::parse(&format!("{}0{}", "[".repeat(500), "]".repeat(500))).is_err());
+        assert!(Expr::parse(&format!("{}0{}", "len(".repeat(500), ")".repeat(500))).is_err());
+
+        let deep = format!("{}!!x{}", "(-".repeat(40), ")".repeat(40));
+        assert_eq!(eval_str(&deep, &json!({"x": true})), Value::Null);
+        let chains = ["*", "+", "<", "==", "&&", "||"]
+            .iter()
+            .fold(String::from("1"), |source, op| {
+                source + &format!(" {op} 1").repeat(MAX_DEPTH - 2)
+            });
+        assert_eq!(eval_str(&chains, &json!({})), json!(1));
+        let sum = vec!["1"; MAX_DEPTH - 1].join(" + ");
+        assert_eq!(eval_str(&sum, &json!({})), json!(MAX_DEPTH - 1));
+    }
+
+    #[test]
+    fn test_trait_impls() {
+        fn count<P: Predicate<Value>>(predicate: &P, values: &[Value]) -> usize {
+            values.iter().filter(|value| predicate.apply(value)).count()
+        }
+        fn apply<M: Mapper<Value, Value>>(mapper: &M, value: &Value) -> Value {
+            mapper.apply(value)
+        }
+
+        let values = vec![json!(1), json!(2), json!(3)];
+        assert_eq!(count(&Expr::parse("@ >= 2").unwrap(), &values), 2);
+        assert_eq!(count(&compile_predicate("@ != 2").unwrap(), &values), 2);
+        assert_eq!(apply(&Expr::parse("@ * 3").unwrap(), &values[1]), json!(6));
+        assert_eq!(
+            apply(&compile_mapper("str(@)").unwrap(), &values[2]),
+            json!("3")
+        );
+    }
+}
@@ -0,0 +1,13 @@
+/*!
+Hash collections for Lodash-RS.
+
+Keyed results such as `group_by` and `count_by` return these maps and sets.
+With the default `std` feature they are the standard library's types; on
+`no_std + alloc` builds they come from `hashbrown`.
+*/
+
+#[cfg(feature = "std")]
+pub use std::collections::{hash_map, hash_set, HashMap, HashSet};
+
+#[cfg(not(feature = "std"))]
+pub use hashbrown::{hash_map, hash_set, HashMap, HashSet};
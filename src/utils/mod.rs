@@ -6,11 +6,20 @@ used throughout the library.
 */
 
 pub mod error;
+#[cfg(feature = "serialize")]
+pub mod expr;
+pub mod hash;
+#[cfg(feature = "serialize")]
+pub(crate) mod path;
+#[cfg(feature = "std")]
 pub mod type_conv;
 pub mod async_support;
 
 // Re-export commonly used items
-pub use error::{LodashError, Result, IntoLodashError};
+pub use error::{LodashError, Result};
+#[cfg(feature = "std")]
+pub use error::{ErrorSource, IntoLodashError};
+#[cfg(feature = "std")]
 pub use type_conv::*;
 
 #[cfg(feature = "async")]
@@ -0,0 +1,93 @@
+/*!
+Property paths for Lodash-RS.
+
+This module parses Lodash-style property paths such as `user.tags[0]` or
+`a["b c"]` and resolves them against a `serde_json::Value`. It is shared by
+string templates and declarative pipelines, which wrap parse failures in
+their own error messages.
+*/
+
+use serde_json::Value;
+
+/// A single step of a property path such as `user.tags[0]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum PathPart {
+    Key(String),
+    Index(usize),
+}
+
+/// Parse a property path such as `a.b[0]["c"]`. Paths must start with a
+/// key. Returns `None` if `expression` is not a valid path.
+pub(crate) fn parse_path(expression: &str) -> Option<Vec<PathPart>> {
+    let chars: Vec<char> = expression.trim().chars().collect();
+    let is_ident = |c: char| c.is_alphanumeric() || c == '_' || c == '$';
+    let mut parts = Vec::new();
+    let mut index = 0;
+    while index < chars.len() {
+        match chars[index] {
+            '.' if !parts.is_empty() => index += 1,
+            '[' => {
+                let close = chars[index..]
+                    .iter()
+                    .position(|&c| c == ']')
+                    .map(|position| index + position)?;
+                let inner: String = chars[index + 1..close].iter().collect();
+                let inner = inner.trim();
+                let quoted = inner
+                    .strip_prefix('"')
+                    .and_then(|rest| rest.strip_suffix('"'))
+                    .or_else(|| {
+                        inner
+                            .strip_prefix('\'')
+                            .and_then(|rest| rest.strip_suffix('\''))
+                    });
+                let part = match quoted {
+                    Some(key) => PathPart::Key(key.to_string()),
+                    None => PathPart::Index(inner.parse().ok()?),
+                };
+                parts.push(part);
+                index = close + 1;
+                continue;
+            }
+            _ => {}
+        }
+        let start = index;
+        while index < chars.len() && is_ident(chars[index]) {
+            index += 1;
+        }
+        if start == index {
+            return None;
+        }
+        parts.push(PathPart::Key(chars[start..index].iter().collect()));
+    }
+    match parts.first() {
+        Some(PathPart::Key(_)) => Some(parts),
+        _ => None,
+    }
+}
+
+/// Resolve `path` in `value`, or `None` as soon as a step is missing.
+pub(crate) fn get_path<'a>(value: &'a Value, path: &[PathPart]) -> Option<&'a Value> {
+    path.iter().try_fold(value, |current, part| match part {
+        PathPart::Key(key) => current.get(key),
+        PathPart::Index(index) => current.get(index),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_and_get_path() {
+        let value = json!({ "a": { "b c": [10, { "d": true }] } });
+        let path = parse_path(r#"a["b c"][1].d"#).unwrap();
+        assert_eq!(get_path(&value, &path), Some(&json!(true)));
+        assert_eq!(get_path(&value, &parse_path("a.x.y").unwrap()), None);
+
+        for invalid in ["", "[0]", "a..b", "a[x]", "a[0", "a + b", ".a"] {
+            assert_eq!(parse_path(invalid), None, "{invalid:?}");
+        }
+    }
+}
@@ -6,6 +6,13 @@ different data types in a type-safe manner.
 */
 
 use crate::utils::{LodashError, Result};
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+#[cfg(feature = "derive")]
+pub use rust_lodash_derive::{ToComparable, ToHashKey, ToKey};
 
 /// Trait for types that can be converted to a key for grouping operations.
 pub trait ToKey {
@@ -13,57 +20,75 @@ pub trait ToKey {
     fn to_key(&self) -> String;
 }
 
-impl ToKey for String {
+macro_rules! impl_to_key_display {
+    ($($t:ty),*) => {
+        $(
+            impl ToKey for $t {
+                fn to_key(&self) -> String {
+                    self.to_string()
+                }
+            }
+        )*
+    };
+}
+
+impl_to_key_display!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64, bool, char
+);
+
+impl ToKey for str {
     fn to_key(&self) -> String {
-        self.clone()
+        self.to_string()
     }
 }
 
-impl ToKey for &str {
+impl ToKey for String {
     fn to_key(&self) -> String {
-        (*self).to_string()
+        self.clone()
     }
 }
 
-impl ToKey for i32 {
+impl<T: ToKey + ?Sized> ToKey for &T {
     fn to_key(&self) -> String {
-        (*self).to_string()
+        (**self).to_key()
     }
 }
 
-impl ToKey for i64 {
+impl<T: ToKey + ?Sized> ToKey for Box<T> {
     fn to_key(&self) -> String {
-        (*self).to_string()
+        (**self).to_key()
     }
 }
 
-impl ToKey for u32 {
+impl<T: ToKey + ToOwned + ?Sized> ToKey for Cow<'_, T> {
     fn to_key(&self) -> String {
-        (*self).to_string()
+        (**self).to_key()
     }
 }
 
-impl ToKey for u64 {
+/// `None` is keyed as `null`, as Lodash keys `null`.
+impl<T: ToKey> ToKey for Option<T> {
     fn to_key(&self) -> String {
-        (*self).to_string()
+        self.as_ref().map_or_else(|| "null".to_string(), ToKey::to_key)
     }
 }
 
-impl ToKey for f32 {
+/// The element keys joined by [`compose_key`].
+impl<T: ToKey> ToKey for [T] {
     fn to_key(&self) -> String {
-        (*self).to_string()
+        compose_key(self.iter().map(ToKey::to_key))
     }
 }
 
-impl ToKey for f64 {
+impl<T: ToKey> ToKey for Vec<T> {
     fn to_key(&self) -> String {
-        (*self).to_string()
+        self.as_slice().to_key()
     }
 }
 
-impl ToKey for bool {
+impl<T: ToKey, const N: usize> ToKey for [T; N] {
     fn to_key(&self) -> String {
-        (*self).to_string()
+        self.as_slice().to_key()
     }
 }
 
@@ -121,67 +146,157 @@ pub trait ToComparable {
     fn to_comparable(&self) -> Self::Output;
 }
 
-impl ToComparable for i32 {
-    type Output = i32;
-    
+macro_rules! impl_to_comparable_copy {
+    ($($t:ty),*) => {
+        $(
+            impl ToComparable for $t {
+                type Output = $t;
+
+                fn to_comparable(&self) -> Self::Output {
+                    *self
+                }
+            }
+        )*
+    };
+}
+
+impl_to_comparable_copy!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, bool, char
+);
+
+/// A float ordered by `total_cmp`, the comparable form of `f32` and `f64`.
+///
+/// Unlike the float it is `Ord`, so it can drive `sort_by` and `order_by`.
+/// Negative NaN sorts first, then negative infinity up to negative zero,
+/// positive zero up to positive infinity, and positive NaN last; the two
+/// zeros are not equal.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::collection::transform::sort_by;
+/// use rust_lodash::utils::ToComparable;
+///
+/// let sorted = sort_by(&[2.5, f64::NAN, -1.0, 0.0], |x| x.to_comparable());
+/// assert_eq!(sorted[..3], [-1.0, 0.0, 2.5]);
+/// assert!(sorted[3].is_nan());
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TotalOrder<F>(pub F);
+
+macro_rules! impl_total_order {
+    ($($t:ty),*) => {
+        $(
+            impl PartialEq for TotalOrder<$t> {
+                fn eq(&self, other: &Self) -> bool {
+                    self.cmp(other) == Ordering::Equal
+                }
+            }
+
+            impl Eq for TotalOrder<$t> {}
+
+            impl PartialOrd for TotalOrder<$t> {
+                fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                    Some(self.cmp(other))
+                }
+            }
+
+            impl Ord for TotalOrder<$t> {
+                fn cmp(&self, other: &Self) -> Ordering {
+                    self.0.total_cmp(&other.0)
+                }
+            }
+
+            impl Hash for TotalOrder<$t> {
+                fn hash<H: Hasher>(&self, state: &mut H) {
+                    self.0.to_bits().hash(state);
+                }
+            }
+
+            impl ToComparable for $t {
+                type Output = TotalOrder<$t>;
+
+                fn to_comparable(&self) -> Self::Output {
+                    TotalOrder(*self)
+                }
+            }
+        )*
+    };
+}
+
+impl_total_order!(f32, f64);
+
+impl ToComparable for str {
+    type Output = String;
+
     fn to_comparable(&self) -> Self::Output {
-        *self
+        self.to_string()
     }
 }
 
-impl ToComparable for i64 {
-    type Output = i64;
-    
+impl ToComparable for String {
+    type Output = String;
+
     fn to_comparable(&self) -> Self::Output {
-        *self
+        self.clone()
     }
 }
 
-impl ToComparable for u32 {
-    type Output = u32;
-    
+impl<T: ToComparable + ?Sized> ToComparable for &T {
+    type Output = T::Output;
+
     fn to_comparable(&self) -> Self::Output {
-        *self
+        (**self).to_comparable()
     }
 }
 
-impl ToComparable for u64 {
-    type Output = u64;
-    
+impl<T: ToComparable + ?Sized> ToComparable for Box<T> {
+    type Output = T::Output;
+
     fn to_comparable(&self) -> Self::Output {
-        *self
+        (**self).to_comparable()
     }
 }
 
-impl ToComparable for f32 {
-    type Output = f32;
-    
+impl<T: ToComparable + ToOwned + ?Sized> ToComparable for Cow<'_, T> {
+    type Output = T::Output;
+
     fn to_comparable(&self) -> Self::Output {
-        *self
+        (**self).to_comparable()
     }
 }
 
-impl ToComparable for f64 {
-    type Output = f64;
-    
+/// `None` compares before every `Some`.
+impl<T: ToComparable> ToComparable for Option<T> {
+    type Output = Option<T::Output>;
+
     fn to_comparable(&self) -> Self::Output {
-        *self
+        self.as_ref().map(ToComparable::to_comparable)
     }
 }
 
-impl ToComparable for String {
-    type Output = String;
-    
+/// Sequences compare element by element, a prefix first.
+impl<T: ToComparable> ToComparable for [T] {
+    type Output = Vec<T::Output>;
+
     fn to_comparable(&self) -> Self::Output {
-        self.clone()
+        self.iter().map(ToComparable::to_comparable).collect()
     }
 }
 
-impl ToComparable for &str {
-    type Output = String;
-    
+impl<T: ToComparable> ToComparable for Vec<T> {
+    type Output = Vec<T::Output>;
+
     fn to_comparable(&self) -> Self::Output {
-        (*self).to_string()
+        self.as_slice().to_comparable()
+    }
+}
+
+impl<T: ToComparable, const N: usize> ToComparable for [T; N] {
+    type Output = Vec<T::Output>;
+
+    fn to_comparable(&self) -> Self::Output {
+        self.as_slice().to_comparable()
     }
 }
 
@@ -204,58 +319,194 @@ pub trait ToHashKey {
     fn to_hash_key(&self) -> u64;
 }
 
-impl ToHashKey for i32 {
+macro_rules! impl_to_hash_key_cast {
+    ($($t:ty),*) => {
+        $(
+            impl ToHashKey for $t {
+                #[allow(clippy::cast_sign_loss, clippy::cast_lossless)]
+                fn to_hash_key(&self) -> u64 {
+                    *self as u64
+                }
+            }
+        )*
+    };
+}
+
+impl_to_hash_key_cast!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, bool);
+
+impl ToHashKey for char {
     fn to_hash_key(&self) -> u64 {
-        #[allow(clippy::cast_sign_loss)]
-        {
-            *self as u64
-        }
+        u64::from(u32::from(*self))
     }
 }
 
-impl ToHashKey for i64 {
+impl ToHashKey for i128 {
     fn to_hash_key(&self) -> u64 {
-        #[allow(clippy::cast_sign_loss)]
-        {
-            *self as u64
-        }
+        hash_of(self)
+    }
+}
+
+impl ToHashKey for u128 {
+    fn to_hash_key(&self) -> u64 {
+        hash_of(self)
     }
 }
 
-impl ToHashKey for u32 {
+/// The bits of the float, so that keys agree with [`TotalOrder`].
+impl ToHashKey for f32 {
     fn to_hash_key(&self) -> u64 {
-        u64::from(*self)
+        u64::from(self.to_bits())
     }
 }
 
-impl ToHashKey for u64 {
+/// The bits of the float, so that keys agree with [`TotalOrder`].
+impl ToHashKey for f64 {
     fn to_hash_key(&self) -> u64 {
-        *self
+        self.to_bits()
+    }
+}
+
+impl ToHashKey for str {
+    fn to_hash_key(&self) -> u64 {
+        hash_of(self)
     }
 }
 
 impl ToHashKey for String {
     fn to_hash_key(&self) -> u64 {
-        use std::collections::hash_map::DefaultHasher;
-        use std::hash::{Hash, Hasher};
-        
-        let mut hasher = DefaultHasher::new();
-        self.hash(&mut hasher);
-        hasher.finish()
+        self.as_str().to_hash_key()
     }
 }
 
-impl ToHashKey for &str {
+impl<T: ToHashKey + ?Sized> ToHashKey for &T {
     fn to_hash_key(&self) -> u64 {
-        use std::collections::hash_map::DefaultHasher;
-        use std::hash::{Hash, Hasher};
-        
-        let mut hasher = DefaultHasher::new();
-        self.hash(&mut hasher);
-        hasher.finish()
+        (**self).to_hash_key()
     }
 }
 
+impl<T: ToHashKey + ?Sized> ToHashKey for Box<T> {
+    fn to_hash_key(&self) -> u64 {
+        (**self).to_hash_key()
+    }
+}
+
+impl<T: ToHashKey + ToOwned + ?Sized> ToHashKey for Cow<'_, T> {
+    fn to_hash_key(&self) -> u64 {
+        (**self).to_hash_key()
+    }
+}
+
+impl<T: ToHashKey> ToHashKey for Option<T> {
+    fn to_hash_key(&self) -> u64 {
+        match self {
+            Some(value) => compose_hash_key([1, value.to_hash_key()]),
+            None => compose_hash_key([0]),
+        }
+    }
+}
+
+/// The element hash keys combined by [`compose_hash_key`].
+impl<T: ToHashKey> ToHashKey for [T] {
+    fn to_hash_key(&self) -> u64 {
+        compose_hash_key(self.iter().map(ToHashKey::to_hash_key))
+    }
+}
+
+impl<T: ToHashKey> ToHashKey for Vec<T> {
+    fn to_hash_key(&self) -> u64 {
+        self.as_slice().to_hash_key()
+    }
+}
+
+impl<T: ToHashKey, const N: usize> ToHashKey for [T; N] {
+    fn to_hash_key(&self) -> u64 {
+        self.as_slice().to_hash_key()
+    }
+}
+
+/// Tuples key, compare and hash field by field, like a derived struct: keys
+/// are joined by [`compose_key`], comparables form a tuple and hash keys
+/// are combined by [`compose_hash_key`].
+macro_rules! impl_tuple_keys {
+    ($(($name:ident, $index:tt)),+) => {
+        impl<$($name: ToKey),+> ToKey for ($($name,)+) {
+            fn to_key(&self) -> String {
+                compose_key([$(self.$index.to_key()),+])
+            }
+        }
+
+        impl<$($name: ToComparable),+> ToComparable for ($($name,)+) {
+            type Output = ($($name::Output,)+);
+
+            fn to_comparable(&self) -> Self::Output {
+                ($(self.$index.to_comparable(),)+)
+            }
+        }
+
+        impl<$($name: ToHashKey),+> ToHashKey for ($($name,)+) {
+            fn to_hash_key(&self) -> u64 {
+                compose_hash_key([$(self.$index.to_hash_key()),+])
+            }
+        }
+    };
+}
+
+impl_tuple_keys!((A, 0));
+impl_tuple_keys!((A, 0), (B, 1));
+impl_tuple_keys!((A, 0), (B, 1), (C, 2));
+impl_tuple_keys!((A, 0), (B, 1), (C, 2), (D, 3));
+impl_tuple_keys!((A, 0), (B, 1), (C, 2), (D, 3), (E, 4));
+impl_tuple_keys!((A, 0), (B, 1), (C, 2), (D, 3), (E, 4), (G, 5));
+impl_tuple_keys!((A, 0), (B, 1), (C, 2), (D, 3), (E, 4), (G, 5), (H, 6));
+impl_tuple_keys!((A, 0), (B, 1), (C, 2), (D, 3), (E, 4), (G, 5), (H, 6), (I, 7));
+
+fn hash_of<T: Hash + ?Sized>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Join the keys of several parts into one key, as derived [`ToKey`]
+/// implementations do.
+///
+/// Parts are separated by `:`, with `:` and `\` inside a part escaped by a
+/// backslash, so different parts never produce the same key.
+///
+/// # Examples
+///
+/// ```
+/// use rust_lodash::utils::compose_key;
+///
+/// assert_eq!(compose_key(["a".to_string(), "b".to_string()]), "a:b");
+/// assert_eq!(compose_key(["a:b".to_string(), String::new()]), "a\\:b:");
+/// ```
+pub fn compose_key<I: IntoIterator<Item = String>>(parts: I) -> String {
+    let mut key = String::new();
+    for (index, part) in parts.into_iter().enumerate() {
+        if index > 0 {
+            key.push(':');
+        }
+        for c in part.chars() {
+            if matches!(c, ':' | '\\') {
+                key.push('\\');
+            }
+            key.push(c);
+        }
+    }
+    key
+}
+
+/// Combine the hash keys of several parts into one, as derived
+/// [`ToHashKey`] implementations do. The result depends on the order of the
+/// parts.
+pub fn compose_hash_key<I: IntoIterator<Item = u64>>(parts: I) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    for part in parts {
+        hasher.write_u64(part);
+    }
+    hasher.finish()
+}
+
 /// Utility function to safely convert between types.
 /// Safely convert a value from one type to another.
 /// 
@@ -294,6 +545,18 @@ mod tests {
         assert_eq!(true.to_key(), "true");
     }
 
+    #[test]
+    fn test_compose_keys() {
+        assert_eq!(compose_key(Vec::new()), "");
+        assert_ne!(
+            compose_key(["a:".to_string(), "b".to_string()]),
+            compose_key(["a".to_string(), ":b".to_string()])
+        );
+        assert_eq!(compose_key(["a\\".to_string()]), "a\\\\");
+        assert_ne!(compose_hash_key([1, 2]), compose_hash_key([2, 1]));
+        assert_eq!(compose_hash_key([1, 2]), compose_hash_key([1, 2]));
+    }
+
     #[test]
     fn test_predicate() {
         let pred = |x: &i32| *x > 5;
@@ -319,6 +582,48 @@ mod tests {
         assert_eq!("hello".to_comparable(), "hello");
     }
 
+    #[test]
+    fn test_composite_keys() {
+        assert_eq!(('a', 1u8, -2i8).to_key(), "a:1:-2");
+        assert_eq!(("x:y", 3usize).to_key(), "x\\:y:3");
+        assert_eq!(Some(Box::new("b")).to_key(), "b");
+        assert_eq!(None::<i32>.to_key(), "null");
+        assert_eq!(vec![1, 2].to_key(), "1:2");
+        assert_eq!([Cow::Borrowed("a"), Cow::Owned("b".into())].to_key(), "a:b");
+        assert_eq!((&&"c").to_key(), "c");
+    }
+
+    #[test]
+    fn test_composite_comparables() {
+        assert!(("a", 2).to_comparable() < ("a", 10).to_comparable());
+        assert!(None::<i32>.to_comparable() < Some(0).to_comparable());
+        assert!(vec![1, 2].to_comparable() < vec![1, 2, 0].to_comparable());
+        assert_eq!(Cow::Borrowed("z").to_comparable(), "z");
+        assert_eq!('b'.to_comparable(), 'b');
+
+        let mut floats = [1.5, f64::NAN, -0.0, f64::NEG_INFINITY, 0.0, -f64::NAN];
+        floats.sort_by_key(ToComparable::to_comparable);
+        assert!(floats[0].is_nan() && floats[0].is_sign_negative());
+        assert_eq!(floats[1..5], [f64::NEG_INFINITY, -0.0, 0.0, 1.5]);
+        assert!(floats[1..5][1].is_sign_negative());
+        assert!(floats[5].is_nan());
+        assert_ne!((-0.0f32).to_comparable(), 0.0f32.to_comparable());
+        assert_eq!(f64::NAN.to_comparable(), f64::NAN.to_comparable());
+    }
+
+    #[test]
+    fn test_composite_hash_keys() {
+        assert_eq!(String::from("hi").to_hash_key(), "hi".to_hash_key());
+        assert_eq!(Cow::Borrowed("hi").to_hash_key(), "hi".to_hash_key());
+        assert_eq!(Box::new(7u8).to_hash_key(), 7);
+        assert_eq!('a'.to_hash_key(), 97);
+        assert_ne!((1, 2).to_hash_key(), (2, 1).to_hash_key());
+        assert_ne!(Some(0).to_hash_key(), None::<i32>.to_hash_key());
+        assert_ne!(vec![1, 2].to_hash_key(), vec![2, 1].to_hash_key());
+        assert_eq!(1.5f64.to_hash_key(), 1.5f64.to_bits());
+        assert_eq!(u128::MAX.to_hash_key(), u128::MAX.to_hash_key());
+    }
+
     #[test]
     fn test_safe_clone() {
         let original = vec![1, 2, 3];
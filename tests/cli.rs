@@ -0,0 +1,83 @@
+//! End-to-end tests for the `rlodash` binary of the `cli` feature
+
+#![cfg(feature = "cli")]
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+const USERS: &str = r#"[
+    {"name": "ann", "age": 31, "active": true, "tags": ["admin"]},
+    {"name": "bob", "age": 17, "active": false, "tags": []},
+    {"name": "cy", "age": 45, "active": true, "tags": ["dev"]}
+]"#;
+
+/// Run `rlodash` with `args` and `stdin`, returning the exit code, stdout
+/// and stderr.
+fn rlodash(args: &[&str], stdin: &str) -> (i32, String, String) {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_rlodash"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    (
+        output.status.code().unwrap_or(-1),
+        String::from_utf8(output.stdout).unwrap(),
+        String::from_utf8(output.stderr).unwrap(),
+    )
+}
+
+#[test]
+fn test_json_pipeline() {
+    let (code, stdout, _) = rlodash(
+        &["filter(matches({active: true})) | sort_by(age, desc) | take(10) | map(name)"],
+        USERS,
+    );
+    assert_eq!((code, stdout.as_str()), (0, "[\"cy\",\"ann\"]\n"));
+
+    let (code, stdout, _) = rlodash(&["-o", "ndjson", "filter(tags contains 'admin')"], USERS);
+    assert_eq!(code, 0);
+    assert_eq!(
+        stdout,
+        "{\"name\":\"ann\",\"age\":31,\"active\":true,\"tags\":[\"admin\"]}\n"
+    );
+}
+
+#[test]
+fn test_ndjson_input_and_csv_output() {
+    let ndjson = "{\"name\": \"ann\", \"note\": \"a, b\"}\n\n{\"name\": \"bob\", \"age\": 17}\n";
+    let (code, stdout, _) = rlodash(&["-o", "csv"], ndjson);
+    assert_eq!(code, 0);
+    assert_eq!(stdout, "name,note,age\nann,\"a, b\",\nbob,,17\n");
+
+    let (code, stdout, _) = rlodash(&["-o", "csv", "map(upper(name))"], ndjson);
+    assert_eq!((code, stdout.as_str()), (0, "value\nANN\nBOB\n"));
+
+    let (code, stdout, stderr) = rlodash(&["map(name)"], "{\"name\":\"a\",\"n\":1}\n");
+    assert_eq!((code, stdout.as_str()), (0, "[\"a\"]\n"), "{stderr}");
+    let (code, stdout, _) = rlodash(&["-o", "ndjson"], "{\"n\": 1}");
+    assert_eq!((code, stdout.as_str()), (0, "{\"n\":1}\n"));
+}
+
+#[test]
+fn test_errors() {
+    let (code, _, stderr) = rlodash(&["-i", "ndjson", "take(1)"], "{\"a\": 1}\n{\"a\":\n");
+    assert_eq!(code, 1);
+    assert!(stderr.starts_with("rlodash: line 2: invalid JSON"), "{stderr}");
+
+    let (code, _, stderr) = rlodash(&["sort_by(age) | mapp(name)"], USERS);
+    assert_eq!(code, 1);
+    assert!(stderr.contains("step 1: unknown step `mapp`"), "{stderr}");
+
+    let (code, _, stderr) = rlodash(&["-o", "xml"], USERS);
+    assert_eq!(code, 2);
+    assert!(stderr.contains("invalid output format"), "{stderr}");
+}
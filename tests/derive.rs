@@ -0,0 +1,182 @@
+//! Tests for the `ToKey`, `ToComparable` and `ToHashKey` derives.
+
+#![cfg(feature = "derive")]
+// Skipped fields exist only to be left out of the keys.
+#![allow(dead_code)]
+
+use rust_lodash::collection::transform::{group_by, sort_by};
+use rust_lodash::utils::{compose_key, ToComparable, ToHashKey, ToKey};
+use std::cmp::Reverse;
+
+#[derive(Debug, Clone, PartialEq, ToKey, ToComparable, ToHashKey)]
+struct User {
+    #[lodash(order = "desc")]
+    age: u32,
+    name: String,
+    #[lodash(skip)]
+    visits: u64,
+}
+
+#[derive(Debug, Clone, ToKey, ToComparable, ToHashKey)]
+struct Id(#[lodash(skip)] &'static str, u64);
+
+#[derive(ToKey, ToComparable, ToHashKey)]
+struct Unit;
+
+#[derive(Clone, ToKey, ToComparable, ToHashKey)]
+struct Tagged<T> {
+    #[lodash(key)]
+    tag: T,
+    #[lodash(key, order = "desc")]
+    rank: i64,
+    note: &'static str,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, ToKey, ToComparable, ToHashKey)]
+enum Level {
+    Low,
+    High,
+}
+
+#[derive(ToKey, ToHashKey)]
+enum Event {
+    Click { x: i32, y: i32 },
+    Key(String, #[lodash(skip)] u64),
+    Quit,
+}
+
+fn user(age: u32, name: &str, visits: u64) -> User {
+    User {
+        age,
+        name: name.to_string(),
+        visits,
+    }
+}
+
+#[test]
+fn test_struct_fields() {
+    let ann = user(30, "ann", 1);
+    assert_eq!(ann.to_key(), "30:ann");
+    assert_eq!(ann.to_comparable(), (Reverse(30), "ann".to_string()));
+    assert_eq!(ann.to_hash_key(), user(30, "ann", 99).to_hash_key());
+    assert_ne!(ann.to_hash_key(), user(31, "ann", 1).to_hash_key());
+
+    let users = vec![user(30, "bob", 0), user(41, "cy", 0), ann.clone()];
+    let sorted = sort_by(&users, ToComparable::to_comparable);
+    let names: Vec<&str> = sorted.iter().map(|user| user.name.as_str()).collect();
+    assert_eq!(names, ["cy", "ann", "bob"]);
+}
+
+#[test]
+fn test_tuple_unit_and_generic_structs() {
+    let id = Id("ignored", 7);
+    assert_eq!(id.to_key(), "7");
+    assert_eq!(id.to_comparable(), 7);
+    assert_eq!(id.to_hash_key(), 7);
+
+    assert_eq!(Unit.to_key(), "Unit");
+    assert_eq!(Unit.to_comparable(), ());
+    assert_eq!(Unit.to_hash_key(), Unit.to_hash_key());
+
+    let tagged = Tagged {
+        tag: "a:b".to_string(),
+        rank: 2,
+        note: "x",
+    };
+    assert_eq!(tagged.to_key(), compose_key(["a:b".into(), "2".into()]));
+    assert_eq!(tagged.to_comparable(), ("a:b".to_string(), Reverse(2)));
+
+    let groups = group_by(
+        &[
+            Tagged {
+                tag: 1,
+                rank: 0,
+                note: "x",
+            },
+            Tagged {
+                tag: 1,
+                rank: 0,
+                note: "y",
+            },
+            Tagged {
+                tag: 2,
+                rank: 0,
+                note: "z",
+            },
+        ],
+        ToKey::to_key,
+    );
+    assert_eq!(groups["1:0"].len(), 2);
+}
+
+#[test]
+fn test_enums() {
+    assert_eq!(Level::High.to_key(), "High");
+    assert!(Level::Low.to_comparable() < Level::High.to_comparable());
+    assert_ne!(Level::Low.to_hash_key(), Level::High.to_hash_key());
+
+    assert_eq!(Event::Click { x: 1, y: -2 }.to_key(), "Click:1:-2");
+    assert_eq!(Event::Key("q".into(), 5).to_key(), "Key:q");
+    assert_eq!(Event::Quit.to_key(), "Quit");
+    assert_eq!(
+        Event::Key("q".into(), 5).to_hash_key(),
+        Event::Key("q".into(), 6).to_hash_key()
+    );
+    assert_ne!(
+        Event::Click { x: 1, y: 2 }.to_hash_key(),
+        Event::Click { x: 2, y: 1 }.to_hash_key()
+    );
+}
+
+#[derive(Debug, Clone, ToKey, ToComparable)]
+struct Reading {
+    #[lodash(order = "desc")]
+    value: f64,
+    sensor: Option<&'static str>,
+    #[lodash(skip)]
+    raw: Vec<u8>,
+}
+
+#[test]
+fn test_composite_fields() {
+    let readings = vec![
+        Reading {
+            value: 1.5,
+            sensor: Some("b"),
+            raw: vec![],
+        },
+        Reading {
+            value: f64::NAN,
+            sensor: None,
+            raw: vec![],
+        },
+        Reading {
+            value: 1.5,
+            sensor: None,
+            raw: vec![1],
+        },
+        Reading {
+            value: 9.0,
+            sensor: Some("a"),
+            raw: vec![],
+        },
+    ];
+    assert_eq!(readings[0].to_key(), "1.5:b");
+    assert_eq!(readings[2].to_key(), "1.5:null");
+
+    let sorted = sort_by(&readings, ToComparable::to_comparable);
+    let order: Vec<(f64, Option<&str>)> = sorted
+        .iter()
+        .map(|reading| (reading.value, reading.sensor))
+        .collect();
+    assert!(order[0].0.is_nan());
+    assert_eq!(
+        order[1..],
+        [(9.0, Some("a")), (1.5, None), (1.5, Some("b"))]
+    );
+
+    let by_sensor = group_by(&readings, |reading| {
+        (reading.sensor, reading.raw.len()).to_key()
+    });
+    assert_eq!(by_sensor["null:0"].len(), 1);
+}
@@ -0,0 +1,130 @@
+//! Serde roundtrip tests for the `serialize` feature, in JSON and CBOR
+
+#![cfg(feature = "serialize")]
+
+use rust_lodash::collection::ordered_map::OrderedMap;
+use rust_lodash::collection::pivot::{crosstab, Table};
+use rust_lodash::collection::Collection;
+use rust_lodash::math::{histogram_by, Bins, Histogram, PercentileMethod};
+use rust_lodash::utils::LodashError;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fmt::Debug;
+
+fn json_roundtrip<T: Serialize + DeserializeOwned>(value: &T) -> T {
+    serde_json::from_str(&serde_json::to_string(value).unwrap()).unwrap()
+}
+
+fn cbor_roundtrip<T: Serialize + DeserializeOwned>(value: &T) -> T {
+    let mut bytes = Vec::new();
+    ciborium::into_writer(value, &mut bytes).unwrap();
+    ciborium::from_reader(bytes.as_slice()).unwrap()
+}
+
+fn assert_roundtrips<T>(value: &T)
+where
+    T: Serialize + DeserializeOwned + PartialEq + Debug,
+{
+    assert_eq!(&json_roundtrip(value), value);
+    assert_eq!(&cbor_roundtrip(value), value);
+}
+
+#[test]
+fn test_collection_is_a_plain_sequence() {
+    let collection = Collection::new(vec![1, 2, 3]);
+    assert_eq!(serde_json::to_string(&collection).unwrap(), "[1,2,3]");
+    assert_eq!(
+        serde_json::from_str::<Collection<i32>>("[4,5]").unwrap(),
+        Collection::new(vec![4, 5])
+    );
+    assert_roundtrips(&collection);
+    assert_roundtrips(&Collection::new(vec!["a".to_string(), "b".to_string()]));
+    assert_roundtrips(&Collection::<u8>::empty());
+}
+
+#[test]
+fn test_error_is_a_tagged_enum() {
+    assert_eq!(
+        serde_json::to_value(LodashError::index_out_of_bounds(5, 3)).unwrap(),
+        serde_json::json!({"kind": "index_out_of_bounds", "index": 5, "size": 3})
+    );
+    assert_eq!(
+        serde_json::to_string(&LodashError::empty_collection()).unwrap(),
+        r#"{"kind":"empty_collection"}"#
+    );
+
+    for error in [
+        LodashError::invalid_input("bad"),
+        LodashError::type_conversion("str", "i32"),
+        LodashError::index_out_of_bounds(1, 0),
+        LodashError::empty_collection(),
+        LodashError::invalid_predicate("panicked"),
+        LodashError::custom("oops"),
+        LodashError::empty_collection().with_operation("load").with_index(4),
+    ] {
+        assert_roundtrips(&error);
+    }
+
+    let error = LodashError::from_error("disk full").with_index(2);
+    assert_eq!(
+        serde_json::to_value(&error).unwrap(),
+        serde_json::json!({
+            "kind": "context",
+            "operation": null,
+            "index": 2,
+            "source": {"kind": "custom", "message": "disk full"}
+        })
+    );
+}
+
+#[test]
+fn test_ordered_map_keeps_insertion_order() {
+    let counts = Collection::new(vec!["pear", "fig", "pear", "apple"]).count_by_ordered(|s| *s);
+    assert_eq!(
+        serde_json::to_string(&counts).unwrap(),
+        r#"{"pear":2,"fig":1,"apple":1}"#
+    );
+
+    let parsed: OrderedMap<String, usize> = serde_json::from_str(r#"{"z":1,"a":2,"m":3}"#).unwrap();
+    assert_eq!(
+        parsed.keys().map(String::as_str).collect::<Vec<_>>(),
+        vec!["z", "a", "m"]
+    );
+    assert_roundtrips(&parsed);
+
+    let groups: OrderedMap<bool, Vec<i32>> = vec![(true, vec![2, 4]), (false, vec![1, 3])]
+        .into_iter()
+        .collect();
+    assert_roundtrips(&groups);
+
+    let hashed = Collection::new(vec!["fig", "fig"]).count_by(|s| s.to_string());
+    assert_eq!(serde_json::to_string(&hashed).unwrap(), r#"{"fig":2}"#);
+    assert_roundtrips(&hashed);
+}
+
+#[test]
+fn test_table_roundtrips_and_validates_shape() {
+    let table = crosstab(
+        &[("x", 1), ("y", 2), ("x", 2)],
+        |p| p.0.to_string(),
+        |p| p.1,
+    );
+    assert_roundtrips(&table);
+    assert_eq!(
+        serde_json::to_value(&table).unwrap(),
+        table.to_json().unwrap()
+    );
+
+    let ragged = r#"{"rows": ["a", "b"], "columns": [1], "cells": [[1], [2, 3]]}"#;
+    let error = serde_json::from_str::<Table<String, i32, i32>>(ragged).unwrap_err();
+    assert!(error.to_string().contains("2 rows of 1 columns"));
+}
+
+#[test]
+fn test_statistics_types_roundtrip() {
+    let histogram: Histogram = histogram_by(&[1.0, 2.0, 2.5, 4.0], Bins::Count(3), |x| *x).unwrap();
+    assert_roundtrips(&histogram);
+    assert_roundtrips(&Bins::Width(0.5));
+    assert_roundtrips(&Bins::Auto);
+    assert_roundtrips(&PercentileMethod::NearestRank);
+}
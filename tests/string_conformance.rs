@@ -0,0 +1,245 @@
+//! Conformance tests for the string module, ported from Lodash's test suite
+
+use rust_lodash::string::{
+    camel_case, capitalize, deburr, kebab_case, lower_case, lower_first, snake_case, start_case,
+    upper_case, upper_first, words,
+};
+
+type CaseFn = fn(&str) -> String;
+
+const CASE_METHODS: [(&str, CaseFn, &str); 6] = [
+    ("camel_case", camel_case, "fooBar"),
+    ("kebab_case", kebab_case, "foo-bar"),
+    ("lower_case", lower_case, "foo bar"),
+    ("snake_case", snake_case, "foo_bar"),
+    ("start_case", start_case, "Foo Bar"),
+    ("upper_case", upper_case, "FOO BAR"),
+];
+
+const CASE_INPUTS: [&str; 8] = [
+    "foo bar",
+    "Foo bar",
+    "foo Bar",
+    "Foo Bar",
+    "FOO BAR",
+    "fooBar",
+    "--foo-bar--",
+    "__foo_bar__",
+];
+
+#[test]
+fn test_case_methods_convert_strings() {
+    for (name, method, expected) in CASE_METHODS {
+        for input in CASE_INPUTS {
+            let actual = method(input);
+            let expected = match (name, input) {
+                ("start_case", "FOO BAR") => "FOO BAR",
+                _ => expected,
+            };
+            assert_eq!(actual, expected, "{name}({input:?})");
+        }
+    }
+}
+
+#[test]
+fn test_case_methods_double_convert() {
+    for (name, method, expected) in CASE_METHODS {
+        for input in CASE_INPUTS {
+            let actual = method(&method(input));
+            let expected = match (name, input) {
+                ("start_case", "FOO BAR") => "FOO BAR",
+                _ => expected,
+            };
+            assert_eq!(actual, expected, "{name}({name}({input:?}))");
+        }
+    }
+}
+
+#[test]
+fn test_case_methods_remove_contraction_apostrophes() {
+    let postfixes = ["d", "ll", "m", "re", "s", "t", "ve"];
+    for apostrophe in ["'", "\u{2019}"] {
+        for postfix in postfixes {
+            let input = format!("a b{apostrophe}{postfix} c");
+            let expected = [
+                format!("aB{postfix}C"),
+                format!("a-b{postfix}-c"),
+                format!("a b{postfix} c"),
+                format!("a_b{postfix}_c"),
+                format!("A B{postfix} C"),
+                format!("A B{} C", postfix.to_uppercase()),
+            ];
+            for ((name, method, _), expected) in CASE_METHODS.iter().zip(expected) {
+                assert_eq!(method(&input), expected, "{name}({input:?})");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_case_methods_remove_latin_math_operators() {
+    for (name, method, _) in CASE_METHODS {
+        for operator in ["\u{d7}", "\u{f7}"] {
+            assert_eq!(method(operator), "", "{name}({operator:?})");
+        }
+    }
+}
+
+#[test]
+fn test_case_methods_deburr_letters() {
+    let burred = "\u{c0}\u{c1}\u{c2}\u{c3}\u{c4}\u{c5}\u{c7}\u{c8}\u{c9}\u{ca}\u{cb}\u{cc}\u{cd}\u{ce}\u{cf}\u{d1}\u{d2}\u{d3}\u{d4}\u{d5}\u{d6}\u{d8}\u{d9}\u{da}\u{db}\u{dc}\u{dd}";
+    let deburred = "AAAAAACEEEEIIIINOOOOOOUUUUY";
+    assert_eq!(deburr(burred), deburred);
+    assert_eq!(deburr(&burred.to_lowercase()), deburred.to_lowercase());
+    for (name, method, _) in CASE_METHODS {
+        let expected = method(deburred);
+        assert_eq!(method(burred), expected, "{name}({burred:?})");
+    }
+    assert_eq!(camel_case("cr\u{e8}me br\u{fb}l\u{e9}e"), "cremeBrulee");
+    assert_eq!(kebab_case("\u{c6}sir Stra\u{df}e"), "aesir-strasse");
+}
+
+#[test]
+fn test_camel_case_numbers() {
+    let cases = [
+        ("12 feet", "12Feet"),
+        ("enable 6h format", "enable6HFormat"),
+        ("enable 24H format", "enable24HFormat"),
+        ("too legit 2 quit", "tooLegit2Quit"),
+        ("walk 500 miles", "walk500Miles"),
+        ("xhr2 request", "xhr2Request"),
+    ];
+    for (input, expected) in cases {
+        assert_eq!(camel_case(input), expected, "camel_case({input:?})");
+    }
+}
+
+#[test]
+fn test_camel_case_acronyms() {
+    let cases = [
+        ("safe HTML", "safeHtml"),
+        ("safeHTML", "safeHtml"),
+        ("escape HTML entities", "escapeHtmlEntities"),
+        ("escapeHTMLEntities", "escapeHtmlEntities"),
+        ("XMLHttpRequest", "xmlHttpRequest"),
+        ("XmlHTTPRequest", "xmlHttpRequest"),
+    ];
+    for (input, expected) in cases {
+        assert_eq!(camel_case(input), expected, "camel_case({input:?})");
+    }
+}
+
+#[test]
+fn test_start_case() {
+    assert_eq!(start_case("--foo-bar--"), "Foo Bar");
+    assert_eq!(start_case("fooBar"), "Foo Bar");
+    assert_eq!(start_case("__FOO_BAR__"), "FOO BAR");
+}
+
+#[test]
+fn test_case_first_and_capitalize() {
+    assert_eq!(upper_first("fred"), "Fred");
+    assert_eq!(upper_first("Fred"), "Fred");
+    assert_eq!(upper_first("FRED"), "FRED");
+    assert_eq!(lower_first("fred"), "fred");
+    assert_eq!(lower_first("Fred"), "fred");
+    assert_eq!(lower_first("FRED"), "fRED");
+    assert_eq!(capitalize("fred"), "Fred");
+    assert_eq!(capitalize("Fred"), "Fred");
+    assert_eq!(capitalize(" fred"), " fred");
+}
+
+#[test]
+fn test_words_compound() {
+    let cases: [(&str, &[&str]); 12] = [
+        ("12ft", &["12", "ft"]),
+        ("aeiouAreVowels", &["aeiou", "Are", "Vowels"]),
+        ("enable 6h format", &["enable", "6", "h", "format"]),
+        ("enable 24H format", &["enable", "24", "H", "format"]),
+        ("isISO8601", &["is", "ISO", "8601"]),
+        ("LETTERSAeiouAreVowels", &["LETTERS", "Aeiou", "Are", "Vowels"]),
+        ("tooLegit2Quit", &["too", "Legit", "2", "Quit"]),
+        ("walk500Miles", &["walk", "500", "Miles"]),
+        ("xhr2Request", &["xhr", "2", "Request"]),
+        ("XMLHttp", &["XML", "Http"]),
+        ("XmlHTTP", &["Xml", "HTTP"]),
+        ("XmlHttp", &["Xml", "Http"]),
+    ];
+    for (input, expected) in cases {
+        assert_eq!(words(input), expected, "words({input:?})");
+    }
+}
+
+#[test]
+fn test_words_diacritical_marks() {
+    let cases: [(&str, &[&str]); 3] = [
+        ("LETTERS\u{c6}iouAreVowels", &["LETTERS", "\u{c6}iou", "Are", "Vowels"]),
+        ("\u{e6}iouAreVowels", &["\u{e6}iou", "Are", "Vowels"]),
+        ("\u{e6}iou2Consonants", &["\u{e6}iou", "2", "Consonants"]),
+    ];
+    for (input, expected) in cases {
+        assert_eq!(words(input), expected, "words({input:?})");
+    }
+}
+
+#[test]
+fn test_words_contractions() {
+    let postfixes = ["d", "ll", "m", "re", "s", "t", "ve"];
+    for apostrophe in ["'", "\u{2019}"] {
+        for postfix in postfixes {
+            let input = format!("a b{apostrophe}{postfix} c");
+            let expected = vec!["a".to_string(), format!("b{apostrophe}{postfix}"), "c".to_string()];
+            assert_eq!(words(&input), expected, "words({input:?})");
+
+            let upper: Vec<String> = expected.iter().map(|w| w.to_uppercase()).collect();
+            assert_eq!(words(&input.to_uppercase()), upper, "words({:?})", input.to_uppercase());
+        }
+    }
+}
+
+#[test]
+fn test_words_ordinals() {
+    for ordinal in ["1st", "2nd", "3rd", "4th"] {
+        assert_eq!(words(ordinal), vec![ordinal]);
+        let upper = ordinal.to_uppercase();
+        assert_eq!(words(&upper), vec![upper.clone()]);
+    }
+}
+
+#[test]
+fn test_words_ignore_operators_and_punctuation() {
+    let symbols = [
+        "\u{ac}", "\u{b1}", "\u{d7}", "\u{f7}", "\u{2012}", "\u{2013}", "\u{2014}", "\u{2015}",
+        "\u{2024}", "\u{2025}", "\u{2026}", "\u{205d}", "\u{205e}",
+    ];
+    for symbol in symbols {
+        assert!(words(symbol).is_empty(), "words({symbol:?})");
+    }
+}
+
+#[test]
+fn test_words_emoji() {
+    let flag = "\u{1f1fa}\u{1f1f8}";
+    let heart = "\u{2764}\u{fe0f}";
+    let hearts = "\u{1f495}";
+    let leafs = "\u{1f342}";
+    let rocket = "\u{1f680}";
+    let thumbs_up = "\u{1f44d}";
+    let family = "\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}";
+    let skin_tone = "\u{1f44d}\u{1f3fd}";
+
+    let input = format!("A {leafs}, {heart} and {rocket}");
+    assert_eq!(words(&input), vec!["A", leafs, heart, "and", rocket]);
+
+    for emoji in [flag, heart, hearts, thumbs_up, family, skin_tone] {
+        assert_eq!(words(emoji), vec![emoji], "words({emoji:?})");
+    }
+
+    assert_eq!(words(&format!("{flag}{thumbs_up}")), vec![flag, thumbs_up]);
+}
+
+#[test]
+fn test_words_combining_marks() {
+    let combo = "a\u{300}";
+    assert_eq!(words(&format!("{combo} b")), vec![combo, "b"]);
+}